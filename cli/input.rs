@@ -39,6 +39,10 @@ pub enum Commands {
         #[clap(long)]
         brief: bool,
 
+        /// The format to print the results in. Defaults to text.
+        #[clap(long, arg_enum)]
+        format: Option<OutputFormat>,
+
         /// File paths to ignore relative to the project directory. These are combined with any
         /// that are listed in your config.
         #[clap(long, multiple_values = true)]
@@ -69,6 +73,63 @@ pub enum Commands {
         #[clap(short, long, arg_enum)]
         format: Option<EmitFormat>,
     },
+    /// Searches the project for code matching a gml pattern, such as `array_length($a) == 0`.
+    ///
+    /// Identifiers starting with a `$` are metavariables, which match any expression.
+    #[clap(alias = "grep")]
+    Search {
+        /// The pattern to search for. Wrap it in single quotes to keep your shell from expanding
+        /// its metavariables.
+        pattern: String,
+
+        /// The path to the project directory to search. Uses the current directory if not
+        /// provided.
+        #[clap(long, short, parse(from_os_str))]
+        path: Option<PathBuf>,
+
+        /// Prints the matches as JSON instead of human readable snippets.
+        #[clap(long)]
+        json: bool,
+
+        /// A template to replace every match with, such as `array_empty($a)`. Files are rewritten
+        /// in place.
+        #[clap(long)]
+        rewrite: Option<String>,
+
+        /// If provided, will force color output instead of deferring.
+        #[clap(long)]
+        color: bool,
+    },
+    /// Lists every global function, constructor, macro, enum, enum member and globalvar that is
+    /// never referenced anywhere in the project.
+    ///
+    /// Symbols can be exempted with `allow` tags, or with the `public_api_paths` and
+    /// `used_symbols` options in your config.
+    Unused {
+        /// The path to the project directory to search. Uses the current directory if not
+        /// provided.
+        #[clap(long, short, parse(from_os_str))]
+        path: Option<PathBuf>,
+
+        /// Prints the unused symbols as JSON instead of a human readable list.
+        #[clap(long)]
+        json: bool,
+    },
+    /// Measures the complexity of every file and function in the project: its cyclomatic
+    /// complexity, how deeply it nests, how many statements it has and how long its longest line
+    /// is.
+    ///
+    /// Values over the thresholds in your config (such as `max_complexity`) are highlighted.
+    Metrics {
+        /// The path to the project directory to measure. Uses the current directory if not
+        /// provided.
+        #[clap(long, short, parse(from_os_str))]
+        path: Option<PathBuf>,
+
+        /// The format to print the tables in. Defaults to text.
+        #[clap(long, arg_enum)]
+        format: Option<MetricsFormat>,
+    },
 }
 
 #[derive(Parser, Debug, Copy, Clone, ArgEnum)]
@@ -87,6 +148,26 @@ impl From<ConfigTemplate> for Config {
     }
 }
 
+#[derive(Parser, Debug, Copy, Clone, PartialEq, ArgEnum)]
+pub enum OutputFormat {
+    /// Human readable diagnostics followed by a summary.
+    Text,
+    /// A JSON object containing every diagnostic, including those suppressed by `allow` tags.
+    Json,
+    /// A SARIF log, for use with code scanning tools.
+    Sarif,
+}
+
+#[derive(Parser, Debug, Copy, Clone, PartialEq, ArgEnum)]
+pub enum MetricsFormat {
+    /// Human readable tables.
+    Text,
+    /// A JSON object with a list of files and a list of functions.
+    Json,
+    /// A single CSV table, with a `kind` column telling files and functions apart.
+    Csv,
+}
+
 #[derive(Parser, Debug, Copy, Clone, ArgEnum)]
 pub enum EmitFormat {
     Json,
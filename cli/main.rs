@@ -1,10 +1,19 @@
 use clap::Parser;
-use codespan_reporting::term::termcolor::{ColorChoice, StandardStream};
+use codespan_reporting::{
+    diagnostic::{Diagnostic, Label},
+    files::Files,
+    term::termcolor::{ColorChoice, StandardStream},
+};
 use colored::Colorize;
 use duck::{
-    Config, Duck, driver,
-    lint::{Lint, LintLevelSetting, collection::*},
-    parse::Ast,
+    Config, Duck,
+    analyze::{FileMetrics, Metrics, longest_line},
+    driver,
+    lint::{
+        Lint, LintLevel, LintLevelSetting, LintRegistry,
+        collection::{UnusedEnum, UnusedEnumMember, UnusedFunction, UnusedGlobalvar, UnusedMacro},
+    },
+    parse::{Ast, Location, Pattern, PatternMatch, rewrite_source},
 };
 use hashbrown::HashMap;
 use num_format::{Locale, ToFormattedString};
@@ -24,6 +33,7 @@ async fn main() {
             allow_duck_errors,
             color,
             brief,
+            format,
             ignored_file_paths,
         } => {
             run(
@@ -33,6 +43,7 @@ async fn main() {
                 allow_duck_errors,
                 color,
                 brief,
+                format.unwrap_or(OutputFormat::Text),
                 ignored_file_paths,
             )
             .await
@@ -44,10 +55,20 @@ async fn main() {
             output_path,
             format,
         } => emit(path, output_path, format).await.map(|_| 0).unwrap_or(1),
+        Commands::Search {
+            pattern,
+            path,
+            json,
+            rewrite,
+            color,
+        } => search(pattern, path, json, rewrite, color).await,
+        Commands::Unused { path, json } => unused(path, json).await,
+        Commands::Metrics { path, format } => metrics(path, format.unwrap_or(MetricsFormat::Text)).await,
     };
     std::process::exit(status_code);
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn run(
     path: Option<PathBuf>,
     allow_warnings: bool,
@@ -55,10 +76,13 @@ async fn run(
     allow_errors: bool,
     color: bool,
     brief: bool,
+    format: OutputFormat,
     mut ignored_file_paths: Vec<String>,
 ) -> i32 {
-    // Force colors?
-    if color {
+    // Force colors? (Machine readable formats never want them)
+    if format != OutputFormat::Text {
+        colored::control::set_override(false);
+    } else if color {
         std::env::set_var("CLICOLOR_FORCE", "1");
     }
 
@@ -72,6 +96,22 @@ async fn run(
     let total_duration = timer.elapsed();
 
     // Output the results
+    let status_code = i32::from(
+        (!allow_warnings && run_summary.warning_count() != 0)
+            || (!allow_denials && run_summary.denial_count() != 0)
+            || (!allow_errors && (!run_summary.io_errors().is_empty())),
+    );
+    match format {
+        OutputFormat::Text => {}
+        OutputFormat::Json => {
+            println!("{}", run_summary.to_json());
+            return status_code;
+        }
+        OutputFormat::Sarif => {
+            println!("{}", run_summary.to_sarif());
+            return status_code;
+        }
+    }
     let writer = StandardStream::stderr(if color { ColorChoice::Always } else { ColorChoice::Auto });
     let config = codespan_reporting::term::Config::default();
     for report in run_summary.diagnostics() {
@@ -126,13 +166,17 @@ async fn run(
         ConfigUsage::Failed(error) => println!("{}: Your config was not used in this run, as duck encountered the following error while being parsed: {:?}\n", "error".bright_red().bold(), error),
         ConfigUsage::Some => {}
     }
+    for rule in duck.config().pattern_rules.iter() {
+        if let Err(error) = rule.pattern() {
+            println!(
+                "{}: The pattern `{}` in your config was ignored, as duck encountered the following error while parsing it: {error}\n",
+                "error".bright_red().bold(),
+                rule.pattern
+            );
+        }
+    }
 
-    // Return the status code
-    i32::from(
-        (!allow_warnings && run_summary.warning_count() != 0)
-            || (!allow_denials && run_summary.denial_count() != 0)
-            || (!allow_errors && (!run_summary.io_errors().is_empty())),
-    )
+    status_code
 }
 
 fn new_config(template: ConfigTemplate) -> i32 {
@@ -152,130 +196,15 @@ fn new_config(template: ConfigTemplate) -> i32 {
 fn explain(name: String) -> i32 {
     let current_directory = std::env::current_dir().expect("Cannot access the current directory!");
     let (duck, _) = create_duck(&current_directory);
-    let (message, default_level) = match name.as_str() {
-        // @explain. Do not remove!
-        "accessor_alternative" => (
-            AccessorAlternative::explanation().to_string(),
-            AccessorAlternative::default_level(),
-        ),
-        "and_preference" => (AndPreference::explanation().to_string(), AndPreference::default_level()),
-        "anonymous_constructor" => (
-            AnonymousConstructor::explanation().to_string(),
-            AnonymousConstructor::default_level(),
-        ),
-        "bool_equality" => (BoolEquality::explanation().to_string(), BoolEquality::default_level()),
-        "casing_rules" => (CasingRules::explanation().to_string(), CasingRules::default_level()),
-        "collapsable_if" => (CollapsableIf::explanation().to_string(), CollapsableIf::default_level()),
-        "condition_wrapper" => (
-            ConditionWrapper::explanation().to_string(),
-            ConditionWrapper::default_level(),
-        ),
-        "deprecated" => (Deprecated::explanation().to_string(), Deprecated::default_level()),
-        "draw_sprite" => (DrawSprite::explanation().to_string(), DrawSprite::default_level()),
-        "draw_text" => (DrawText::explanation().to_string(), DrawText::default_level()),
-        "english_flavor_violation" => (
-            EnglishFlavorViolation::explanation().to_string(),
-            EnglishFlavorViolation::default_level(),
-        ),
-        "exit" => (Exit::explanation().to_string(), Exit::default_level()),
-        "global" => (Global::explanation().to_string(), Global::default_level()),
-        "invalid_assignment" => (
-            InvalidAssignment::explanation().to_string(),
-            InvalidAssignment::default_level(),
-        ),
-        "invalid_comparison" => (
-            InvalidComparison::explanation().to_string(),
-            InvalidComparison::default_level(),
-        ),
-        "invalid_equality" => (
-            InvalidEquality::explanation().to_string(),
-            InvalidEquality::default_level(),
-        ),
-        "missing_case_member" => (
-            MissingCaseMember::explanation().to_string(),
-            MissingCaseMember::default_level(),
-        ),
-        "missing_default_case" => (
-            MissingDefaultCase::explanation().to_string(),
-            MissingDefaultCase::default_level(),
-        ),
-        "mod_preference" => (ModPreference::explanation().to_string(), ModPreference::default_level()),
-        "multi_var_declaration" => (
-            MultiVarDeclaration::explanation().to_string(),
-            MultiVarDeclaration::default_level(),
-        ),
-        "non_constant_default_parameter" => (
-            NonConstantDefaultParameter::explanation().to_string(),
-            NonConstantDefaultParameter::default_level(),
-        ),
-        "non_simplified_expression" => (
-            NonSimplifiedExpression::explanation().to_string(),
-            NonSimplifiedExpression::default_level(),
-        ),
-        "not_preference" => (NotPreference::explanation().to_string(), NotPreference::default_level()),
-        "or_preference" => (OrPreference::explanation().to_string(), OrPreference::default_level()),
-        "room_goto" => (RoomGoto::explanation().to_string(), RoomGoto::default_level()),
-        "show_debug_message" => (
-            ShowDebugMessage::explanation().to_string(),
-            ShowDebugMessage::default_level(),
-        ),
-        "single_equals_comparison" => (
-            SingleEqualsComparison::explanation().to_string(),
-            SingleEqualsComparison::default_level(),
-        ),
-        "single_switch_case" => (
-            SingleSwitchCase::explanation().to_string(),
-            SingleSwitchCase::default_level(),
-        ),
-        "suspicious_constant_usage" => (
-            SuspicousConstantUsage::explanation().to_string(),
-            SuspicousConstantUsage::default_level(),
-        ),
-        "switch_without_case" => (
-            SwitchWithoutCase::explanation().to_string(),
-            SwitchWithoutCase::default_level(),
-        ),
-        "todo" => (Todo::explanation().to_string(), Todo::default_level()),
-        "too_many_arguments" => (
-            TooManyArguments::explanation().to_string(),
-            TooManyArguments::default_level(),
-        ),
-        "try_catch" => (TryCatch::explanation().to_string(), TryCatch::default_level()),
-        "unassigned_constructor" => (
-            UnassignedConstructor::explanation().to_string(),
-            UnassignedConstructor::default_level(),
-        ),
-        "unnecessary_grouping" => (
-            UnnecessaryGrouping::explanation().to_string(),
-            UnnecessaryGrouping::default_level(),
-        ),
-        "unused_local_variable" => (
-            UnusedLocalVariable::explanation().to_string(),
-            UnusedLocalVariable::default_level(),
-        ),
-        "unused_parameter" => (
-            UnusedParameter::explanation().to_string(),
-            UnusedParameter::default_level(),
-        ),
-        "useless_function" => (
-            UselessFunction::explanation().to_string(),
-            UselessFunction::default_level(),
-        ),
-        "var_prefix_violation" => (
-            VarPrefixViolation::explanation().to_string(),
-            VarPrefixViolation::default_level(),
-        ),
-        "with_loop" => (WithLoop::explanation().to_string(), WithLoop::default_level()),
-        // @end explain. Do not remove!
-        _ => {
-            println!(
-                "{}: Failed to find a lint with the name '{}'!",
-                "error".bold().bright_red(),
-                name
-            );
-            return -1;
-        }
+    let Some(lint) = duck.lints().get(&name) else {
+        println!(
+            "{}: Failed to find a lint with the name '{}'!",
+            "error".bold().bright_red(),
+            name
+        );
+        return -1;
     };
+    let (message, default_level) = (lint.explanation(), lint.default_level());
     println!("{} {}", "Summary for".bright_white().bold(), name.bold().bright_green());
     println!();
     println!("{}: {message}", "Explanation".bold());
@@ -306,7 +235,7 @@ async fn emit(path: Option<PathBuf>, output_path: PathBuf, format: Option<EmitFo
         let (path_receiver, _) = driver::start_gml_discovery(&current_directory, vec![]);
         let (mut file_receiver, file_handle) = driver::start_file_load(path_receiver);
         let (_, library, _) = file_handle.await.unwrap();
-        while let Some((file_id, data)) = file_receiver.recv().await {
+        while let Some((file_id, _, data)) = file_receiver.recv().await {
             let file = library.get(file_id).expect("Failed to find a file in the library!");
             if let Ok(ast) = driver::parse_gml(data, &file_id) {
                 emit.insert(file.name().clone(), ast);
@@ -325,6 +254,438 @@ async fn emit(path: Option<PathBuf>, output_path: PathBuf, format: Option<EmitFo
     Ok(())
 }
 
+async fn search(pattern: String, path: Option<PathBuf>, json: bool, rewrite: Option<String>, color: bool) -> i32 {
+    if json {
+        colored::control::set_override(false);
+    } else if color {
+        std::env::set_var("CLICOLOR_FORCE", "1");
+    }
+    let pattern = match Pattern::new(&pattern) {
+        Ok(pattern) => pattern,
+        Err(error) => {
+            println!(
+                "{}: Failed to parse the pattern: {}",
+                "error".bright_red().bold(),
+                error.message
+            );
+            return 1;
+        }
+    };
+
+    // Find every match in the project
+    let current_directory =
+        path.unwrap_or_else(|| std::env::current_dir().expect("Cannot access the current directory!"));
+    let (duck, _) = create_duck(&current_directory);
+    let (path_receiver, _) = driver::start_gml_discovery(&current_directory, duck.config().ignored_file_paths.clone());
+    let (mut file_receiver, file_handle) = driver::start_file_load(path_receiver);
+    let mut results: Vec<(PathBuf, &'static str, Vec<PatternMatch>)> = vec![];
+    let mut unparsed_files = 0;
+    while let Some((file_id, path, data)) = file_receiver.recv().await {
+        match driver::parse_gml(data, &file_id) {
+            Ok(ast) => {
+                let matches = pattern.find(&ast);
+                if !matches.is_empty() {
+                    results.push((path, data, matches));
+                }
+            }
+            Err(_) => unparsed_files += 1,
+        }
+    }
+    let (_, library, _) = file_handle.await.unwrap();
+    let match_count: usize = results.iter().map(|(_, _, matches)| matches.len()).sum();
+
+    // Output the results
+    if json {
+        let output: Vec<serde_json::Value> = results
+            .iter()
+            .flat_map(|(_, data, matches)| matches.iter().map(move |pattern_match| (*data, pattern_match)))
+            .map(|(data, pattern_match)| {
+                let Location(file_id, span) = pattern_match.location();
+                let start = library.location(file_id, span.start()).unwrap();
+                let end = library.location(file_id, span.end()).unwrap();
+                let bindings: HashMap<&str, String> = pattern_match
+                    .bindings()
+                    .map(|(name, binding)| (name, binding.text(data)))
+                    .collect();
+                serde_json::json!({
+                    "file": library.name(file_id).unwrap(),
+                    "start": { "line": start.line_number, "column": start.column_number },
+                    "end": { "line": end.line_number, "column": end.column_number },
+                    "text": &library.source(file_id).unwrap()[span.start()..span.end()],
+                    "bindings": bindings,
+                    "rewrite": rewrite.as_ref().map(|template| pattern_match.rewrite_in(data, template)),
+                })
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&output).unwrap());
+    } else {
+        let writer = StandardStream::stdout(if color { ColorChoice::Always } else { ColorChoice::Auto });
+        let config = codespan_reporting::term::Config::default();
+        for (data, pattern_match) in results
+            .iter()
+            .flat_map(|(_, data, matches)| matches.iter().map(move |pattern_match| (*data, pattern_match)))
+        {
+            let Location(file_id, span) = pattern_match.location();
+            let label = Label::primary(file_id, span);
+            let diagnostic = match rewrite.as_ref() {
+                Some(template) => Diagnostic::note().with_message("Match").with_labels(vec![
+                    label.with_message(format!("replaced with `{}`", pattern_match.rewrite_in(data, template))),
+                ]),
+                None => Diagnostic::note().with_message("Match").with_labels(vec![label]),
+            };
+            codespan_reporting::term::emit(&mut writer.lock(), &config, &library, &diagnostic).unwrap();
+        }
+        println!(
+            "  {}",
+            format!(
+                "🦆 <( Found {} match{} in {} file{}! )",
+                match_count.to_string().bright_green().bold(),
+                if match_count == 1 { "" } else { "es" },
+                results.len().to_string().bright_green().bold(),
+                if results.len() == 1 { "" } else { "s" },
+            )
+            .bold()
+        );
+        if unparsed_files != 0 {
+            println!(
+                "{}",
+                format!("note: {unparsed_files} file(s) could not be parsed, and were not searched.")
+                    .bright_black()
+                    .bold()
+            );
+        }
+    }
+
+    // Apply the rewrites
+    if let Some(template) = rewrite {
+        for (path, data, matches) in results.iter() {
+            if let Err(error) = std::fs::write(path, rewrite_source(data, matches, &template)) {
+                println!(
+                    "{}: Failed to rewrite {}: {error}",
+                    "error".bright_red().bold(),
+                    path.display()
+                );
+                return 1;
+            }
+        }
+    }
+    0
+}
+
+async fn unused(path: Option<PathBuf>, json: bool) -> i32 {
+    if json {
+        colored::control::set_override(false);
+    }
+
+    // Run just the lints for unused symbols, making sure they report even if the config (or their
+    // default level) allows them. `allow` tags still apply.
+    let current_directory =
+        path.unwrap_or_else(|| std::env::current_dir().expect("Cannot access the current directory!"));
+    let (duck, _) = create_duck(&current_directory);
+    let mut lints = LintRegistry::new();
+    lints.register(UnusedFunction);
+    lints.register(UnusedMacro);
+    lints.register(UnusedEnum);
+    lints.register(UnusedEnumMember);
+    lints.register(UnusedGlobalvar);
+    let mut config = duck.config().clone();
+    for lint in lints.iter() {
+        if *config.get_lint_level_setting(lint.tag(), lint.default_level()) == LintLevel::Allow {
+            config.lint_levels.insert(lint.tag().into(), LintLevel::Warn);
+        }
+    }
+    let run_summary = Duck::new_with_lints(config, lints)
+        .run(&current_directory)
+        .await
+        .unwrap();
+    let library = run_summary.files();
+
+    // Collect the symbols out of the diagnostics (which always name them in backticks)
+    let root = current_directory.canonicalize().unwrap_or(current_directory);
+    let mut unparsed_files = 0;
+    let mut symbols = vec![];
+    for diagnostic in run_summary.diagnostics() {
+        let (Some(tag), Some(label)) = (diagnostic.code.as_deref(), diagnostic.labels.first()) else {
+            unparsed_files += 1;
+            continue;
+        };
+        let name = diagnostic.message.split('`').nth(1).unwrap_or_default();
+        let file = library.name(label.file_id).unwrap();
+        let file = Path::new(&file)
+            .strip_prefix(&root)
+            .map_or_else(|_| file.clone(), |path| path.display().to_string());
+        let location = library.location(label.file_id, label.range.start).unwrap();
+        symbols.push((tag, name, file, location.line_number, location.column_number));
+    }
+    symbols.sort_by(|a, b| (&a.2, a.3, a.4).cmp(&(&b.2, b.3, b.4)));
+
+    // Output the results
+    if json {
+        let output: Vec<serde_json::Value> = symbols
+            .iter()
+            .map(|(tag, name, file, line, column)| {
+                serde_json::json!({
+                    "lint": tag,
+                    "name": name,
+                    "file": file,
+                    "line": line,
+                    "column": column,
+                })
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&output).unwrap());
+        return 0;
+    }
+    let headings = [
+        (UnusedFunction.tag(), "Functions and constructors"),
+        (UnusedMacro.tag(), "Macros"),
+        (UnusedEnum.tag(), "Enums"),
+        (UnusedEnumMember.tag(), "Enum members"),
+        (UnusedGlobalvar.tag(), "Globalvars"),
+    ];
+    for (tag, heading) in headings {
+        let group: Vec<_> = symbols.iter().filter(|symbol| symbol.0 == tag).collect();
+        if group.is_empty() {
+            continue;
+        }
+        println!("{} ({})", heading.bold(), group.len());
+        let width = group.iter().map(|symbol| symbol.1.len()).max().unwrap_or_default();
+        for (_, name, file, line, column) in group {
+            println!("  {name:width$}  {}", format!("{file}:{line}:{column}").bright_black());
+        }
+        println!();
+    }
+    println!(
+        "  {}",
+        format!(
+            "🦆 <( Found {} unused symbol{}! )",
+            symbols.len().to_string().yellow().bold(),
+            if symbols.len() == 1 { "" } else { "s" },
+        )
+        .bold()
+    );
+    if unparsed_files != 0 {
+        println!(
+            "{}",
+            format!(
+                "note: {unparsed_files} file(s) could not be parsed, so anything only used within them was reported."
+            )
+            .bright_black()
+            .bold()
+        );
+    }
+    0
+}
+
+/// A row in the tables printed by `duck metrics`, for either a file or a function.
+struct MetricsRow {
+    file: String,
+    /// The name of the function, which is None for rows about files and `(anonymous)` for
+    /// anonymous functions.
+    function: Option<String>,
+    line: usize,
+    parameter_count: usize,
+    metrics: Metrics,
+    longest_line: usize,
+}
+impl MetricsRow {
+    fn to_json(&self) -> serde_json::Value {
+        let mut value = serde_json::json!({
+            "file": self.file,
+            "cyclomatic_complexity": self.metrics.cyclomatic_complexity(),
+            "nesting_depth": self.metrics.nesting_depth(),
+            "statement_count": self.metrics.statement_count(),
+            "longest_line": self.longest_line,
+        });
+        if let Some(function) = self.function.as_ref() {
+            value["function"] = function.as_str().into();
+            value["line"] = self.line.into();
+            value["parameter_count"] = self.parameter_count.into();
+        }
+        value
+    }
+
+    fn to_csv(&self) -> String {
+        let escape = |text: &str| {
+            if text.contains([',', '"', '\n']) {
+                format!("\"{}\"", text.replace('"', "\"\""))
+            } else {
+                text.to_string()
+            }
+        };
+        let (kind, function, parameter_count) = match self.function.as_ref() {
+            Some(function) => ("function", escape(function), self.parameter_count.to_string()),
+            None => ("file", String::new(), String::new()),
+        };
+        format!(
+            "{kind},{},{},{function},{parameter_count},{},{},{},{}",
+            escape(&self.file),
+            self.line,
+            self.metrics.cyclomatic_complexity(),
+            self.metrics.nesting_depth(),
+            self.metrics.statement_count(),
+            self.longest_line,
+        )
+    }
+}
+
+async fn metrics(path: Option<PathBuf>, format: MetricsFormat) -> i32 {
+    if format != MetricsFormat::Text {
+        colored::control::set_override(false);
+    }
+
+    // Measure every file, and every function within them
+    let current_directory =
+        path.unwrap_or_else(|| std::env::current_dir().expect("Cannot access the current directory!"));
+    let (duck, _) = create_duck(&current_directory);
+    let config = duck.config();
+    let root = current_directory
+        .canonicalize()
+        .unwrap_or_else(|_| current_directory.clone());
+    let (path_receiver, _) = driver::start_gml_discovery(&current_directory, config.ignored_file_paths.clone());
+    let (mut file_receiver, file_handle) = driver::start_file_load(path_receiver);
+    let mut files = vec![];
+    let mut functions = vec![];
+    let mut unparsed_files = 0;
+    while let Some((file_id, path, data)) = file_receiver.recv().await {
+        let Ok(ast) = driver::parse_gml(data, &file_id) else {
+            unparsed_files += 1;
+            continue;
+        };
+        let file = path
+            .strip_prefix(&root)
+            .unwrap_or(&path)
+            .display()
+            .to_string()
+            .replace('\\', "/");
+        let file_metrics = FileMetrics::new(&ast);
+        for function in file_metrics.functions() {
+            let Location(_, span) = function.location();
+            functions.push(MetricsRow {
+                file: file.clone(),
+                function: Some(function.name().unwrap_or("(anonymous)").to_string()),
+                line: data[..span.start()].matches('\n').count() + 1,
+                parameter_count: function.parameter_count(),
+                metrics: *function.metrics(),
+                longest_line: longest_line(data, span.start(), span.end()),
+            });
+        }
+        files.push(MetricsRow {
+            file,
+            function: None,
+            line: 1,
+            parameter_count: 0,
+            metrics: *file_metrics.metrics(),
+            longest_line: longest_line(data, 0, data.len()),
+        });
+    }
+    file_handle.await.unwrap();
+    files.sort_by(|a, b| a.file.cmp(&b.file));
+    functions.sort_by(|a, b| (&a.file, a.line).cmp(&(&b.file, b.line)));
+
+    // Output the results
+    match format {
+        MetricsFormat::Json => {
+            let output = serde_json::json!({
+                "files": files.iter().map(MetricsRow::to_json).collect::<Vec<_>>(),
+                "functions": functions.iter().map(MetricsRow::to_json).collect::<Vec<_>>(),
+            });
+            println!("{}", serde_json::to_string_pretty(&output).unwrap());
+            return 0;
+        }
+        MetricsFormat::Csv => {
+            println!(
+                "kind,file,line,function,parameter_count,cyclomatic_complexity,nesting_depth,statement_count,longest_line"
+            );
+            for row in files.iter().chain(functions.iter()) {
+                println!("{}", row.to_csv());
+            }
+            return 0;
+        }
+        MetricsFormat::Text => {}
+    }
+    let limits = [
+        config.max_complexity,
+        config.max_nesting_depth,
+        config.max_statements,
+        config.max_line_length,
+        config.max_arguments,
+    ];
+    let headings = ["complexity", "nesting", "statements", "longest line", "parameters"];
+    for (title, rows) in [("Files", &files), ("Functions", &functions)] {
+        if rows.is_empty() {
+            continue;
+        }
+        let is_function = title == "Functions";
+        let labels: Vec<String> = rows
+            .iter()
+            .map(|row| match row.function.as_ref() {
+                Some(function) => format!("{function}  {}", format!("{}:{}", row.file, row.line).bright_black()),
+                None => row.file.clone(),
+            })
+            .collect();
+        let width = rows
+            .iter()
+            .map(|row| match row.function.as_ref() {
+                Some(function) => function.len() + row.file.len() + row.line.to_string().len() + 3,
+                None => row.file.len(),
+            })
+            .max()
+            .unwrap_or_default();
+        let columns = if is_function { 5 } else { 4 };
+        println!("{} ({})", title.bold(), rows.len());
+        print!("  {:width$}", "");
+        for heading in &headings[..columns] {
+            print!("  {}", heading.bright_black());
+        }
+        println!();
+        for (row, label) in rows.iter().zip(labels) {
+            let visible_width = match row.function.as_ref() {
+                Some(function) => function.len() + row.file.len() + row.line.to_string().len() + 3,
+                None => row.file.len(),
+            };
+            print!("  {label}{}", " ".repeat(width - visible_width));
+            let values = [
+                row.metrics.cyclomatic_complexity(),
+                row.metrics.nesting_depth(),
+                row.metrics.statement_count(),
+                row.longest_line,
+                row.parameter_count,
+            ];
+            for ((value, limit), heading) in values.iter().zip(limits).zip(headings).take(columns) {
+                let text = format!("{value:>width$}", width = heading.len());
+                if *value > limit {
+                    print!("  {}", text.yellow().bold());
+                } else {
+                    print!("  {text}");
+                }
+            }
+            println!();
+        }
+        println!();
+    }
+    println!(
+        "  {}",
+        format!(
+            "🦆 <( Measured {} file{} and {} function{}! )",
+            files.len().to_string().bright_green().bold(),
+            if files.len() == 1 { "" } else { "s" },
+            functions.len().to_string().bright_green().bold(),
+            if functions.len() == 1 { "" } else { "s" },
+        )
+        .bold()
+    );
+    if unparsed_files != 0 {
+        println!(
+            "{}",
+            format!("note: {unparsed_files} file(s) could not be parsed, and were not measured.")
+                .bright_black()
+                .bold()
+        );
+    }
+    0
+}
+
 fn create_duck(current_directory: &Path) -> (Duck, ConfigUsage) {
     if let Ok(text) = std::fs::read_to_string(current_directory.join(".duck.toml")) {
         match toml::from_str::<Config>(&text) {
@@ -0,0 +1,132 @@
+use crate::{
+    analyze::{ControlFlowGraph, Metrics},
+    parse::*,
+};
+use hashbrown::HashSet;
+use std::cell::OnceCell;
+
+/// A series of statements that run together: either the body of a function, or the top level of a
+/// file (which is the body of its event or script).
+///
+/// Analyses of the body, such as its [ControlFlowGraph], are built the first time they are asked
+/// for and then shared between every lint that visits the body.
+#[derive(Debug)]
+pub struct Body<'a> {
+    stmts: &'a [Stmt],
+    function: Option<&'a Function>,
+    cfg: OnceCell<ControlFlowGraph<'a>>,
+    metrics: OnceCell<Metrics>,
+    locals: OnceCell<HashSet<String>>,
+}
+impl<'a> Body<'a> {
+    /// Creates the body for the top level statements of a file.
+    pub fn new(stmts: &'a [Stmt]) -> Self {
+        Self {
+            stmts,
+            function: None,
+            cfg: OnceCell::new(),
+            metrics: OnceCell::new(),
+            locals: OnceCell::new(),
+        }
+    }
+
+    /// Creates the body of a function.
+    pub fn function(function: &'a Function) -> Self {
+        Self {
+            stmts: match function.body.kind() {
+                StmtKind::Block(block) => &block.body,
+                _ => std::slice::from_ref(&function.body),
+            },
+            function: Some(function),
+            cfg: OnceCell::new(),
+            metrics: OnceCell::new(),
+            locals: OnceCell::new(),
+        }
+    }
+
+    /// Returns the statements in this body.
+    pub fn stmts(&self) -> &'a [Stmt] {
+        self.stmts
+    }
+
+    /// Returns the function this is the body of, or None if this is the top level of a file.
+    pub fn as_function(&self) -> Option<&'a Function> {
+        self.function
+    }
+
+    /// Returns where to point when reporting on the body as a whole: the name of its function (or
+    /// the whole body, if the function is anonymous), or otherwise its first statement. Returns
+    /// None for a file without any statements.
+    pub fn location(&self) -> Option<Location> {
+        match self.function {
+            Some(Function {
+                name: Some(name), body, ..
+            }) => Some(Location(body.file_id(), name.span)),
+            Some(Function { body, .. }) => Some(Location(body.file_id(), body.span())),
+            None => self.stmts.first().map(|stmt| Location(stmt.file_id(), stmt.span())),
+        }
+    }
+
+    /// Returns how the body is referred to in messages: the function's name in backticks, "this
+    /// function" if it is anonymous, or "this file" for the top level of a file.
+    pub fn describe(&self) -> String {
+        match self.function {
+            Some(Function { name: Some(name), .. }) => format!("`{name}`"),
+            Some(_) => "this function".into(),
+            None => "this file".into(),
+        }
+    }
+
+    /// Returns the control flow graph of the body.
+    pub fn cfg(&self) -> &ControlFlowGraph<'a> {
+        self.cfg.get_or_init(|| ControlFlowGraph::new(self.stmts))
+    }
+
+    /// Returns the complexity metrics of the body.
+    pub fn metrics(&self) -> Metrics {
+        *self.metrics.get_or_init(|| Metrics::new(self.stmts))
+    }
+
+    /// Returns whether the name refers to one of the body's parameters or local variables, rather
+    /// than anything global. Locals declared anywhere in the body count (including the variable of
+    /// a `catch`), as gml scopes them to the whole function, while those in nested functions don't.
+    pub fn is_local(&self, name: &str) -> bool {
+        self.locals
+            .get_or_init(|| {
+                let mut locals: HashSet<String> = self
+                    .function
+                    .iter()
+                    .flat_map(|function| function.parameters.iter())
+                    .map(|parameter| parameter.name().to_string())
+                    .collect();
+                for stmt in self.stmts {
+                    Self::collect_locals(stmt, &mut locals);
+                }
+                locals
+            })
+            .contains(name)
+    }
+
+    fn collect_locals(stmt: &Stmt, locals: &mut HashSet<String>) {
+        fn visit_expr(expr: &Expr, locals: &mut HashSet<String>) {
+            if matches!(expr.kind(), ExprKind::Function(_)) {
+                return;
+            }
+            expr.visit_child_stmts(|stmt| Body::collect_locals(stmt, locals));
+            expr.visit_child_exprs(|expr| visit_expr(expr, locals));
+        }
+        match stmt.kind() {
+            StmtKind::LocalVariables(LocalVariables { declarations }) => {
+                locals.extend(declarations.iter().map(|field| field.name().to_string()));
+            }
+            StmtKind::TryCatch(TryCatch { catch_expr, .. }) => {
+                if let Some(identifier) = catch_expr.kind().as_identifier() {
+                    locals.insert(identifier.lexeme.clone());
+                }
+            }
+            _ => {}
+        }
+        stmt.visit_child_stmts(|stmt| Self::collect_locals(stmt, locals));
+        stmt.visit_child_exprs(|expr| visit_expr(expr, locals));
+    }
+}
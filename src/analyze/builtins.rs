@@ -0,0 +1,140 @@
+use crate::analyze::Arity;
+use hashbrown::HashMap;
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+
+/// The signatures of the functions built into GameMaker's runtime that duck knows about, keyed by
+/// name. This is not every built-in function, so a name missing from it is not proof that a
+/// function doesn't exist.
+pub static GML_FUNCTIONS: Lazy<HashMap<String, BuiltinFunction>> =
+    Lazy::new(|| serde_json::from_str(include_str!("../../assets/gml_functions.json")).unwrap());
+
+/// The signature of a function built into GameMaker's runtime.
+#[derive(Debug, PartialEq, Clone, Deserialize)]
+pub struct BuiltinFunction {
+    parameters: Option<Vec<BuiltinParameter>>,
+    #[serde(default)]
+    variadic: bool,
+    #[serde(default)]
+    returns: GmlType,
+    #[serde(default)]
+    deprecated: bool,
+}
+impl BuiltinFunction {
+    /// Returns the parameters the function declares. Functions whose parameters are not known
+    /// return none.
+    pub fn parameters(&self) -> &[BuiltinParameter] {
+        self.parameters.as_deref().unwrap_or_default()
+    }
+
+    /// Returns the number of arguments the function can be called with, if its parameters are
+    /// known.
+    pub fn arity(&self) -> Option<Arity> {
+        let parameters = self.parameters.as_ref()?;
+        let minimum = parameters.iter().filter(|parameter| !parameter.optional).count();
+        let maximum = (!self.variadic).then_some(parameters.len());
+        Some(Arity::new(minimum, maximum))
+    }
+
+    /// Returns the type of the value the function returns.
+    pub fn returns(&self) -> GmlType {
+        self.returns
+    }
+
+    /// Returns whether the function has been deprecated by GameMaker.
+    pub fn is_deprecated(&self) -> bool {
+        self.deprecated
+    }
+
+    /// Formats the function's signature as it would be called, wrapping optional parameters in
+    /// brackets (ie: `array_create(size, [value])`).
+    pub fn signature(&self, name: &str) -> String {
+        let mut parameters: Vec<String> = self
+            .parameters()
+            .iter()
+            .map(|parameter| {
+                if parameter.optional {
+                    format!("[{}]", parameter.name)
+                } else {
+                    parameter.name.clone()
+                }
+            })
+            .collect();
+        if self.variadic {
+            parameters.push("...".into());
+        }
+        format!("{name}({})", parameters.join(", "))
+    }
+}
+
+/// A parameter of a [BuiltinFunction].
+#[derive(Debug, PartialEq, Clone, Deserialize)]
+pub struct BuiltinParameter {
+    name: String,
+    #[serde(rename = "type")]
+    ty: GmlType,
+    #[serde(default)]
+    optional: bool,
+}
+impl BuiltinParameter {
+    /// Returns the name the parameter is documented with.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the type of value the parameter expects.
+    pub fn ty(&self) -> GmlType {
+        self.ty
+    }
+
+    /// Returns whether the parameter can be omitted.
+    pub fn is_optional(&self) -> bool {
+        self.optional
+    }
+}
+
+/// The types the runtime distinguishes between in [BuiltinFunction] signatures.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GmlType {
+    /// Any value is accepted.
+    Any,
+    /// A number.
+    Real,
+    /// A string.
+    String,
+    /// A boolean. Booleans are numbers in gml, so any real is accepted as well.
+    Bool,
+    /// An array.
+    Array,
+    /// A struct.
+    Struct,
+    /// A function or method.
+    Function,
+    /// A reference to an asset, such as a sprite or an object.
+    Asset,
+    /// A handle to a runtime resource, such as a data structure, instance or surface.
+    Id,
+    /// A raw pointer, such as a texture.
+    Pointer,
+    /// No value (used only for return types).
+    #[default]
+    Void,
+}
+impl std::fmt::Display for GmlType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            GmlType::Any => "any",
+            GmlType::Real => "real",
+            GmlType::String => "string",
+            GmlType::Bool => "bool",
+            GmlType::Array => "array",
+            GmlType::Struct => "struct",
+            GmlType::Function => "function",
+            GmlType::Asset => "asset",
+            GmlType::Id => "id",
+            GmlType::Pointer => "pointer",
+            GmlType::Void => "void",
+        })
+    }
+}
@@ -0,0 +1,582 @@
+use crate::{analyze::Evaluator, parse::*};
+use hashbrown::HashMap;
+
+/// A control flow graph for a series of statements, such as the body of a function or event.
+///
+/// The graph is made of [BasicBlock]s, which each hold a series of [Step]s that always run
+/// together, followed by edges to every block that control can flow to next. Every graph has an
+/// entry block, where control starts, and an empty exit block, which every `return`, `exit` and
+/// uncaught `throw` (as well as the end of the statements) flows to.
+///
+/// Conditions that are constant (such as `true` or `1 > 0`) only ever take one path. Statements
+/// within expressions (such as the body of a function declared within the statements) are not a
+/// part of the graph, as they do not run when the statements do.
+///
+/// ```
+/// use duck::{analyze::ControlFlowGraph, parse::Parser};
+/// let ast = Parser::new("if foo { return; } bar();", 0)
+///     .into_ast()
+///     .unwrap();
+/// let cfg = ControlFlowGraph::new(ast.stmts());
+/// let call = cfg.block_of(&ast.stmts()[1]).unwrap();
+/// assert!(cfg.is_reachable(call));
+/// assert!(cfg.dominates(cfg.entry(), call));
+/// ```
+#[derive(Debug)]
+pub struct ControlFlowGraph<'a> {
+    blocks: Vec<BasicBlock<'a>>,
+    end: BlockId,
+    stmt_blocks: HashMap<usize, BlockId>,
+    dominators: Vec<Option<BlockId>>,
+}
+impl<'a> ControlFlowGraph<'a> {
+    /// Builds the graph for the statements.
+    pub fn new(stmts: &'a [Stmt]) -> Self {
+        let mut builder = Builder::default();
+        for stmt in stmts {
+            builder.stmt(stmt);
+        }
+        builder.edge(builder.current, EXIT);
+        let mut cfg = Self {
+            end: builder.current,
+            blocks: builder.blocks,
+            stmt_blocks: builder.stmt_blocks,
+            dominators: vec![],
+        };
+        cfg.dominators = cfg.compute_dominators();
+        cfg
+    }
+
+    /// Returns the block control flow starts in.
+    pub fn entry(&self) -> BlockId {
+        ENTRY
+    }
+
+    /// Returns the empty block control flow ends in.
+    pub fn exit(&self) -> BlockId {
+        EXIT
+    }
+
+    /// Returns the block control is in after the last statement, having not left early through a
+    /// `return`, `exit` or `throw`. If this block can't be reached, the statements never finish on
+    /// their own.
+    pub fn end(&self) -> BlockId {
+        self.end
+    }
+
+    /// Returns the block with the given id.
+    pub fn block(&self, id: BlockId) -> &BasicBlock<'a> {
+        &self.blocks[id.0]
+    }
+
+    /// Returns an iterator over every block in the graph, in the order they were created.
+    pub fn blocks(&self) -> impl Iterator<Item = (BlockId, &BasicBlock<'a>)> {
+        self.blocks
+            .iter()
+            .enumerate()
+            .map(|(index, block)| (BlockId(index), block))
+    }
+
+    /// Returns the block the statement starts in, if it is a part of the graph. Statements that
+    /// contain others, such as an if statement, start in the block that evaluates their condition.
+    pub fn block_of(&self, stmt: &Stmt) -> Option<BlockId> {
+        self.stmt_blocks.get(&address(stmt)).copied()
+    }
+
+    /// Returns whether there is any path from the entry to the block.
+    pub fn is_reachable(&self, id: BlockId) -> bool {
+        id == ENTRY || self.dominators[id.0].is_some()
+    }
+
+    /// Returns the immediate dominator of the block: the closest block that every path from the
+    /// entry to this block must pass through. Returns None for the entry, and for blocks that can't
+    /// be reached.
+    pub fn immediate_dominator(&self, id: BlockId) -> Option<BlockId> {
+        self.dominators[id.0]
+    }
+
+    /// Returns whether every path from the entry to `block` passes through `dominator`. Blocks
+    /// dominate themselves. Nothing dominates a block that can't be reached.
+    pub fn dominates(&self, dominator: BlockId, block: BlockId) -> bool {
+        if !self.is_reachable(block) {
+            return false;
+        }
+        let mut current = Some(block);
+        while let Some(id) = current {
+            if id == dominator {
+                return true;
+            }
+            current = self.dominators[id.0];
+        }
+        false
+    }
+
+    /// Finds the immediate dominator of every block with the iterative algorithm described in
+    /// "A Simple, Fast Dominance Algorithm" (Cooper, Harvey and Kennedy).
+    fn compute_dominators(&self) -> Vec<Option<BlockId>> {
+        // Number every reachable block by its postorder
+        let mut postorder = vec![];
+        let mut visited = vec![false; self.blocks.len()];
+        let mut stack = vec![(ENTRY, 0)];
+        visited[ENTRY.0] = true;
+        while let Some((id, next_successor)) = stack.pop() {
+            match self.block(id).successors.get(next_successor) {
+                Some(successor) => {
+                    stack.push((id, next_successor + 1));
+                    if !visited[successor.0] {
+                        visited[successor.0] = true;
+                        stack.push((*successor, 0));
+                    }
+                }
+                None => postorder.push(id),
+            }
+        }
+        let mut postorder_number = vec![usize::MAX; self.blocks.len()];
+        for (number, id) in postorder.iter().enumerate() {
+            postorder_number[id.0] = number;
+        }
+
+        let mut dominators = vec![None; self.blocks.len()];
+        dominators[ENTRY.0] = Some(ENTRY);
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for id in postorder.iter().rev().filter(|id| **id != ENTRY) {
+                let mut new_dominator = None;
+                for predecessor in self.block(*id).predecessors.iter() {
+                    if dominators[predecessor.0].is_none() {
+                        continue;
+                    }
+                    new_dominator = Some(match new_dominator {
+                        None => *predecessor,
+                        Some(mut other) => {
+                            let mut finger = *predecessor;
+                            while finger != other {
+                                while postorder_number[finger.0] < postorder_number[other.0] {
+                                    finger = dominators[finger.0].unwrap();
+                                }
+                                while postorder_number[other.0] < postorder_number[finger.0] {
+                                    other = dominators[other.0].unwrap();
+                                }
+                            }
+                            finger
+                        }
+                    });
+                }
+                if new_dominator.is_some() && dominators[id.0] != new_dominator {
+                    dominators[id.0] = new_dominator;
+                    changed = true;
+                }
+            }
+        }
+        dominators[ENTRY.0] = None;
+        dominators
+    }
+}
+
+/// The id of a [BasicBlock] within a [ControlFlowGraph].
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy)]
+pub struct BlockId(usize);
+impl BlockId {
+    /// Returns the index of the block in the graph.
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+/// A series of [Step]s that always run together, one after the other.
+#[derive(Debug, Default)]
+pub struct BasicBlock<'a> {
+    steps: Vec<Step<'a>>,
+    successors: Vec<BlockId>,
+    predecessors: Vec<BlockId>,
+}
+impl<'a> BasicBlock<'a> {
+    /// Returns the steps in this block, in the order they run.
+    pub fn steps(&self) -> &[Step<'a>] {
+        &self.steps
+    }
+
+    /// Returns the blocks control can flow to after this one.
+    pub fn successors(&self) -> &[BlockId] {
+        &self.successors
+    }
+
+    /// Returns the blocks control can flow to this one from.
+    pub fn predecessors(&self) -> &[BlockId] {
+        &self.predecessors
+    }
+}
+
+/// Something that runs within a [BasicBlock].
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Step<'a> {
+    /// A statement that runs in its entirety, such as an assignment, a call, or a `return`.
+    Stmt(&'a Stmt),
+    /// An expression that decides where control flows next, such as the condition of an if
+    /// statement or the identity of a switch statement, along with the statement it belongs to.
+    Branch(&'a Stmt, &'a Expr),
+}
+impl<'a> Step<'a> {
+    /// Returns the statement this step belongs to.
+    pub fn stmt(&self) -> &'a Stmt {
+        match self {
+            Step::Stmt(stmt) | Step::Branch(stmt, _) => stmt,
+        }
+    }
+}
+
+const ENTRY: BlockId = BlockId(0);
+const EXIT: BlockId = BlockId(1);
+
+/// Statements are keyed by their address, as their ids are not unique in tests.
+fn address(stmt: &Stmt) -> usize {
+    stmt as *const Stmt as usize
+}
+
+/// Returns the value of a condition that is constant, which only ever takes one path.
+fn constant_condition(expr: &Expr) -> Option<bool> {
+    Evaluator::new().evaluate(expr)?.as_bool()
+}
+
+/// Where `break` and `continue` statements lead within a loop or switch statement.
+struct Breakable {
+    break_target: BlockId,
+    /// None for switch statements, where `continue` applies to the surrounding loop.
+    continue_target: Option<BlockId>,
+    /// The number of try statements surrounding the loop or switch.
+    try_depth: usize,
+}
+
+/// A try statement that is currently being built.
+struct TryFrame {
+    /// The start of the catch body, while the try body is being built.
+    catch: Option<BlockId>,
+    /// The start of the finally body, if there is one.
+    finally: Option<BlockId>,
+    /// Every target that a jump routed through the finally body must continue on to, along with
+    /// the try depth of the target.
+    pending_jumps: Vec<(BlockId, usize)>,
+}
+
+struct Builder<'a> {
+    blocks: Vec<BasicBlock<'a>>,
+    current: BlockId,
+    stmt_blocks: HashMap<usize, BlockId>,
+    breakables: Vec<Breakable>,
+    try_frames: Vec<TryFrame>,
+}
+impl Default for Builder<'_> {
+    fn default() -> Self {
+        Self {
+            blocks: vec![BasicBlock::default(), BasicBlock::default()],
+            current: ENTRY,
+            stmt_blocks: HashMap::new(),
+            breakables: vec![],
+            try_frames: vec![],
+        }
+    }
+}
+impl<'a> Builder<'a> {
+    fn new_block(&mut self) -> BlockId {
+        self.blocks.push(BasicBlock::default());
+        BlockId(self.blocks.len() - 1)
+    }
+
+    fn edge(&mut self, from: BlockId, to: BlockId) {
+        if !self.blocks[from.0].successors.contains(&to) {
+            self.blocks[from.0].successors.push(to);
+            self.blocks[to.0].predecessors.push(from);
+        }
+    }
+
+    fn push(&mut self, step: Step<'a>) {
+        self.blocks[self.current.0].steps.push(step);
+    }
+
+    /// Ends the current block with a jump to the target, after which nothing can be reached.
+    fn jump(&mut self, target: BlockId, target_try_depth: usize) {
+        self.route(self.current, target, target_try_depth);
+        self.current = self.new_block();
+    }
+
+    /// Adds an edge from the block to the target, passing through the finally body of every try
+    /// statement that is left along the way.
+    fn route(&mut self, from: BlockId, target: BlockId, target_try_depth: usize) {
+        let finally_frame = (target_try_depth..self.try_frames.len())
+            .rev()
+            .find(|index| self.try_frames[*index].finally.is_some());
+        match finally_frame {
+            Some(index) => {
+                let frame = &mut self.try_frames[index];
+                let finally = frame.finally.unwrap();
+                if !frame.pending_jumps.contains(&(target, target_try_depth)) {
+                    frame.pending_jumps.push((target, target_try_depth));
+                }
+                self.edge(from, finally);
+            }
+            None => self.edge(from, target),
+        }
+    }
+
+    /// Returns where a `throw` leads: the innermost catch body, or the exit.
+    fn throw_target(&self) -> (BlockId, usize) {
+        self.try_frames
+            .iter()
+            .enumerate()
+            .rev()
+            .find_map(|(index, frame)| frame.catch.map(|catch| (catch, index + 1)))
+            .unwrap_or((EXIT, 0))
+    }
+
+    fn stmt(&mut self, stmt: &'a Stmt) {
+        self.stmt_blocks.insert(address(stmt), self.current);
+        match stmt.kind() {
+            StmtKind::Block(block) => {
+                for stmt in block.body.iter() {
+                    self.stmt(stmt);
+                }
+            }
+            StmtKind::If(If {
+                condition,
+                body,
+                else_stmt,
+                ..
+            }) => {
+                self.push(Step::Branch(stmt, condition));
+                let branch = self.current;
+                let value = constant_condition(condition);
+                let after = self.new_block();
+                self.current = self.new_block();
+                if value != Some(false) {
+                    self.edge(branch, self.current);
+                }
+                self.stmt(body);
+                self.edge(self.current, after);
+                match else_stmt {
+                    Some(else_stmt) => {
+                        self.current = self.new_block();
+                        if value != Some(true) {
+                            self.edge(branch, self.current);
+                        }
+                        self.stmt(else_stmt);
+                        self.edge(self.current, after);
+                    }
+                    None if value != Some(true) => self.edge(branch, after),
+                    None => {}
+                }
+                self.current = after;
+            }
+            StmtKind::While(While { condition, body }) => {
+                let header = self.new_block();
+                self.edge(self.current, header);
+                self.current = header;
+                self.push(Step::Branch(stmt, condition));
+                self.loop_body(body, header, header, constant_condition(condition));
+            }
+            StmtKind::For(For {
+                initializer,
+                condition,
+                iterator,
+                body,
+            }) => {
+                self.stmt(initializer);
+                let header = self.new_block();
+                let iteration = self.new_block();
+                self.edge(self.current, header);
+                self.current = header;
+                self.push(Step::Branch(stmt, condition));
+                let after = self.loop_body(body, header, iteration, constant_condition(condition));
+                self.current = iteration;
+                self.stmt(iterator);
+                self.edge(self.current, header);
+                self.current = after;
+            }
+            StmtKind::Repeat(Repeat {
+                tick_counts: expr,
+                body,
+            })
+            | StmtKind::With(With { identity: expr, body }) => {
+                self.push(Step::Branch(stmt, expr));
+                let header = self.new_block();
+                self.edge(self.current, header);
+                self.current = header;
+                self.loop_body(body, header, header, None);
+            }
+            StmtKind::DoUntil(DoUntil { body, condition }) => {
+                let start = self.new_block();
+                let check = self.new_block();
+                let after = self.new_block();
+                self.edge(self.current, start);
+                self.current = start;
+                self.breakable(body, after, Some(check));
+                self.edge(self.current, check);
+                self.current = check;
+                self.push(Step::Branch(stmt, condition));
+                let value = constant_condition(condition);
+                if value != Some(true) {
+                    self.edge(check, start);
+                }
+                if value != Some(false) {
+                    self.edge(check, after);
+                }
+                self.current = after;
+            }
+            StmtKind::Switch(Switch {
+                identity,
+                cases,
+                default_case,
+            }) => {
+                self.push(Step::Branch(stmt, identity));
+                let dispatch = self.current;
+                let after = self.new_block();
+                self.breakables.push(Breakable {
+                    break_target: after,
+                    continue_target: None,
+                    try_depth: self.try_frames.len(),
+                });
+                let bodies = cases
+                    .iter()
+                    .map(|case| case.iter_body_statements().collect::<Vec<_>>())
+                    .chain(default_case.iter().map(|body| body.iter().collect()));
+                let mut previous_case = None;
+                for body in bodies {
+                    let case = self.new_block();
+                    self.edge(dispatch, case);
+                    if let Some(previous_case) = previous_case {
+                        // Cases without a break fall through into the next one
+                        self.edge(previous_case, case);
+                    }
+                    self.current = case;
+                    for stmt in body {
+                        self.stmt(stmt);
+                    }
+                    previous_case = Some(self.current);
+                }
+                self.breakables.pop();
+                if let Some(previous_case) = previous_case {
+                    self.edge(previous_case, after);
+                }
+                if default_case.is_none() {
+                    self.edge(dispatch, after);
+                }
+                self.current = after;
+            }
+            StmtKind::TryCatch(TryCatch {
+                try_body,
+                catch_body,
+                finally_body,
+                ..
+            }) => {
+                let catch = self.new_block();
+                let finally = finally_body.as_ref().map(|_| self.new_block());
+                let after = self.new_block();
+                self.try_frames.push(TryFrame {
+                    catch: Some(catch),
+                    finally,
+                    pending_jumps: vec![],
+                });
+
+                // Any statement in the try body could throw (including the first), so the block
+                // before it and every block within it lead to the catch
+                self.edge(self.current, catch);
+                let first_try_block = self.new_block();
+                self.edge(self.current, first_try_block);
+                self.current = first_try_block;
+                self.stmt(try_body);
+                let try_end = self.current;
+                for id in first_try_block.0..self.blocks.len() {
+                    self.edge(BlockId(id), catch);
+                }
+
+                self.try_frames.last_mut().unwrap().catch = None;
+                let normal_end = finally.unwrap_or(after);
+                self.edge(try_end, normal_end);
+                self.current = catch;
+                self.stmt(catch_body);
+                self.edge(self.current, normal_end);
+
+                let frame = self.try_frames.pop().unwrap();
+                if let (Some(finally_body), Some(finally)) = (finally_body, finally) {
+                    self.current = finally;
+                    self.stmt(finally_body);
+                    self.edge(self.current, after);
+                    for (target, target_try_depth) in frame.pending_jumps {
+                        self.route(self.current, target, target_try_depth);
+                    }
+                }
+                self.current = after;
+            }
+            StmtKind::Return(_) | StmtKind::Exit => {
+                self.push(Step::Stmt(stmt));
+                self.jump(EXIT, 0);
+            }
+            StmtKind::Throw(_) => {
+                self.push(Step::Stmt(stmt));
+                let (target, target_try_depth) = self.throw_target();
+                self.jump(target, target_try_depth);
+            }
+            StmtKind::Break => {
+                self.push(Step::Stmt(stmt));
+                let (target, target_try_depth) = self
+                    .breakables
+                    .last()
+                    .map_or((EXIT, 0), |breakable| (breakable.break_target, breakable.try_depth));
+                self.jump(target, target_try_depth);
+            }
+            StmtKind::Continue => {
+                self.push(Step::Stmt(stmt));
+                let (target, target_try_depth) = self
+                    .breakables
+                    .iter()
+                    .rev()
+                    .find_map(|breakable| breakable.continue_target.map(|target| (target, breakable.try_depth)))
+                    .unwrap_or((EXIT, 0));
+                self.jump(target, target_try_depth);
+            }
+            StmtKind::Enum(_)
+            | StmtKind::Macro(_)
+            | StmtKind::Globalvar(_)
+            | StmtKind::LocalVariables(_)
+            | StmtKind::Delete(_)
+            | StmtKind::Assignment(_)
+            | StmtKind::Expr(_) => self.push(Step::Stmt(stmt)),
+        }
+    }
+
+    /// Builds the body of a loop whose condition was just evaluated in `header`, returning the
+    /// block after the loop (which the current block is set to). Loops whose condition is the
+    /// literal `true` never end on their own, and loops whose condition is `false` never run
+    /// their body.
+    fn loop_body(
+        &mut self,
+        body: &'a Stmt,
+        header: BlockId,
+        continue_target: BlockId,
+        condition: Option<bool>,
+    ) -> BlockId {
+        let after = self.new_block();
+        if condition != Some(true) {
+            self.edge(header, after);
+        }
+        self.current = self.new_block();
+        if condition != Some(false) {
+            self.edge(header, self.current);
+        }
+        self.breakable(body, after, Some(continue_target));
+        self.edge(self.current, continue_target);
+        self.current = after;
+        after
+    }
+
+    /// Builds a statement that `break` and `continue` statements within lead out of.
+    fn breakable(&mut self, body: &'a Stmt, break_target: BlockId, continue_target: Option<BlockId>) {
+        self.breakables.push(Breakable {
+            break_target,
+            continue_target,
+            try_depth: self.try_frames.len(),
+        });
+        self.stmt(body);
+        self.breakables.pop();
+    }
+}
@@ -0,0 +1,261 @@
+use crate::{
+    analyze::{DeclarationKind, Project},
+    parse::*,
+};
+
+/// How deeply constants may refer to one another before giving up on finding their value. This
+/// stops constants that (eventually) refer to themselves from recursing forever.
+const MAX_DEPTH: usize = 1024;
+
+/// Folds constant expressions down into the [Value] they will always produce.
+///
+/// Without a [Project], only literals and the operators between them are understood. With one,
+/// enum members and simple macros (whose body is a single constant, such as `#macro SIZE 16`) are
+/// resolved as well.
+///
+/// ```
+/// use duck::{
+///     analyze::{Evaluator, Value},
+///     parse::Parser,
+/// };
+/// let expr = Parser::new("(1 + 2) * 4 == 12", 0).expr().unwrap();
+/// assert_eq!(Evaluator::new().evaluate(&expr), Some(Value::Bool(true)));
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Evaluator<'a> {
+    project: Option<&'a Project>,
+    depth: usize,
+}
+impl<'a> Evaluator<'a> {
+    /// Creates an evaluator that only understands literals.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an evaluator that can also resolve the enum members and macros in the project.
+    pub fn with_project(project: &'a Project) -> Self {
+        Self {
+            project: Some(project),
+            depth: 0,
+        }
+    }
+
+    /// Returns the value the expression always produces, or None if it isn't constant (or its
+    /// value can't be worked out, such as when it divides by zero).
+    pub fn evaluate(&self, expr: &Expr) -> Option<Value> {
+        match expr.kind() {
+            ExprKind::Literal(literal) => match literal {
+                Literal::True => Some(Value::Bool(true)),
+                Literal::False => Some(Value::Bool(false)),
+                Literal::Undefined => Some(Value::Undefined),
+                Literal::Noone => Some(Value::Real(-4.0)),
+                Literal::String(string) => Some(Value::String(string.clone())),
+                literal => literal.as_number().map(Value::Real),
+            },
+            ExprKind::Grouping(Grouping { inner, .. }) => self.evaluate(inner),
+            ExprKind::Unary(Unary { op, right }) => {
+                let right = self.evaluate(right)?;
+                match op {
+                    UnaryOp::Not(_) => right.as_bool().map(|value| Value::Bool(!value)),
+                    UnaryOp::Positive(_) => right.as_real().map(Value::Real),
+                    UnaryOp::Negative(_) => right.as_real().map(|value| Value::Real(-value)),
+                    UnaryOp::BitwiseNot(_) => right.as_real().map(|value| Value::Real(!(value as i64) as f64)),
+                    UnaryOp::Increment(_) | UnaryOp::Decrement(_) => None,
+                }
+            }
+            ExprKind::Evaluation(Evaluation { left, op, right }) => {
+                Self::evaluation(self.evaluate(left)?, op, self.evaluate(right)?)
+            }
+            ExprKind::Logical(Logical { left, op, right }) => {
+                let left = self.evaluate(left).and_then(|value| value.as_bool());
+                let right = self.evaluate(right).and_then(|value| value.as_bool());
+                // One side can decide the result on its own, whatever the other side is
+                match (op, left, right) {
+                    (LogicalOp::And(_), Some(false), _) | (LogicalOp::And(_), _, Some(false)) => {
+                        Some(Value::Bool(false))
+                    }
+                    (LogicalOp::Or(_), Some(true), _) | (LogicalOp::Or(_), _, Some(true)) => Some(Value::Bool(true)),
+                    (LogicalOp::And(_), Some(left), Some(right)) => Some(Value::Bool(left && right)),
+                    (LogicalOp::Or(_), Some(left), Some(right)) => Some(Value::Bool(left || right)),
+                    (LogicalOp::Xor(_), Some(left), Some(right)) => Some(Value::Bool(left != right)),
+                    _ => None,
+                }
+            }
+            ExprKind::Equality(Equality { left, op, right }) => {
+                Self::equality(&self.evaluate(left)?, op, &self.evaluate(right)?).map(Value::Bool)
+            }
+            ExprKind::Identifier(identifier) => self.macro_value(&identifier.lexeme),
+            kind => {
+                let (left, right) = kind.as_dot_access()?;
+                let left = left.as_identifier()?;
+                self.enum_member(&format!("{}.{}", left.lexeme, right.lexeme))
+                    .map(Value::Real)
+            }
+        }
+    }
+
+    /// Returns the value of the enum member with the given name (ie: `Enum.Member`). Members
+    /// without an initializer are one more than the member before them.
+    pub fn enum_member(&self, name: &str) -> Option<f64> {
+        let evaluator = self.deeper()?;
+        let [member] = evaluator.project?.declarations(name) else {
+            return None;
+        };
+        if member.kind() != DeclarationKind::EnumMember {
+            return None;
+        }
+        match (member.initializer(), member.previous()) {
+            (Some(initializer), _) => evaluator.evaluate(initializer)?.as_real(),
+            (None, Some(previous)) => evaluator.enum_member(previous).map(|value| value + 1.0),
+            (None, None) => Some(0.0),
+        }
+    }
+
+    /// Returns the value of a macro, as long as every declaration of it shares the same body and
+    /// that body is a single constant (or the name of another constant).
+    fn macro_value(&self, name: &str) -> Option<Value> {
+        let evaluator = self.deeper()?;
+        let declarations = evaluator.project?.declarations(name);
+        let body = declarations.first()?.body()?.trim();
+        if declarations.iter().any(|declaration| {
+            declaration.kind() != DeclarationKind::Macro || declaration.body().map(str::trim) != Some(body)
+        }) {
+            return None;
+        }
+        evaluator.macro_body(body.strip_suffix(';').unwrap_or(body).trim())
+    }
+
+    /// Macro bodies are raw gml that isn't parsed, so only the simplest bodies are understood.
+    fn macro_body(&self, body: &str) -> Option<Value> {
+        if let Some(negated) = body.strip_prefix('-') {
+            return self
+                .macro_body(negated.trim())?
+                .as_real()
+                .map(|value| Value::Real(-value));
+        }
+        if let Some(string) = body.strip_prefix('"').and_then(|body| body.strip_suffix('"')) {
+            return (!string.contains('"')).then(|| Value::String(string.to_string()));
+        }
+        if let Some(hex) = body.strip_prefix("0x").or_else(|| body.strip_prefix('$')) {
+            return u64::from_str_radix(hex, 16).ok().map(|value| Value::Real(value as f64));
+        }
+        match body {
+            "true" => Some(Value::Bool(true)),
+            "false" => Some(Value::Bool(false)),
+            "undefined" => Some(Value::Undefined),
+            _ if body.starts_with(|c: char| c.is_ascii_digit() || c == '.') => body.parse().ok().map(Value::Real),
+            _ => match body.split_once('.') {
+                Some((left, right)) if Self::is_name(left) && Self::is_name(right) => {
+                    self.enum_member(body).map(Value::Real)
+                }
+                None if Self::is_name(body) => self.macro_value(body),
+                _ => None,
+            },
+        }
+    }
+
+    fn evaluation(left: Value, op: &EvaluationOp, right: Value) -> Option<Value> {
+        if let (EvaluationOp::Plus(_), Value::String(left), Value::String(right)) = (op, &left, &right) {
+            return Some(Value::String(format!("{left}{right}")));
+        }
+        let (left, right) = (left.as_real()?, right.as_real()?);
+        let (left_int, right_int) = (left as i64, right as i64);
+        let value = match op {
+            EvaluationOp::Plus(_) => left + right,
+            EvaluationOp::Minus(_) => left - right,
+            EvaluationOp::Star(_) => left * right,
+            EvaluationOp::Slash(_) if right != 0.0 => left / right,
+            EvaluationOp::Div(_) if right != 0.0 => (left / right).trunc(),
+            EvaluationOp::Modulo(_) if right != 0.0 => left % right,
+            EvaluationOp::And(_) => (left_int & right_int) as f64,
+            EvaluationOp::Or(_) => (left_int | right_int) as f64,
+            EvaluationOp::Xor(_) => (left_int ^ right_int) as f64,
+            EvaluationOp::BitShiftLeft(_) => left_int.checked_shl(u32::try_from(right_int).ok()?)? as f64,
+            EvaluationOp::BitShiftRight(_) => left_int.checked_shr(u32::try_from(right_int).ok()?)? as f64,
+            _ => return None,
+        };
+        Some(Value::Real(value))
+    }
+
+    fn equality(left: &Value, op: &EqualityOp, right: &Value) -> Option<bool> {
+        use std::cmp::Ordering;
+        let ordering = match (left, right) {
+            (Value::String(left), Value::String(right)) => left.cmp(right),
+            (Value::Undefined, Value::Undefined) => Ordering::Equal,
+            // Undefined can be compared for equality against anything, but has no order
+            (Value::Undefined, _) | (_, Value::Undefined) => {
+                return match op {
+                    EqualityOp::Equal(_) => Some(false),
+                    EqualityOp::NotEqual(_) => Some(true),
+                    _ => None,
+                };
+            }
+            (left, right) => left.as_real()?.partial_cmp(&right.as_real()?)?,
+        };
+        Some(match op {
+            EqualityOp::Equal(_) => ordering == Ordering::Equal,
+            EqualityOp::NotEqual(_) => ordering != Ordering::Equal,
+            EqualityOp::GreaterThan(_) => ordering == Ordering::Greater,
+            EqualityOp::GreaterThanOrEqual(_) => ordering != Ordering::Less,
+            EqualityOp::LessThan(_) => ordering == Ordering::Less,
+            EqualityOp::LessThanOrEqual(_) => ordering != Ordering::Greater,
+        })
+    }
+
+    fn is_name(text: &str) -> bool {
+        !text.is_empty() && text.chars().all(|c| c.is_alphanumeric() || c == '_')
+    }
+
+    /// Returns an evaluator one level deeper into the constants it is resolving, or None if it has
+    /// gone too deep.
+    fn deeper(&self) -> Option<Self> {
+        (self.depth < MAX_DEPTH).then_some(Self {
+            depth: self.depth + 1,
+            ..*self
+        })
+    }
+}
+
+/// A constant value in gml.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Value {
+    /// A number.
+    Real(f64),
+    /// A string.
+    String(String),
+    /// `true` or `false`.
+    Bool(bool),
+    /// `undefined`.
+    Undefined,
+}
+impl Value {
+    /// Returns the value as a number. Booleans are `1` and `0`, while strings and `undefined` are
+    /// not numbers.
+    pub fn as_real(&self) -> Option<f64> {
+        match self {
+            Value::Real(value) => Some(*value),
+            Value::Bool(value) => Some(if *value { 1.0 } else { 0.0 }),
+            Value::String(_) | Value::Undefined => None,
+        }
+    }
+
+    /// Returns whether the value is true when used as a condition. Numbers are true when they are
+    /// greater than `0.5`, and strings and `undefined` can't be used as conditions.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Value::Bool(value) => Some(*value),
+            Value::Real(value) => Some(*value > 0.5),
+            Value::String(_) | Value::Undefined => None,
+        }
+    }
+}
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Real(value) => write!(f, "{value}"),
+            Value::String(string) => write!(f, "\"{string}\""),
+            Value::Bool(value) => write!(f, "{value}"),
+            Value::Undefined => f.write_str("undefined"),
+        }
+    }
+}
@@ -0,0 +1,216 @@
+use crate::{analyze::Body, parse::*};
+
+/// Measurements of how complex a series of statements is, such as the body of a function or an
+/// event. Functions declared within the statements are not included, as they are measured as
+/// bodies of their own.
+///
+/// ```
+/// use duck::{analyze::Metrics, parse::Parser};
+/// let ast = Parser::new("if a && b { while c { d(); } } else { e(); }", 0)
+///     .into_ast()
+///     .unwrap();
+/// let metrics = Metrics::new(ast.stmts());
+/// assert_eq!(metrics.cyclomatic_complexity(), 4);
+/// assert_eq!(metrics.nesting_depth(), 2);
+/// assert_eq!(metrics.statement_count(), 4);
+/// ```
+#[derive(Debug, PartialEq, Eq, Default, Clone, Copy, serde::Serialize)]
+pub struct Metrics {
+    cyclomatic_complexity: usize,
+    nesting_depth: usize,
+    statement_count: usize,
+    #[serde(skip)]
+    deepest: Option<Location>,
+}
+impl Metrics {
+    /// Measures the statements.
+    pub fn new(stmts: &[Stmt]) -> Self {
+        let mut metrics = Self {
+            cyclomatic_complexity: 1,
+            ..Default::default()
+        };
+        for stmt in stmts {
+            metrics.visit_stmt(stmt, 0);
+        }
+        metrics
+    }
+
+    /// Returns the number of independent paths through the statements: one, plus one for every
+    /// branch, loop, switch case, catch, ternary and short-circuiting operator.
+    pub fn cyclomatic_complexity(&self) -> usize {
+        self.cyclomatic_complexity
+    }
+
+    /// Returns how many branches and loops deep the most deeply nested statement is. Statements
+    /// that are not within any are at a depth of 0, and `else if` chains count as a single level.
+    pub fn nesting_depth(&self) -> usize {
+        self.nesting_depth
+    }
+
+    /// Returns the location of the first statement at the deepest level of nesting, if there are
+    /// any statements.
+    pub fn deepest(&self) -> Option<Location> {
+        self.deepest
+    }
+
+    /// Returns the number of statements, not counting the blocks that group them.
+    pub fn statement_count(&self) -> usize {
+        self.statement_count
+    }
+
+    fn visit_stmt(&mut self, stmt: &Stmt, depth: usize) {
+        if !matches!(stmt.kind(), StmtKind::Block(_)) {
+            self.statement_count += 1;
+            if self.deepest.is_none() || depth > self.nesting_depth {
+                self.nesting_depth = depth;
+                self.deepest = Some(Location(stmt.file_id(), stmt.span()));
+            }
+        }
+        self.cyclomatic_complexity += match stmt.kind() {
+            StmtKind::If(_)
+            | StmtKind::While(_)
+            | StmtKind::For(_)
+            | StmtKind::DoUntil(_)
+            | StmtKind::Repeat(_)
+            | StmtKind::With(_)
+            | StmtKind::TryCatch(_) => 1,
+            StmtKind::Switch(switch) => switch.cases.len(),
+            _ => 0,
+        };
+        match stmt.kind() {
+            StmtKind::If(If {
+                condition,
+                body,
+                else_stmt,
+                ..
+            }) => {
+                self.visit_expr(condition);
+                self.visit_stmt(body, depth + 1);
+                if let Some(else_stmt) = else_stmt {
+                    let else_depth = match else_stmt.kind() {
+                        StmtKind::If(_) => depth,
+                        _ => depth + 1,
+                    };
+                    self.visit_stmt(else_stmt, else_depth);
+                }
+            }
+            StmtKind::Block(_) => stmt.visit_child_stmts(|child| self.visit_stmt(child, depth)),
+            // The assignments within a declaration are a part of it, rather than statements of
+            // their own
+            StmtKind::LocalVariables(_) | StmtKind::Enum(_) => {
+                stmt.visit_child_stmts(|child| child.visit_child_exprs(|expr| self.visit_expr(expr)));
+                stmt.visit_child_exprs(|expr| self.visit_expr(expr));
+            }
+            _ => {
+                stmt.visit_child_stmts(|child| self.visit_stmt(child, depth + 1));
+                stmt.visit_child_exprs(|expr| self.visit_expr(expr));
+            }
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &Expr) {
+        match expr.kind() {
+            ExprKind::Function(_) => return,
+            ExprKind::Logical(Logical {
+                op: LogicalOp::And(_) | LogicalOp::Or(_),
+                ..
+            })
+            | ExprKind::Ternary(_)
+            | ExprKind::NullCoalecence(_) => self.cyclomatic_complexity += 1,
+            _ => {}
+        }
+        expr.visit_child_exprs(|expr| self.visit_expr(expr));
+    }
+}
+
+/// The [Metrics] of a file's top level (the body of its event or script), along with those of
+/// every function declared within it.
+#[derive(Debug)]
+pub struct FileMetrics {
+    metrics: Metrics,
+    functions: Vec<FunctionMetrics>,
+}
+impl FileMetrics {
+    /// Measures the file and every function within it.
+    pub fn new(ast: &Ast) -> Self {
+        let mut functions = vec![];
+        for stmt in ast.stmts() {
+            Self::collect_stmt(stmt, &mut functions);
+        }
+        Self {
+            metrics: Metrics::new(ast.stmts()),
+            functions,
+        }
+    }
+
+    /// Returns the metrics of the file's top level.
+    pub fn metrics(&self) -> &Metrics {
+        &self.metrics
+    }
+
+    /// Returns the metrics of every function in the file, in the order they appear.
+    pub fn functions(&self) -> &[FunctionMetrics] {
+        &self.functions
+    }
+
+    fn collect_stmt(stmt: &Stmt, functions: &mut Vec<FunctionMetrics>) {
+        stmt.visit_child_stmts(|stmt| Self::collect_stmt(stmt, functions));
+        stmt.visit_child_exprs(|expr| Self::collect_expr(expr, functions));
+    }
+
+    fn collect_expr(expr: &Expr, functions: &mut Vec<FunctionMetrics>) {
+        if let ExprKind::Function(function) = expr.kind() {
+            functions.push(FunctionMetrics {
+                name: function.name.as_ref().map(|name| name.lexeme.clone()),
+                location: Location(expr.file_id(), expr.span()),
+                parameter_count: function.parameters.len(),
+                metrics: Body::function(function).metrics(),
+            });
+        }
+        expr.visit_child_stmts(|stmt| Self::collect_stmt(stmt, functions));
+        expr.visit_child_exprs(|expr| Self::collect_expr(expr, functions));
+    }
+}
+
+/// The [Metrics] of a single function.
+#[derive(Debug)]
+pub struct FunctionMetrics {
+    name: Option<String>,
+    location: Location,
+    parameter_count: usize,
+    metrics: Metrics,
+}
+impl FunctionMetrics {
+    /// Returns the name of the function, or None if it is anonymous.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the location of the entire function.
+    pub fn location(&self) -> Location {
+        self.location
+    }
+
+    /// Returns the number of parameters the function has.
+    pub fn parameter_count(&self) -> usize {
+        self.parameter_count
+    }
+
+    /// Returns the metrics of the function's body.
+    pub fn metrics(&self) -> &Metrics {
+        &self.metrics
+    }
+}
+
+/// Returns the length, in characters, of the longest line that the given range of the source
+/// touches.
+pub fn longest_line(source: &str, start: usize, end: usize) -> usize {
+    let (start, end) = (start.min(source.len()), end.min(source.len()));
+    let start = source[..start].rfind('\n').map_or(0, |index| index + 1);
+    let end = source[end..].find('\n').map_or(source.len(), |index| end + index);
+    source[start..end]
+        .lines()
+        .map(|line| line.trim_end_matches('\r').chars().count())
+        .max()
+        .unwrap_or_default()
+}
@@ -0,0 +1,454 @@
+use crate::{Config, FileId, analyze::Evaluator, parse::*};
+use hashbrown::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// An index of every global symbol declared across a project's files, along with every name that
+/// is referenced within them.
+///
+/// Lints in the late pass are given the project being linted, letting them see past the file they
+/// are in. References are collected by name alone, so a local variable that shares a name with a
+/// global function is counted as a reference to it. This errs on the side of symbols appearing to
+/// be used, which is the safer mistake for anything that reports on them.
+#[derive(Debug, Default)]
+pub struct Project {
+    paths: HashMap<FileId, PathBuf>,
+    declarations: HashMap<String, Vec<Declaration>>,
+    references: HashSet<String>,
+    /// The variables assigned to in each script, and in the events of each object and room.
+    assignments: HashMap<PathBuf, HashSet<String>>,
+}
+impl Project {
+    /// Creates an empty project.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the declarations and references in a file to the project. Top level functions are only
+    /// global in scripts, so files within an `objects` or `rooms` folder don't declare any.
+    pub fn add(&mut self, ast: &Ast, path: &Path) {
+        let Some(stmt) = ast.stmts().first() else {
+            return;
+        };
+        let file_id = stmt.file_id();
+        let is_script = !runs_on_instance(path);
+        self.paths.insert(file_id, path.to_path_buf());
+        for stmt in ast.stmts() {
+            match stmt.kind().as_expr_stmt().and_then(|expr| expr.kind().as_function()) {
+                Some(
+                    function @ Function {
+                        name: Some(name),
+                        constructor,
+                        ..
+                    },
+                ) if is_script => {
+                    let kind = match constructor {
+                        Some(_) => DeclarationKind::Constructor,
+                        None => DeclarationKind::Function,
+                    };
+                    let mut declaration = Declaration::new(&name.lexeme, kind, Location(file_id, name.span));
+                    declaration.arity = Some(Arity::of(function));
+                    declaration.parent = constructor
+                        .as_ref()
+                        .and_then(|constructor| constructor.inheritance.as_ref())
+                        .and_then(|inheritance| inheritance.kind().as_call())
+                        .and_then(|call| call.left.kind().as_identifier())
+                        .map(|parent| (parent.lexeme.clone(), Location(file_id, parent.span)));
+                    self.declare(declaration);
+                    Indexer::new(self, path, Some(&name.lexeme)).visit_stmt(stmt);
+                }
+                _ => Indexer::new(self, path, None).visit_stmt(stmt),
+            }
+        }
+    }
+
+    /// Returns every declaration of a symbol with the given name. Enum members are named after
+    /// their enum (ie: `Enum.Member`).
+    pub fn declarations(&self, name: &str) -> &[Declaration] {
+        self.declarations.get(name).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns an iterator over every declaration in the project.
+    pub fn iter_declarations(&self) -> impl Iterator<Item = &Declaration> {
+        self.declarations.values().flatten()
+    }
+
+    /// Returns the declaration of the given kind made in a file with the given name, if there is
+    /// one.
+    pub fn find(&self, file_id: FileId, name: &str, kind: DeclarationKind) -> Option<&Declaration> {
+        self.declarations(name)
+            .iter()
+            .find(|declaration| declaration.location.0 == file_id && declaration.kind == kind)
+    }
+
+    /// Returns whether the name is referenced anywhere in the project, outside of its own
+    /// declaration. Names found in string literals (such as `asset_get_index("scr_foo")`) and in
+    /// the bodies of macros count as references.
+    pub fn is_referenced(&self, name: &str) -> bool {
+        self.references.contains(name)
+    }
+
+    /// Returns whether the declaration is never referenced anywhere in the project, and isn't
+    /// exempted from being reported as unused by the config.
+    pub fn is_unused(&self, declaration: &Declaration, config: &Config) -> bool {
+        !self.is_referenced(&declaration.name)
+            && !config.is_used_symbol(&declaration.name)
+            && !self
+                .path(declaration.location.0)
+                .is_some_and(|path| config.is_public_api(path))
+    }
+
+    /// Returns the value of the enum member with the given name (ie: `Enum.Member`). Initializers
+    /// can use numbers, arithmetic, simple macros and the members of any enum in the project, and
+    /// members without one are one more than the member before them. Returns None if the value
+    /// isn't constant, or depends on something that isn't.
+    pub fn enum_member_value(&self, name: &str) -> Option<f64> {
+        Evaluator::with_project(self).enum_member(name)
+    }
+
+    /// Returns whether the name is assigned to as a variable (ie: `name = ...` or `self.name =
+    /// ...`) in the file with the given id, or in any of the other events of the object or room it
+    /// belongs to.
+    pub fn is_assigned(&self, file_id: FileId, name: &str) -> bool {
+        self.path(file_id)
+            .and_then(|path| self.assignments.get(owner(path)))
+            .is_some_and(|assignments| assignments.contains(name))
+    }
+
+    /// Returns the path of the file with the given id, if it is a part of the project.
+    pub fn path(&self, file_id: FileId) -> Option<&Path> {
+        self.paths.get(&file_id).map(PathBuf::as_path)
+    }
+
+    /// Returns whether the file with the given id belongs to an object or room, where its code runs
+    /// on an instance rather than on its own.
+    pub fn runs_on_instance(&self, file_id: FileId) -> bool {
+        self.path(file_id).is_some_and(runs_on_instance)
+    }
+
+    fn declare(&mut self, declaration: Declaration) {
+        self.declarations
+            .entry(declaration.name.clone())
+            .or_default()
+            .push(declaration);
+    }
+}
+
+/// A global symbol declared somewhere in a [Project].
+#[derive(Debug, PartialEq, Clone)]
+pub struct Declaration {
+    name: String,
+    kind: DeclarationKind,
+    location: Location,
+    config: Option<String>,
+    arity: Option<Arity>,
+    parent: Option<(String, Location)>,
+    body: Option<String>,
+    initializer: Option<Expr>,
+    previous: Option<String>,
+}
+impl Declaration {
+    fn new(name: impl Into<String>, kind: DeclarationKind, location: Location) -> Self {
+        Self {
+            name: name.into(),
+            kind,
+            location,
+            config: None,
+            arity: None,
+            parent: None,
+            body: None,
+            initializer: None,
+            previous: None,
+        }
+    }
+
+    /// Returns the name of the symbol. Enum members are named after their enum (ie:
+    /// `Enum.Member`).
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns what kind of symbol was declared.
+    pub fn kind(&self) -> DeclarationKind {
+        self.kind
+    }
+
+    /// Returns the location of the symbol's name in its declaration.
+    pub fn location(&self) -> Location {
+        self.location
+    }
+
+    /// Returns the configuration a macro is bound to (ie: `Debug` in `#macro Debug:FOO 0`), if it
+    /// is bound to one.
+    pub fn config(&self) -> Option<&str> {
+        self.config.as_deref()
+    }
+
+    /// Returns the number of arguments a function or constructor can be called with.
+    pub fn arity(&self) -> Option<Arity> {
+        self.arity
+    }
+
+    /// Returns the raw gml a macro expands to.
+    pub fn body(&self) -> Option<&str> {
+        self.body.as_deref()
+    }
+
+    /// Returns the expression an enum member is initialized with, if it has one.
+    pub fn initializer(&self) -> Option<&Expr> {
+        self.initializer.as_ref()
+    }
+
+    /// Returns the name of the member declared before an enum member (ie: `Enum.First`), if it
+    /// isn't the first member.
+    pub fn previous(&self) -> Option<&str> {
+        self.previous.as_deref()
+    }
+
+    /// Returns the name of the constructor a constructor inherits from, along with where that
+    /// name is written in its declaration (ie: `Parent` in `function Child() : Parent()
+    /// constructor`).
+    pub fn parent(&self) -> Option<(&str, Location)> {
+        self.parent.as_ref().map(|(name, location)| (name.as_str(), *location))
+    }
+}
+
+/// The number of arguments a function can be called with.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Arity {
+    minimum: usize,
+    maximum: Option<usize>,
+}
+impl Arity {
+    /// Creates a new arity. A maximum of None accepts any number of arguments.
+    pub fn new(minimum: usize, maximum: Option<usize>) -> Self {
+        Self { minimum, maximum }
+    }
+
+    /// Returns the arity of a function declared in gml. Every parameter up to the last one without
+    /// a default value is required. Functions that read `argument_count` are assumed to handle any
+    /// number of arguments, and functions that read `argument[n]` (or `argument0` and the like) may
+    /// be given more arguments than they declare.
+    pub fn of(function: &Function) -> Self {
+        let (reads_count, reads_arguments) = Self::argument_reads(&function.body);
+        if reads_count {
+            return Self::new(0, None);
+        }
+        let minimum = function
+            .parameters
+            .iter()
+            .rposition(|parameter| parameter.assignment_value().is_none())
+            .map_or(0, |index| index + 1);
+        let maximum = (!reads_arguments).then_some(function.parameters.len());
+        Self::new(minimum, maximum)
+    }
+
+    /// Returns the fewest arguments that can be passed.
+    pub fn minimum(&self) -> usize {
+        self.minimum
+    }
+
+    /// Returns the most arguments that can be passed, or None if there is no limit.
+    pub fn maximum(&self) -> Option<usize> {
+        self.maximum
+    }
+
+    /// Returns whether a call with the given number of arguments is valid.
+    pub fn accepts(&self, count: usize) -> bool {
+        count >= self.minimum && self.maximum.is_none_or(|maximum| count <= maximum)
+    }
+
+    /// Returns whether the statement reads `argument_count`, and whether it reads `argument[n]`
+    /// or any of `argument0` through `argument15`, without entering any nested functions.
+    fn argument_reads(stmt: &Stmt) -> (bool, bool) {
+        fn visit_stmt(stmt: &Stmt, reads: &mut (bool, bool)) {
+            stmt.visit_child_stmts(|stmt| visit_stmt(stmt, reads));
+            stmt.visit_child_exprs(|expr| visit_expr(expr, reads));
+        }
+        fn visit_expr(expr: &Expr, reads: &mut (bool, bool)) {
+            match expr.kind() {
+                ExprKind::Function(_) => return,
+                ExprKind::Identifier(Identifier { lexeme, .. }) => match lexeme.strip_prefix("argument") {
+                    Some("_count") => reads.0 = true,
+                    Some(index) if index.is_empty() || index.parse::<u8>().is_ok() => reads.1 = true,
+                    _ => {}
+                },
+                _ => {}
+            }
+            expr.visit_child_stmts(|stmt| visit_stmt(stmt, reads));
+            expr.visit_child_exprs(|expr| visit_expr(expr, reads));
+        }
+        let mut reads = (false, false);
+        visit_stmt(stmt, &mut reads);
+        reads
+    }
+}
+impl std::fmt::Display for Arity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let plural = |count: usize| if count == 1 { "" } else { "s" };
+        match self.maximum {
+            Some(maximum) if maximum == self.minimum => write!(f, "{maximum} argument{}", plural(maximum)),
+            Some(maximum) => write!(f, "{} to {maximum} arguments", self.minimum),
+            None => write!(f, "at least {} argument{}", self.minimum, plural(self.minimum)),
+        }
+    }
+}
+
+/// The different kinds of global symbols that can be declared in gml.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum DeclarationKind {
+    /// A function declared at the top level of a script.
+    Function,
+    /// A constructor declared at the top level of a script.
+    Constructor,
+    /// A `#macro`.
+    Macro,
+    /// An enum.
+    Enum,
+    /// A member of an enum.
+    EnumMember,
+    /// A `globalvar`.
+    Globalvar,
+}
+
+fn runs_on_instance(path: &Path) -> bool {
+    path.components()
+        .any(|component| matches!(component.as_os_str().to_str(), Some("objects" | "rooms")))
+}
+
+/// Returns the path that a file shares its variables with: the folder of the object or room it is
+/// an event of, or the file itself for scripts.
+fn owner(path: &Path) -> &Path {
+    match path.parent() {
+        Some(parent) if runs_on_instance(path) => parent,
+        _ => path,
+    }
+}
+
+/// Walks through a statement, declaring the symbols within it and recording every reference.
+struct Indexer<'a> {
+    project: &'a mut Project,
+    /// The path of the file the statement is in.
+    path: &'a Path,
+    /// The function being declared, whose references to itself are ignored.
+    declaring: Option<&'a str>,
+}
+impl<'a> Indexer<'a> {
+    fn new(project: &'a mut Project, path: &'a Path, declaring: Option<&'a str>) -> Self {
+        Self {
+            project,
+            path,
+            declaring,
+        }
+    }
+
+    fn assign(&mut self, name: &str) {
+        let assignments = self
+            .project
+            .assignments
+            .entry(owner(self.path).to_path_buf())
+            .or_default();
+        if !assignments.contains(name) {
+            assignments.insert(name.to_string());
+        }
+    }
+
+    fn reference(&mut self, name: &str) {
+        if self.declaring != Some(name) && !self.project.references.contains(name) {
+            self.project.references.insert(name.to_string());
+        }
+    }
+
+    fn visit_stmt(&mut self, stmt: &Stmt) {
+        match stmt.kind() {
+            StmtKind::Macro(mac) => {
+                let mut declaration = Declaration::new(
+                    &mac.name.lexeme,
+                    DeclarationKind::Macro,
+                    Location(stmt.file_id(), mac.name.span),
+                );
+                declaration.config = mac.config.clone();
+                declaration.body = Some(mac.body.clone());
+                self.project.declare(declaration);
+                // Macro bodies aren't parsed, so every word in them (along with every dotted path,
+                // for enum members) is treated as a reference
+                for path in mac.body.split(|c: char| !c.is_alphanumeric() && c != '_' && c != '.') {
+                    self.reference(path);
+                    for word in path.split('.') {
+                        self.reference(word);
+                    }
+                }
+            }
+            StmtKind::Enum(gml_enum) => {
+                self.project.declare(Declaration::new(
+                    &gml_enum.name.lexeme,
+                    DeclarationKind::Enum,
+                    Location(stmt.file_id(), gml_enum.name.span),
+                ));
+                let mut previous = None;
+                for member in gml_enum.members.iter() {
+                    let name = format!("{}.{}", gml_enum.name.lexeme, member.name());
+                    let mut declaration =
+                        Declaration::new(&name, DeclarationKind::EnumMember, member.name_expr().location());
+                    declaration.initializer = member.assignment_value().cloned();
+                    declaration.previous = previous.replace(name);
+                    self.project.declare(declaration);
+                    if let Some(value) = member.assignment_value() {
+                        self.visit_expr(value);
+                    }
+                }
+            }
+            StmtKind::Globalvar(Globalvar { name }) => self.project.declare(Declaration::new(
+                &name.lexeme,
+                DeclarationKind::Globalvar,
+                Location(stmt.file_id(), name.span),
+            )),
+            StmtKind::LocalVariables(LocalVariables { declarations }) => {
+                for value in declarations.iter().filter_map(Field::assignment_value) {
+                    self.visit_expr(value);
+                }
+            }
+            StmtKind::Assignment(Assignment { left, right, .. }) => {
+                match left.kind() {
+                    ExprKind::Identifier(name) | ExprKind::Access(Access::Identity { right: name }) => {
+                        self.assign(&name.lexeme)
+                    }
+                    _ => {}
+                }
+                self.visit_expr(left);
+                self.visit_expr(right);
+            }
+            kind => {
+                kind.visit_child_stmts(|stmt| self.visit_stmt(stmt));
+                kind.visit_child_exprs(|expr| self.visit_expr(expr));
+            }
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &Expr) {
+        match expr.kind() {
+            ExprKind::Identifier(identifier) => self.reference(&identifier.lexeme),
+            ExprKind::Access(Access::Global { right }) => self.reference(&right.lexeme),
+            ExprKind::Access(Access::Dot { left, right }) => {
+                if let Some(left) = left.kind().as_identifier() {
+                    self.reference(&format!("{}.{}", left.lexeme, right.lexeme));
+                }
+                self.visit_expr(left);
+            }
+            ExprKind::Literal(Literal::String(string)) => self.reference(string),
+            ExprKind::Function(function) => {
+                // Only the default values of parameters are references
+                for value in function.parameters.iter().filter_map(Field::assignment_value) {
+                    self.visit_expr(value);
+                }
+                if let Some(inheritance) = function.constructor.as_ref().and_then(|c| c.inheritance.as_ref()) {
+                    self.visit_expr(inheritance);
+                }
+                self.visit_stmt(&function.body);
+            }
+            _ => {
+                expr.visit_child_stmts(|stmt| self.visit_stmt(stmt));
+                expr.visit_child_exprs(|expr| self.visit_expr(expr));
+            }
+        }
+    }
+}
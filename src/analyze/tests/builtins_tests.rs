@@ -0,0 +1,51 @@
+use crate::analyze::{Arity, GML_FUNCTIONS, GmlType};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn signatures() {
+    let draw_sprite = &GML_FUNCTIONS["draw_sprite"];
+    assert_eq!(draw_sprite.arity(), Some(Arity::new(4, Some(4))));
+    assert_eq!(draw_sprite.parameters()[0].ty(), GmlType::Asset);
+    assert_eq!(draw_sprite.returns(), GmlType::Void);
+    assert_eq!(
+        draw_sprite.signature("draw_sprite"),
+        "draw_sprite(sprite, subimg, x, y)"
+    );
+
+    let array_create = &GML_FUNCTIONS["array_create"];
+    assert_eq!(array_create.arity(), Some(Arity::new(1, Some(2))));
+    assert_eq!(array_create.returns(), GmlType::Array);
+    assert_eq!(array_create.signature("array_create"), "array_create(size, [value])");
+
+    let show_debug_message = &GML_FUNCTIONS["show_debug_message"];
+    assert_eq!(show_debug_message.arity(), Some(Arity::new(1, None)));
+    assert_eq!(
+        show_debug_message.signature("show_debug_message"),
+        "show_debug_message(str, ...)"
+    );
+}
+
+#[test]
+fn deprecation() {
+    assert!(GML_FUNCTIONS["variable_struct_get"].is_deprecated());
+    assert!(!GML_FUNCTIONS["struct_get"].is_deprecated());
+    assert_eq!(GML_FUNCTIONS["buffer_surface_copy"].arity(), None);
+}
+
+#[test]
+fn optional_parameters_are_trailing() {
+    for (name, function) in GML_FUNCTIONS.iter() {
+        let first_optional = function
+            .parameters()
+            .iter()
+            .position(|parameter| parameter.is_optional());
+        if let Some(first_optional) = first_optional {
+            assert!(
+                function.parameters()[first_optional..]
+                    .iter()
+                    .all(|parameter| parameter.is_optional()),
+                "{name} has a required parameter after an optional one"
+            );
+        }
+    }
+}
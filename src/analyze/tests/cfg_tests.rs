@@ -0,0 +1,292 @@
+use crate::{
+    analyze::{BlockId, Body, ControlFlowGraph, Step},
+    parse::*,
+};
+use pretty_assertions::assert_eq;
+
+fn parse(source: &'static str) -> Ast {
+    Parser::new_with_default_ids(source, 0).into_ast().unwrap()
+}
+
+/// Returns the block of the first statement in the Ast that displays as the given text.
+fn block(cfg: &ControlFlowGraph, ast: &Ast, text: &str) -> BlockId {
+    fn search(cfg: &ControlFlowGraph, stmt: &Stmt, text: &str) -> Option<Option<BlockId>> {
+        if stmt.to_string() == text {
+            return Some(cfg.block_of(stmt));
+        }
+        let mut found = None;
+        stmt.visit_child_stmts(|child| found = found.or_else(|| search(cfg, child, text)));
+        found
+    }
+    ast.stmts()
+        .iter()
+        .find_map(|stmt| search(cfg, stmt, text))
+        .unwrap_or_else(|| panic!("Failed to find `{text}`"))
+        .unwrap_or_else(|| panic!("`{text}` is not a part of the graph"))
+}
+
+fn reachable(source: &'static str, text: &str) -> bool {
+    let ast = parse(source);
+    let cfg = ControlFlowGraph::new(ast.stmts());
+    cfg.is_reachable(block(&cfg, &ast, text))
+}
+
+#[test]
+fn straight_line() {
+    let ast = parse("a = 0; b = 1; foo();");
+    let cfg = ControlFlowGraph::new(ast.stmts());
+    let entry = cfg.block(cfg.entry());
+    assert_eq!(entry.steps().len(), 3);
+    assert_eq!(entry.successors(), &[cfg.exit()]);
+    assert_eq!(cfg.immediate_dominator(cfg.exit()), Some(cfg.entry()));
+}
+
+#[test]
+fn if_else_diamond() {
+    let ast = parse("if foo { a = 0; } else { a = 1; } b = 2;");
+    let cfg = ControlFlowGraph::new(ast.stmts());
+    let (then, otherwise, after) = (
+        block(&cfg, &ast, "a = 0"),
+        block(&cfg, &ast, "a = 1"),
+        block(&cfg, &ast, "b = 2"),
+    );
+    assert_eq!(cfg.block(cfg.entry()).successors().len(), 2);
+    assert_eq!(cfg.immediate_dominator(after), Some(cfg.entry()));
+    assert!(!cfg.dominates(then, after));
+    assert!(!cfg.dominates(otherwise, after));
+    assert!(cfg.dominates(cfg.entry(), then));
+}
+
+#[test]
+fn if_without_else() {
+    let ast = parse("if foo { a = 0; } b = 2;");
+    let cfg = ControlFlowGraph::new(ast.stmts());
+    let after = block(&cfg, &ast, "b = 2");
+    assert!(cfg.block(after).predecessors().contains(&cfg.entry()));
+}
+
+#[test]
+fn branch_steps() {
+    let ast = parse("if foo { a = 0; }");
+    let cfg = ControlFlowGraph::new(ast.stmts());
+    assert!(matches!(cfg.block(cfg.entry()).steps(), [Step::Branch(_, _)]));
+}
+
+#[test]
+fn code_after_return_is_unreachable() {
+    assert!(!reachable("return; a = 0;", "a = 0"));
+}
+
+#[test]
+fn code_after_exit_is_unreachable() {
+    assert!(!reachable("exit; a = 0;", "a = 0"));
+}
+
+#[test]
+fn code_after_throw_is_unreachable() {
+    assert!(!reachable("throw \"oops\"; a = 0;", "a = 0"));
+}
+
+#[test]
+fn code_after_partial_return_is_reachable() {
+    assert!(reachable("if foo { return; } a = 0;", "a = 0"));
+}
+
+#[test]
+fn code_after_full_return_is_unreachable() {
+    assert!(!reachable("if foo { return; } else { exit; } a = 0;", "a = 0"));
+}
+
+#[test]
+fn end() {
+    let ast = parse("if foo { return; } a = 0;");
+    let cfg = ControlFlowGraph::new(ast.stmts());
+    assert!(cfg.is_reachable(cfg.end()));
+    assert_eq!(cfg.block(cfg.end()).successors(), &[cfg.exit()]);
+    let ast = parse("if foo { return; } else { throw bar; }");
+    let cfg = ControlFlowGraph::new(ast.stmts());
+    assert!(!cfg.is_reachable(cfg.end()));
+}
+
+#[test]
+fn while_loop() {
+    let ast = parse("while foo { a = 0; } b = 1;");
+    let cfg = ControlFlowGraph::new(ast.stmts());
+    let header = block(&cfg, &ast, "while foo { ... }");
+    let body = block(&cfg, &ast, "a = 0");
+    let after = block(&cfg, &ast, "b = 1");
+    assert_eq!(cfg.block(header).steps().len(), 0);
+    let condition = cfg.block(header).successors()[0];
+    assert!(cfg.block(body).successors().contains(&condition));
+    assert!(cfg.dominates(condition, body));
+    assert!(cfg.dominates(condition, after));
+}
+
+#[test]
+fn infinite_loop() {
+    assert!(!reachable("while true { a = 0; } b = 1;", "b = 1"));
+    assert!(reachable("while true { break; } b = 1;", "b = 1"));
+}
+
+#[test]
+fn literal_conditions() {
+    assert!(!reachable("if false { a = 0; }", "a = 0"));
+    assert!(!reachable("if (true) { a = 0; } else { a = 1; }", "a = 1"));
+    assert!(!reachable("while false { a = 0; }", "a = 0"));
+    assert!(!reachable("do { a = 0; } until false; b = 1;", "b = 1"));
+    assert!(reachable("if foo { a = 0; }", "a = 0"));
+}
+
+#[test]
+fn constant_conditions() {
+    assert!(!reachable("while 1 { a = 0; } b = 1;", "b = 1"));
+    assert!(!reachable("if 2 < 1 { a = 0; }", "a = 0"));
+    assert!(!reachable("if foo && !true { a = 0; }", "a = 0"));
+    assert!(reachable("if foo && true { a = 0; }", "a = 0"));
+}
+
+#[test]
+fn break_leaves_loop() {
+    assert!(!reachable("while foo { break; a = 0; }", "a = 0"));
+    assert!(reachable("while foo { if bar { break; } a = 0; }", "a = 0"));
+}
+
+#[test]
+fn continue_returns_to_condition() {
+    let ast = parse("while foo { continue; }");
+    let cfg = ControlFlowGraph::new(ast.stmts());
+    let body = block(&cfg, &ast, "continue;");
+    let condition = cfg.block(block(&cfg, &ast, "while foo { ... }")).successors()[0];
+    assert_eq!(cfg.block(body).successors(), &[condition]);
+}
+
+#[test]
+fn for_loop() {
+    let ast = parse("for (var i = 0; i < 10; i++) { continue; }");
+    let cfg = ControlFlowGraph::new(ast.stmts());
+    let initializer = block(&cfg, &ast, "var i = 0;");
+    let body = block(&cfg, &ast, "continue;");
+    let iterator = block(&cfg, &ast, "i++");
+    assert_eq!(initializer, cfg.entry());
+    assert_eq!(cfg.block(body).successors(), &[iterator]);
+    assert!(cfg.dominates(body, iterator));
+}
+
+#[test]
+fn do_until() {
+    let ast = parse("do { a = 0; } until foo; b = 1;");
+    let cfg = ControlFlowGraph::new(ast.stmts());
+    let body = block(&cfg, &ast, "a = 0");
+    let after = block(&cfg, &ast, "b = 1");
+    assert!(cfg.dominates(body, after));
+}
+
+#[test]
+fn repeat_and_with() {
+    assert!(reachable("repeat 10 { a = 0; } b = 1;", "b = 1"));
+    assert!(reachable("with foo { a = 0; } b = 1;", "b = 1"));
+    assert!(!reachable("with foo { break; a = 0; }", "a = 0"));
+}
+
+#[test]
+fn switch_fallthrough() {
+    let ast = parse(
+        "switch foo {
+            case 0: a = 0;
+            case 1: a = 1; break;
+            default: a = 2;
+        }
+        b = 3;",
+    );
+    let cfg = ControlFlowGraph::new(ast.stmts());
+    let (first, second, default, after) = (
+        block(&cfg, &ast, "a = 0"),
+        block(&cfg, &ast, "a = 1"),
+        block(&cfg, &ast, "a = 2"),
+        block(&cfg, &ast, "b = 3"),
+    );
+    assert!(cfg.block(first).successors().contains(&second));
+    assert!(!cfg.block(second).successors().contains(&default));
+    assert!(cfg.block(cfg.entry()).successors().contains(&default));
+    assert!(!cfg.block(cfg.entry()).successors().contains(&after));
+    assert_eq!(cfg.immediate_dominator(after), Some(cfg.entry()));
+}
+
+#[test]
+fn switch_without_default() {
+    let ast = parse("switch foo { case 0: break; } b = 1;");
+    let cfg = ControlFlowGraph::new(ast.stmts());
+    let after = block(&cfg, &ast, "b = 1");
+    assert!(cfg.block(cfg.entry()).successors().contains(&after));
+}
+
+#[test]
+fn continue_in_switch_targets_loop() {
+    let ast = parse("while foo { switch bar { case 0: continue; } a = 0; }");
+    let cfg = ControlFlowGraph::new(ast.stmts());
+    let condition = cfg.block(block(&cfg, &ast, "while foo { ... }")).successors()[0];
+    assert_eq!(cfg.block(block(&cfg, &ast, "continue;")).successors(), &[condition]);
+}
+
+#[test]
+fn try_catch() {
+    let ast = parse("try { a = 0; } catch (e) { a = 1; } b = 2;");
+    let cfg = ControlFlowGraph::new(ast.stmts());
+    let (try_body, catch_body, after) = (
+        block(&cfg, &ast, "a = 0"),
+        block(&cfg, &ast, "a = 1"),
+        block(&cfg, &ast, "b = 2"),
+    );
+    assert!(cfg.block(try_body).successors().contains(&catch_body));
+    assert!(cfg.block(cfg.entry()).successors().contains(&catch_body));
+    assert!(!cfg.dominates(try_body, after));
+    assert!(!cfg.dominates(catch_body, after));
+    assert_eq!(cfg.immediate_dominator(after), Some(cfg.entry()));
+}
+
+#[test]
+fn throw_is_caught() {
+    assert!(reachable("try { throw 0; } catch (e) {} a = 0;", "a = 0"));
+    assert!(!reachable("try { throw 0; } catch (e) { throw e; } a = 0;", "a = 0"));
+}
+
+#[test]
+fn return_passes_through_finally() {
+    let ast = parse("try { return; } catch (e) {} finally { a = 0; } b = 1;");
+    let cfg = ControlFlowGraph::new(ast.stmts());
+    let finally = block(&cfg, &ast, "a = 0");
+    assert!(cfg.block(finally).successors().contains(&cfg.exit()));
+    assert!(cfg.is_reachable(finally));
+    assert!(cfg.dominates(finally, cfg.exit()));
+}
+
+#[test]
+fn nested_finally() {
+    let ast = parse(
+        "while foo {
+            try {
+                try { break; } catch (e) {} finally { a = 0; }
+            } catch (e) {} finally { a = 1; }
+        }",
+    );
+    let cfg = ControlFlowGraph::new(ast.stmts());
+    let (inner, outer) = (block(&cfg, &ast, "a = 0"), block(&cfg, &ast, "a = 1"));
+    assert!(cfg.block(inner).successors().contains(&outer));
+}
+
+#[test]
+fn functions_are_separate_bodies() {
+    let ast = parse("function foo() { return; a = 0; } b = 1;");
+    let cfg = ControlFlowGraph::new(ast.stmts());
+    assert_eq!(cfg.blocks().map(|(_, block)| block.steps().len()).sum::<usize>(), 2);
+    let function = ast.stmts()[0]
+        .kind()
+        .as_expr_stmt()
+        .unwrap()
+        .kind()
+        .as_function()
+        .unwrap();
+    let body = Body::function(function);
+    assert_eq!(body.stmts().len(), 2);
+    assert!(!body.cfg().is_reachable(body.cfg().block_of(&body.stmts()[1]).unwrap()));
+}
@@ -0,0 +1,108 @@
+use crate::{
+    analyze::{Evaluator, Project, Value},
+    parse::*,
+};
+use pretty_assertions::assert_eq;
+use std::path::Path;
+
+fn evaluate(source: &'static str) -> Option<Value> {
+    let expr = Parser::new_with_default_ids(source, 0).expr().unwrap();
+    Evaluator::new().evaluate(&expr)
+}
+
+fn evaluate_in_project(source: &'static str, files: &[&'static str]) -> Option<Value> {
+    let mut project = Project::new();
+    for (file_id, file) in files.iter().enumerate() {
+        let ast = Parser::new_with_default_ids(file, file_id + 1).into_ast().unwrap();
+        project.add(&ast, Path::new("scripts/a/a.gml"));
+    }
+    let expr = Parser::new_with_default_ids(source, 0).expr().unwrap();
+    Evaluator::with_project(&project).evaluate(&expr)
+}
+
+#[test]
+fn arithmetic() {
+    assert_eq!(evaluate("1 + 2 * 3"), Some(Value::Real(7.0)));
+    assert_eq!(evaluate("(1 + 2) * 3"), Some(Value::Real(9.0)));
+    assert_eq!(evaluate("-(4 / 8)"), Some(Value::Real(-0.5)));
+    assert_eq!(evaluate("7 div 2"), Some(Value::Real(3.0)));
+    assert_eq!(evaluate("10 div 2.5"), Some(Value::Real(4.0)));
+    assert_eq!(evaluate("5 div 0.5"), Some(Value::Real(10.0)));
+    assert_eq!(evaluate("-7 div 2"), Some(Value::Real(-3.0)));
+    assert_eq!(evaluate("7 mod 4"), Some(Value::Real(3.0)));
+    assert_eq!(evaluate("1 << 4 | 1"), Some(Value::Real(17.0)));
+    assert_eq!(evaluate("$ff & ~$f"), Some(Value::Real(240.0)));
+    assert_eq!(evaluate("1 / 0"), None);
+    assert_eq!(evaluate("1 div 0"), None);
+    assert_eq!(evaluate("1 div 0.5"), Some(Value::Real(2.0)));
+}
+
+#[test]
+fn strings() {
+    assert_eq!(evaluate("\"foo\" + \"bar\""), Some(Value::String("foobar".into())));
+    assert_eq!(evaluate("\"foo\" == \"foo\""), Some(Value::Bool(true)));
+    assert_eq!(evaluate("\"a\" < \"b\""), Some(Value::Bool(true)));
+    assert_eq!(evaluate("\"foo\" * 2"), None);
+}
+
+#[test]
+fn logical() {
+    assert_eq!(evaluate("true && !false"), Some(Value::Bool(true)));
+    assert_eq!(evaluate("true xor true"), Some(Value::Bool(false)));
+    assert_eq!(evaluate("0.6 and 1"), Some(Value::Bool(true)));
+    assert_eq!(evaluate("foo || true"), Some(Value::Bool(true)));
+    assert_eq!(evaluate("false && foo"), Some(Value::Bool(false)));
+    assert_eq!(evaluate("foo && true"), None);
+}
+
+#[test]
+fn equality() {
+    assert_eq!(evaluate("1 + 1 == 2"), Some(Value::Bool(true)));
+    assert_eq!(evaluate("3 >= 4"), Some(Value::Bool(false)));
+    assert_eq!(evaluate("true == 1"), Some(Value::Bool(true)));
+    assert_eq!(evaluate("noone == -4"), Some(Value::Bool(true)));
+    assert_eq!(evaluate("undefined == undefined"), Some(Value::Bool(true)));
+    assert_eq!(evaluate("undefined != 0"), Some(Value::Bool(true)));
+    assert_eq!(evaluate("undefined < 0"), None);
+}
+
+#[test]
+fn not_constant() {
+    assert_eq!(evaluate("foo"), None);
+    assert_eq!(evaluate("foo + 1"), None);
+    assert_eq!(evaluate("foo()"), None);
+    assert_eq!(evaluate("Dir.Left"), None);
+}
+
+#[test]
+fn macros() {
+    let files = ["
+            #macro SIZE 16
+            #macro DOUBLE_SIZE SIZE
+            #macro NAME \"duck\"
+            #macro NEGATIVE -$10;
+            #macro COMPLEX SIZE * 2
+            #macro LOOP LOOP
+            #macro CONFIGURED 0
+            #macro Debug:CONFIGURED 1
+        "];
+    assert_eq!(evaluate_in_project("SIZE * 2", &files), Some(Value::Real(32.0)));
+    assert_eq!(evaluate_in_project("DOUBLE_SIZE", &files), Some(Value::Real(16.0)));
+    assert_eq!(evaluate_in_project("NAME", &files), Some(Value::String("duck".into())));
+    assert_eq!(evaluate_in_project("NEGATIVE", &files), Some(Value::Real(-16.0)));
+    assert_eq!(evaluate_in_project("COMPLEX", &files), None);
+    assert_eq!(evaluate_in_project("LOOP", &files), None);
+    assert_eq!(evaluate_in_project("CONFIGURED", &files), None);
+}
+
+#[test]
+fn enum_members() {
+    let files = ["enum Dir { Left, Right = 4, Up }", "#macro UP Dir.Up"];
+    assert_eq!(evaluate_in_project("Dir.Up", &files), Some(Value::Real(5.0)));
+    assert_eq!(
+        evaluate_in_project("Dir.Right - Dir.Left", &files),
+        Some(Value::Real(4.0))
+    );
+    assert_eq!(evaluate_in_project("UP == Dir.Up", &files), Some(Value::Bool(true)));
+    assert_eq!(evaluate_in_project("Dir.Down", &files), None);
+}
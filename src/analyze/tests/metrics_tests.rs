@@ -0,0 +1,91 @@
+use crate::{
+    analyze::{FileMetrics, Metrics, longest_line},
+    parse::*,
+};
+use pretty_assertions::assert_eq;
+
+fn metrics(source: &'static str) -> Metrics {
+    let ast = Parser::new_with_default_ids(source, 0).into_ast().unwrap();
+    Metrics::new(ast.stmts())
+}
+
+#[test]
+fn straight_line() {
+    let metrics = metrics("a = 0; b = 1; c();");
+    assert_eq!(metrics.cyclomatic_complexity(), 1);
+    assert_eq!(metrics.nesting_depth(), 0);
+    assert_eq!(metrics.statement_count(), 3);
+}
+
+#[test]
+fn complexity() {
+    assert_eq!(metrics("if a {} else if b {} else {}").cyclomatic_complexity(), 3);
+    assert_eq!(
+        metrics("while a {} for (i = 0; b; i++) {} repeat 3 {} do {} until c;").cyclomatic_complexity(),
+        5
+    );
+    assert_eq!(metrics("with a {} try {} catch (e) {}").cyclomatic_complexity(), 3);
+    assert_eq!(
+        metrics("switch a { case 0: break; case 1: break; default: break; }").cyclomatic_complexity(),
+        3
+    );
+    assert_eq!(metrics("a = b && c || d xor e;").cyclomatic_complexity(), 3);
+    assert_eq!(metrics("a = b ? c : d ?? e;").cyclomatic_complexity(), 3);
+}
+
+#[test]
+fn nesting_depth() {
+    assert_eq!(metrics("if a { b(); }").nesting_depth(), 1);
+    assert_eq!(
+        metrics("if a { while b { for (i = 0; c; i++) { d(); } } }").nesting_depth(),
+        3
+    );
+    assert_eq!(
+        metrics("if a { b(); } else if c { d(); } else if e { f(); }").nesting_depth(),
+        1
+    );
+    assert_eq!(metrics("{ { a(); } }").nesting_depth(), 0);
+    assert_eq!(metrics("switch a { case 0: if b { c(); } break; }").nesting_depth(), 2);
+}
+
+#[test]
+fn statement_count() {
+    assert_eq!(metrics("{ a(); { b(); } }").statement_count(), 2);
+    assert_eq!(metrics("if a { b(); } else { c(); }").statement_count(), 3);
+}
+
+#[test]
+fn functions_are_measured_separately() {
+    let ast = Parser::new_with_default_ids(
+        "
+            function foo(a, b) {
+                if a { return b; }
+                var bar = function() { while true { break; } };
+            }
+            baz = 0;
+        ",
+        0,
+    )
+    .into_ast()
+    .unwrap();
+    let file = FileMetrics::new(&ast);
+    assert_eq!(file.metrics().cyclomatic_complexity(), 1);
+    assert_eq!(file.metrics().statement_count(), 2);
+    let functions = file.functions();
+    assert_eq!(functions.len(), 2);
+    assert_eq!(functions[0].name(), Some("foo"));
+    assert_eq!(functions[0].parameter_count(), 2);
+    assert_eq!(functions[0].metrics().cyclomatic_complexity(), 2);
+    assert_eq!(functions[0].metrics().statement_count(), 3);
+    assert_eq!(functions[1].name(), None);
+    assert_eq!(functions[1].metrics().nesting_depth(), 1);
+}
+
+#[test]
+fn longest_lines() {
+    let source = "a\nbbbb\ncc\r\nd";
+    assert_eq!(longest_line(source, 0, source.len()), 4);
+    assert_eq!(longest_line(source, 7, 8), 2);
+    assert_eq!(longest_line(source, 0, 0), 1);
+    assert_eq!(longest_line("", 0, 0), 0);
+}
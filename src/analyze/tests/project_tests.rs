@@ -0,0 +1,143 @@
+use crate::{
+    analyze::{Arity, DeclarationKind, Project},
+    parse::*,
+};
+use pretty_assertions::assert_eq;
+use std::path::Path;
+
+fn project(files: &[(&str, &'static str)]) -> Project {
+    let mut project = Project::new();
+    for (file_id, (path, source)) in files.iter().enumerate() {
+        let ast = Parser::new_with_default_ids(source, file_id).into_ast().unwrap();
+        project.add(&ast, Path::new(path));
+    }
+    project
+}
+
+fn kinds(project: &Project, name: &str) -> Vec<DeclarationKind> {
+    project
+        .declarations(name)
+        .iter()
+        .map(|declaration| declaration.kind())
+        .collect()
+}
+
+#[test]
+fn declarations() {
+    let project = project(&[(
+        "scripts/a/a.gml",
+        "
+            #macro FOO 0
+            #macro Debug:FOO 1
+            enum Bar { A, B = 2 }
+            globalvar baz;
+            function fizz() { function buzz() {} }
+            function Fizz() constructor {}
+        ",
+    )]);
+    assert_eq!(kinds(&project, "FOO"), vec![DeclarationKind::Macro; 2]);
+    assert_eq!(project.declarations("FOO")[1].config(), Some("Debug"));
+    assert_eq!(kinds(&project, "Bar"), vec![DeclarationKind::Enum]);
+    assert_eq!(kinds(&project, "Bar.B"), vec![DeclarationKind::EnumMember]);
+    assert_eq!(kinds(&project, "baz"), vec![DeclarationKind::Globalvar]);
+    assert_eq!(kinds(&project, "fizz"), vec![DeclarationKind::Function]);
+    assert_eq!(kinds(&project, "Fizz"), vec![DeclarationKind::Constructor]);
+    assert!(project.declarations("buzz").is_empty());
+}
+
+#[test]
+fn object_functions_are_not_global() {
+    let project = project(&[("objects/obj_player/Create_0.gml", "function jump() {}")]);
+    assert!(project.declarations("jump").is_empty());
+}
+
+#[test]
+fn duplicate_declarations() {
+    let project = project(&[
+        ("scripts/a/a.gml", "function foo() {}"),
+        ("scripts/b/b.gml", "function foo() {}"),
+    ]);
+    assert_eq!(project.declarations("foo").len(), 2);
+    assert!(project.find(1, "foo", DeclarationKind::Function).is_some());
+}
+
+#[test]
+fn references() {
+    let project = project(&[
+        ("scripts/a/a.gml", "function foo() { return foo(); } #macro BAR Baz.A"),
+        (
+            "objects/obj/Create_0.gml",
+            "x = global.fizz; y = asset_get_index(\"buzz\"); var local = 0; function f(param) {}",
+        ),
+    ]);
+    for name in ["Baz", "Baz.A", "fizz", "buzz", "asset_get_index"] {
+        assert!(project.is_referenced(name), "`{name}` was not referenced");
+    }
+    for name in ["foo", "local", "param"] {
+        assert!(!project.is_referenced(name), "`{name}` was referenced");
+    }
+}
+
+#[test]
+fn arity() {
+    let project = project(&[(
+        "scripts/a/a.gml",
+        "
+            function a(x, y = 0) {}
+            function b(x = 0, y) {}
+            function c(x) { return argument1; }
+            function d(x) { return argument_count; }
+            function e() { var f = function() { return argument_count; } }
+        ",
+    )]);
+    let arity = |name| project.declarations(name)[0].arity().unwrap();
+    assert_eq!(arity("a"), Arity::new(1, Some(2)));
+    assert_eq!(arity("b"), Arity::new(2, Some(2)));
+    assert_eq!(arity("c"), Arity::new(1, None));
+    assert_eq!(arity("d"), Arity::new(0, None));
+    assert_eq!(arity("e"), Arity::new(0, Some(0)));
+    assert_eq!(arity("a").to_string(), "1 to 2 arguments");
+    assert_eq!(arity("c").to_string(), "at least 1 argument");
+}
+
+#[test]
+fn parents() {
+    let project = project(&[(
+        "scripts/a/a.gml",
+        "
+            function Vec2(x, y) constructor {}
+            function Vec3(x, y, z) : Vec2(x, y) constructor {}
+        ",
+    )]);
+    let parent = |name| project.declarations(name)[0].parent().map(|(parent, _)| parent);
+    assert_eq!(parent("Vec2"), None);
+    assert_eq!(parent("Vec3"), Some("Vec2"));
+}
+
+#[test]
+fn enum_member_values() {
+    let project = project(&[
+        (
+            "scripts/a/a.gml",
+            "
+                #macro FOO 0
+                enum Dir { Left, Right = 5, Up, Down = -(2), Back = Dir.Up, Front, Flag = 1 << 3 }
+                enum Loop { A = Loop.B, B = Loop.A, C = bar(), D }
+            ",
+        ),
+        ("scripts/b/b.gml", "enum Other { A = Dir.Front * 2 }"),
+    ]);
+    let value = |name| project.enum_member_value(name);
+    assert_eq!(project.declarations("FOO")[0].body(), Some("0"));
+    assert_eq!(value("Dir.Left"), Some(0.0));
+    assert_eq!(value("Dir.Right"), Some(5.0));
+    assert_eq!(value("Dir.Up"), Some(6.0));
+    assert_eq!(value("Dir.Down"), Some(-2.0));
+    assert_eq!(value("Dir.Back"), Some(6.0));
+    assert_eq!(value("Dir.Front"), Some(7.0));
+    assert_eq!(value("Dir.Flag"), Some(8.0));
+    assert_eq!(value("Other.A"), Some(14.0));
+    assert_eq!(value("Loop.A"), None);
+    assert_eq!(value("Loop.D"), None);
+    assert_eq!(value("Dir"), None);
+}
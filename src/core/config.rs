@@ -1,7 +1,11 @@
-use crate::lint::{LintLevel, LintLevelSetting};
+use crate::{
+    lint::{Date, LintLevel, LintLevelSetting, LintRegistry, SuppressionIssue},
+    parse::{Pattern, Tag},
+};
 use hashbrown::HashMap;
 use heck::{ToLowerCamelCase, ToShoutySnakeCase, ToSnakeCase, ToUpperCamelCase};
 use itertools::Itertools;
+use std::{borrow::Cow, path::Path, sync::OnceLock};
 
 /// A series of various settings shared by the lints to customize their
 /// behavior.
@@ -15,6 +19,24 @@ pub struct Config {
     /// function declarations to have.
     #[serde(default = "default_max_arguments")]
     pub max_arguments: usize,
+    /// The maximum cyclomatic complexity the [too_complex] lint will allow functions and events
+    /// to have.
+    #[serde(default = "default_max_complexity")]
+    pub max_complexity: usize,
+    /// The deepest the [too_deeply_nested] lint will allow statements within functions and events
+    /// to be nested.
+    #[serde(default = "default_max_nesting_depth")]
+    pub max_nesting_depth: usize,
+    /// The maximum number of statements the [too_many_statements] lint will allow functions and
+    /// events to have.
+    #[serde(default = "default_max_statements")]
+    pub max_statements: usize,
+    /// The maximum number of characters the [line_too_long] lint will allow a line to have.
+    #[serde(default = "default_max_line_length")]
+    pub max_line_length: usize,
+    /// Numbers that the [magic_number] lint will allow to be written directly in code.
+    #[serde(default = "default_allowed_magic_numbers")]
+    pub allowed_magic_numbers: Vec<f64>,
     /// Whether or not [statement_parenthetical_violation] lint should be
     /// asserting that statements do or do not contain surrounding
     /// parenthesis.
@@ -54,6 +76,10 @@ pub struct Config {
     /// math.
     #[serde(default)]
     pub simplification_rules: SimplificationRules,
+    /// The kinds of names the `shadowing` lint should check local variables and parameters
+    /// against.
+    #[serde(default)]
+    pub shadowing_rules: ShadowingRules,
     /// Manual definitions for any lint's lint level. The key is the lint's tag.
     ///
     /// FIXME: We do not currently validate that all entries are valid lint
@@ -67,12 +93,43 @@ pub struct Config {
     /// File paths that duck should ignore.
     #[serde(default)]
     pub ignored_file_paths: Vec<String>,
+    /// The tags of lints whose `allow` tags must give a reason in order to suppress them (ie:
+    /// `#[allow(global, reason = "...")]`). Use `"*"` to require a reason for every lint.
+    #[serde(default)]
+    pub require_allow_reasons: Vec<String>,
+    /// Whether or not `allow` tags should stop suppressing lints once the date in their `until`
+    /// attribute has passed (ie: `#[allow(global, until = "2026-12-31")]`).
+    #[serde(default = "default_expire_allows")]
+    pub expire_allows: bool,
+    /// Rules for the `banned_call` lint, each describing a set of functions that should not be
+    /// called.
+    #[serde(default)]
+    pub banned_calls: Vec<BannedCallRule>,
+    /// Rules for the `pattern_violation` lint, each describing a structural pattern of code that
+    /// should be reported.
+    #[serde(default)]
+    pub pattern_rules: Vec<PatternRule>,
+    /// Scripts whose declarations are a public API, such as a library used by other projects,
+    /// that the `unused_*` lints should never report. Each pattern is matched against the end of
+    /// the file's path, just like [BannedCallRule::allowed_paths].
+    #[serde(default)]
+    pub public_api_paths: Vec<String>,
+    /// Global symbols that are used in ways duck can't see, such as names built at runtime for
+    /// `asset_get_index`, that the `unused_*` lints should never report. A `*` matches any number
+    /// of characters, and enum members are written as `Enum.Member`.
+    #[serde(default)]
+    pub used_symbols: Vec<String>,
 }
 impl Default for Config {
     fn default() -> Self {
         Self {
             todo_keyword: default_todo_keyword(),
             max_arguments: default_max_arguments(),
+            max_complexity: default_max_complexity(),
+            max_nesting_depth: default_max_nesting_depth(),
+            max_statements: default_max_statements(),
+            max_line_length: default_max_line_length(),
+            allowed_magic_numbers: default_allowed_magic_numbers(),
             statement_parentheticals: default_statement_parentheticals(),
             var_prefixes: default_var_prefixes(),
             length_enum_member_name: default_length_enum_member_name(),
@@ -84,7 +141,14 @@ impl Default for Config {
             casing_rules: Default::default(),
             lint_levels: Default::default(),
             simplification_rules: Default::default(),
+            shadowing_rules: Default::default(),
             ignored_file_paths: Default::default(),
+            require_allow_reasons: Default::default(),
+            expire_allows: default_expire_allows(),
+            banned_calls: Default::default(),
+            pattern_rules: Default::default(),
+            public_api_paths: Default::default(),
+            used_symbols: Default::default(),
         }
     }
 }
@@ -92,50 +156,10 @@ impl Config {
     /// Creates a config with every possible field present at its default value.
     pub fn full() -> Self {
         Self {
-            lint_levels: HashMap::from([
-                // @tags
-                ("accessor_alternative".into(), LintLevel::Warn),
-                ("and_preference".into(), LintLevel::Allow),
-                ("anonymous_constructor".into(), LintLevel::Allow),
-                ("bool_equality".into(), LintLevel::Allow),
-                ("casing_rules".into(), LintLevel::Allow),
-                ("collapsable_if".into(), LintLevel::Warn),
-                ("condition_wrapper".into(), LintLevel::Allow),
-                ("deprecated".into(), LintLevel::Warn),
-                ("draw_sprite".into(), LintLevel::Allow),
-                ("draw_text".into(), LintLevel::Allow),
-                ("english_flavor_violation".into(), LintLevel::Allow),
-                ("exit".into(), LintLevel::Allow),
-                ("global".into(), LintLevel::Allow),
-                ("invalid_assignment".into(), LintLevel::Deny),
-                ("invalid_comparison".into(), LintLevel::Deny),
-                ("invalid_equality".into(), LintLevel::Deny),
-                ("missing_case_member".into(), LintLevel::Warn),
-                ("missing_default_case".into(), LintLevel::Allow),
-                ("mod_preference".into(), LintLevel::Allow),
-                ("multi_var_declaration".into(), LintLevel::Allow),
-                ("non_constant_default_parameter".into(), LintLevel::Warn),
-                ("non_simplified_expression".into(), LintLevel::Warn),
-                ("not_preference".into(), LintLevel::Allow),
-                ("or_preference".into(), LintLevel::Allow),
-                ("room_goto".into(), LintLevel::Allow),
-                ("show_debug_message".into(), LintLevel::Allow),
-                ("single_equals_comparison".into(), LintLevel::Warn),
-                ("single_switch_case".into(), LintLevel::Warn),
-                ("suspicious_constant_usage".into(), LintLevel::Deny),
-                ("switch_without_case".into(), LintLevel::Warn),
-                ("todo".into(), LintLevel::Allow),
-                ("too_many_arguments".into(), LintLevel::Warn),
-                ("try_catch".into(), LintLevel::Allow),
-                ("unassigned_constructor".into(), LintLevel::Warn),
-                ("unnecessary_grouping".into(), LintLevel::Warn),
-                ("unused_local_variable".into(), LintLevel::Warn),
-                ("unused_parameter".into(), LintLevel::Warn),
-                ("useless_function".into(), LintLevel::Deny),
-                ("var_prefix_violation".into(), LintLevel::Allow),
-                ("with_loop".into(), LintLevel::Allow),
-                // @end tags
-            ]),
+            lint_levels: LintRegistry::builtin()
+                .iter()
+                .map(|lint| (lint.tag().into(), lint.default_level()))
+                .collect(),
             ..Default::default()
         }
     }
@@ -143,13 +167,72 @@ impl Config {
     /// if the user hasn't specifide anything.
     pub fn get_lint_level_setting(&self, tag: &str, default: LintLevel) -> LintLevelSetting {
         // Check if there is a config-based rule for this lint
-        if let Some((_, level)) = self.lint_levels.iter().find(|(key, _)| key == &tag) {
+        if let Some(level) = self.lint_levels.get(tag) {
             LintLevelSetting::ConfigSpecified(*level)
         } else {
             LintLevelSetting::Default(default)
         }
     }
 
+    /// Returns the config with the lint levels set by the given tags (ie: `#[allow(draw_text)]` or
+    /// `#[deny(todo)]`) applied on top of it. If none of the tags set a lint level, the config is
+    /// simply borrowed. `allow` tags with a [SuppressionIssue] are ignored.
+    pub fn with_tags(&self, tags: &[Tag]) -> Cow<'_, Config> {
+        let mut config = Cow::Borrowed(self);
+        for tag in tags {
+            if let Some(level) = LintLevel::from_str(tag.label()) {
+                for lint in tag.parameters() {
+                    if level == LintLevel::Allow && self.allow_issue(tag, lint).is_some() {
+                        continue;
+                    }
+                    config.to_mut().lint_levels.insert(lint.to_string(), level);
+                }
+            }
+        }
+        config
+    }
+
+    /// Returns the config with any settings that do not apply to the file at the given path
+    /// removed, such as [BannedCallRule]s that are allowed in it. If nothing needs to be removed,
+    /// the config is simply borrowed.
+    pub fn for_file(&self, path: &Path) -> Cow<'_, Config> {
+        if self.banned_calls.iter().any(|rule| rule.allows_file(path)) {
+            let mut config = self.clone();
+            config.banned_calls.retain(|rule| !rule.allows_file(path));
+            Cow::Owned(config)
+        } else {
+            Cow::Borrowed(self)
+        }
+    }
+
+    /// Returns whether the file at the given path is a part of the project's public API, according
+    /// to [Config::public_api_paths].
+    pub fn is_public_api(&self, path: &Path) -> bool {
+        self.public_api_paths.iter().any(|pattern| path_match(pattern, path))
+    }
+
+    /// Returns whether the global symbol with the given name is listed in
+    /// [Config::used_symbols].
+    pub fn is_used_symbol(&self, name: &str) -> bool {
+        self.used_symbols.iter().any(|pattern| glob_match(pattern, name))
+    }
+
+    /// Returns the issue preventing an `allow` tag from suppressing the given lint, if there is
+    /// one.
+    pub fn allow_issue(&self, tag: &Tag, lint: &str) -> Option<SuppressionIssue> {
+        if let Some(until) = tag.until().filter(|_| self.expire_allows) {
+            match Date::parse(until) {
+                Some(date) if date < Date::today() => return Some(SuppressionIssue::Expired(until.into())),
+                Some(_) => {}
+                None => return Some(SuppressionIssue::InvalidDate(until.into())),
+            }
+        }
+        if tag.reason().is_none() && self.require_allow_reasons.iter().any(|v| v == lint || v == "*") {
+            return Some(SuppressionIssue::MissingReason);
+        }
+        None
+    }
+
     /// Get the config's prefer and keyword.
     pub fn prefer_and_keyword(&self) -> bool {
         self.prefer_and_keyword
@@ -180,6 +263,21 @@ fn default_todo_keyword() -> String {
 fn default_max_arguments() -> usize {
     7
 }
+fn default_max_complexity() -> usize {
+    20
+}
+fn default_max_nesting_depth() -> usize {
+    5
+}
+fn default_max_statements() -> usize {
+    100
+}
+fn default_max_line_length() -> usize {
+    120
+}
+fn default_allowed_magic_numbers() -> Vec<f64> {
+    vec![-1.0, 0.0, 1.0, 2.0]
+}
 fn default_statement_parentheticals() -> bool {
     true
 }
@@ -192,6 +290,9 @@ fn default_english_flavor() -> EnglishFlavor {
 fn default_length_enum_member_name() -> String {
     "Len".into()
 }
+fn default_expire_allows() -> bool {
+    true
+}
 
 /// The spelling preference in the user's codebase for the built-in GameMaker
 /// functions (such as `draw_color` vs `draw_colour`).
@@ -239,6 +340,159 @@ impl Default for CasingRules {
     }
 }
 
+/// A rule for the `banned_call` lint, written as a `[[banned_calls]]` table in the config.
+///
+/// ```toml
+/// [[banned_calls]]
+/// patterns = ["draw_text*"]
+/// message = "text must be drawn through our localization system"
+/// replacement = "ui_draw_text"
+/// level = "deny"
+/// allowed_paths = ["scripts/ui_*/*"]
+/// allowed_events = ["Draw_64"]
+/// ```
+///
+/// When several rules ban the same function, only the most specific one applies: a pattern without
+/// a `*` beats any that has one, and otherwise the pattern with the most characters besides `*`
+/// wins. Rules that are equally specific are resolved in the order they are written.
+#[derive(Debug, PartialEq, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct BannedCallRule {
+    /// The names of the banned functions. A `*` matches any number of characters, so
+    /// `draw_text*` bans `draw_text` along with all of its variants.
+    pub patterns: Vec<String>,
+    /// An explanation of why the functions are banned.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// The function that should be called instead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replacement: Option<String>,
+    /// The level to report calls at, if different from the level of `banned_call`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub level: Option<LintLevel>,
+    /// Files the functions may be called in. Each pattern is matched against the end of the
+    /// file's path, with `*` matching anything within a folder or file name and `**` matching any
+    /// number of folders (ie: `scripts/debug_*/*` or `objects/obj_hud/**`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_paths: Vec<String>,
+    /// Object events the functions may be called in, matched against the name of the event's
+    /// file (ie: `Draw_*` for every draw event, or `Draw_64` for just the Draw GUI event).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_events: Vec<String>,
+}
+impl BannedCallRule {
+    /// Returns whether the function with the given name is banned by this rule.
+    pub fn bans(&self, function_name: &str) -> bool {
+        self.specificity(function_name).is_some()
+    }
+
+    /// Returns how specifically this rule bans the function with the given name, or None if it
+    /// doesn't. Exact names are more specific than any pattern with a `*`, and patterns are more
+    /// specific the more characters they have besides `*`.
+    pub fn specificity(&self, function_name: &str) -> Option<(bool, usize)> {
+        self.patterns
+            .iter()
+            .filter(|pattern| glob_match(pattern, function_name))
+            .map(|pattern| (!pattern.contains('*'), pattern.chars().filter(|c| *c != '*').count()))
+            .max()
+    }
+
+    /// Returns whether the banned functions may be called in the file at the given path, due to
+    /// either its `allowed_paths` or `allowed_events`.
+    pub fn allows_file(&self, path: &Path) -> bool {
+        let allowed_path = self.allowed_paths.iter().any(|pattern| path_match(pattern, path));
+        let path = path.to_string_lossy().replace('\\', "/");
+        let allowed_event = || {
+            let mut folders = path.rsplit('/').skip(1);
+            let in_object = folders.nth(1) == Some("objects");
+            let event = path.rsplit('/').next().unwrap_or_default().trim_end_matches(".gml");
+            in_object && self.allowed_events.iter().any(|pattern| glob_match(pattern, event))
+        };
+        allowed_path || allowed_event()
+    }
+}
+
+/// A rule for the `pattern_violation` lint, written as a `[[pattern_rules]]` table in the config.
+/// See [Pattern] for how patterns are written.
+///
+/// ```toml
+/// [[pattern_rules]]
+/// pattern = "if ($a == true) $body"
+/// message = "comparisons to `true` are redundant"
+/// rewrite = "if ($a) $body"
+/// ```
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct PatternRule {
+    /// The structural pattern of code to report.
+    pub pattern: String,
+    /// An explanation of why the code should not be written this way.
+    pub message: String,
+    /// A template for what the code should be replaced with, which can use the pattern's
+    /// metavariables.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rewrite: Option<String>,
+    /// The level to report matches at, if different from the level of `pattern_violation`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub level: Option<LintLevel>,
+    #[serde(skip)]
+    compiled: OnceLock<Result<Pattern, String>>,
+}
+impl PatternRule {
+    /// Creates a new rule with the given pattern and message.
+    pub fn new(pattern: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            pattern: pattern.into(),
+            message: message.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the parsed pattern, parsing it the first time this is called.
+    ///
+    /// ### Errors
+    ///
+    /// Returns the reason the pattern could not be parsed if it is invalid.
+    pub fn pattern(&self) -> Result<&Pattern, &str> {
+        self.compiled
+            .get_or_init(|| Pattern::new(&self.pattern).map_err(|diagnostic| diagnostic.message))
+            .as_ref()
+            .map_err(String::as_str)
+    }
+}
+
+/// Returns whether the glob pattern matches any trailing portion of the path that starts at a
+/// folder (ie: `scripts/debug_*/*`).
+fn path_match(pattern: &str, path: &Path) -> bool {
+    let path = path.to_string_lossy().replace('\\', "/");
+    std::iter::once(0)
+        .chain(path.match_indices('/').map(|(i, _)| i + 1))
+        .any(|start| glob_match(pattern, &path[start..]))
+}
+
+/// Returns whether the text matches the glob pattern, where `*` matches anything but a `/` and `**`
+/// matches anything at all.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    if let Some(rest) = pattern.strip_prefix("**/") {
+        return std::iter::once(0)
+            .chain(text.match_indices('/').map(|(i, _)| i + 1))
+            .any(|i| glob_match(rest, &text[i..]));
+    }
+    if let Some(rest) = pattern.strip_prefix("**") {
+        return (0..=text.len())
+            .filter(|i| text.is_char_boundary(*i))
+            .any(|i| glob_match(rest, &text[i..]));
+    }
+    if let Some(rest) = pattern.strip_prefix('*') {
+        return (0..=text.find('/').unwrap_or(text.len()))
+            .filter(|i| text.is_char_boundary(*i))
+            .any(|i| glob_match(rest, &text[i..]));
+    }
+    match (pattern.chars().next(), text.chars().next()) {
+        (Some(p), Some(t)) if p == t => glob_match(&pattern[p.len_utf8()..], &text[t.len_utf8()..]),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
 /// Contains preferences for the `non_simplified_expression` lint.
 #[derive(Debug, PartialEq, Copy, Clone, serde::Serialize, serde::Deserialize)]
 pub struct SimplificationRules {
@@ -265,6 +519,34 @@ impl Default for SimplificationRules {
     }
 }
 
+/// Contains preferences for the `shadowing` lint.
+#[derive(Debug, PartialEq, Copy, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct ShadowingRules {
+    /// Whether or not local variables may share a name with a parameter of their function.
+    pub check_parameters: bool,
+    /// Whether or not local variables may share a name with a local variable declared in a block
+    /// around them (ie: a loop's `var i` inside another loop's `var i`).
+    pub check_locals: bool,
+    /// Whether or not local variables and parameters within objects and rooms may share a name
+    /// with a built-in instance variable (ie: `x`, `depth` or `image_index`).
+    pub check_instance_variables: bool,
+    /// Whether or not local variables and parameters may share a name with a built-in global
+    /// variable (ie: `room` or `delta_time`), or with a function, macro, enum or globalvar in the
+    /// project.
+    pub check_globals: bool,
+}
+impl Default for ShadowingRules {
+    fn default() -> Self {
+        Self {
+            check_parameters: true,
+            check_locals: true,
+            check_instance_variables: true,
+            check_globals: true,
+        }
+    }
+}
+
 /// The various casing options supported by duck for the `casing_rules` lint.
 #[derive(Debug, PartialEq, Copy, Clone, enum_map::Enum, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "snake_case")]
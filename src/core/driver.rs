@@ -1,12 +1,19 @@
 use crate::{
     Config, FileId, GmlLibrary,
-    lint::{collection::*, *},
-    parse::{Ast, Expr, ParseVisitor, Parser, Stmt},
+    analyze::{Body, Project},
+    lint::{
+        Lint, LintLevel, LintRegistry, Suppression,
+        collection::{UnknownLint, UnusedAllow},
+        visit_tags,
+    },
+    parse::{Ast, Expr, Location, ParseVisitor, Parser, Span, Stmt, Tag},
 };
 use async_walkdir::{DirEntry, Filtering, WalkDir};
-use codespan_reporting::diagnostic::Diagnostic;
+use codespan_reporting::diagnostic::{Diagnostic, Label, LabelStyle, Severity};
 use futures::StreamExt;
+use hashbrown::{HashMap, HashSet};
 use std::{
+    borrow::Cow,
     path::{Path, PathBuf},
     sync::Arc,
 };
@@ -25,169 +32,389 @@ pub fn parse_gml(source_code: &'static str, file_id: &FileId) -> Result<Ast, Dia
     Parser::new(source_code, *file_id).into_ast()
 }
 
-/// Runs an [Ast] through its pass, running any lint that implements [AstPass].
-///
-/// NOTE: This function is largely auto-generated! See `CONTRIBUTING.md` for
-/// more information.
-pub fn process_ast(ast: &Ast, reports: &mut Vec<Diagnostic<FileId>>, config: &Config) {
-    // @ast calls. Do not remove this comment!
-    run_lint_on_ast::<UnusedLocalVariable>(ast, config, reports);
-    // @end ast calls. Do not remove this comment!
+/// Everything the lints found in a file: the diagnostics to report, along with every diagnostic
+/// that an `allow` tag suppressed.
+#[derive(Debug, Default)]
+pub struct Reports {
+    /// The diagnostics to report.
+    pub diagnostics: Vec<Diagnostic<FileId>>,
+    /// The diagnostics that were suppressed by an `allow` tag.
+    pub suppressions: Vec<Suppression>,
 }
+impl Reports {
+    /// Adds diagnostics produced where the given `allow` tags are in effect, recording those from
+    /// the lints they allow as suppressed instead of reporting them.
+    fn add(&mut self, diagnostics: Vec<Diagnostic<FileId>>, allows: &Allows) {
+        for diagnostic in diagnostics {
+            match diagnostic.code.as_ref().and_then(|lint| allows.get(lint)) {
+                Some(tag) => self.suppressions.push(Suppression::new(diagnostic, tag.clone())),
+                None => self.diagnostics.push(diagnostic),
+            }
+        }
+    }
 
-/// Runs a [Stmt] through the early pass, running any lint that
-/// implements [EarlyStmtPass], as well as collecting information
-/// into the provided [GlobalScopeBuilder].
-///
-/// NOTE: This function is largely auto-generated! See `CONTRIBUTING.md` for
-/// more information.
-pub fn process_stmt_early(stmt: &Stmt, reports: &mut Vec<Diagnostic<FileId>>, config: &Config) {
-    // @early stmt calls. Do not remove this comment!
-    run_early_lint_on_stmt::<CasingRules>(stmt, config, reports);
-    run_early_lint_on_stmt::<CollapsableIf>(stmt, config, reports);
-    run_early_lint_on_stmt::<ConditionWrapper>(stmt, config, reports);
-    run_early_lint_on_stmt::<Deprecated>(stmt, config, reports);
-    run_early_lint_on_stmt::<Exit>(stmt, config, reports);
-    run_early_lint_on_stmt::<Global>(stmt, config, reports);
-    run_early_lint_on_stmt::<InvalidAssignment>(stmt, config, reports);
-    run_early_lint_on_stmt::<MissingDefaultCase>(stmt, config, reports);
-    run_early_lint_on_stmt::<MultiVarDeclaration>(stmt, config, reports);
-    run_early_lint_on_stmt::<SingleSwitchCase>(stmt, config, reports);
-    run_early_lint_on_stmt::<SuspicousConstantUsage>(stmt, config, reports);
-    run_early_lint_on_stmt::<SwitchWithoutCase>(stmt, config, reports);
-    run_early_lint_on_stmt::<TryCatch>(stmt, config, reports);
-    run_early_lint_on_stmt::<UnassignedConstructor>(stmt, config, reports);
-    run_early_lint_on_stmt::<UnnecessaryGrouping>(stmt, config, reports);
-    run_early_lint_on_stmt::<UselessFunction>(stmt, config, reports);
-    run_early_lint_on_stmt::<VarPrefixViolation>(stmt, config, reports);
-    run_early_lint_on_stmt::<WithLoop>(stmt, config, reports);
-    // @end early stmt calls. Do not remove this comment!
+    /// Adds diagnostics produced by lints that looked over the given statements as a whole. Rather
+    /// than using the tags of whatever the lints were run on, each diagnostic is given the tags
+    /// around its primary label, so tags on the statement or expression it points at still apply.
+    fn add_within(
+        &mut self,
+        diagnostics: Vec<Diagnostic<FileId>>,
+        stmts: &[Stmt],
+        config: &Config,
+        allows: &Allows,
+        lints: &LintRegistry,
+    ) {
+        for mut diagnostic in diagnostics {
+            let Some(location) = primary_location(&diagnostic) else {
+                self.add(vec![diagnostic], allows);
+                continue;
+            };
+            let (mut config, mut allows) = (config.clone(), allows.clone());
+            for stmt in stmts {
+                enter_stmt_around(stmt, location, &mut config, &mut allows);
+            }
+            if let Some(lint) = diagnostic.code.as_deref().and_then(|code| lints.get(code)) {
+                diagnostic.severity = match *config.get_lint_level_setting(lint.tag(), lint.default_level()) {
+                    LintLevel::Allow => continue,
+                    LintLevel::Warn => Severity::Warning,
+                    LintLevel::Deny => Severity::Error,
+                };
+            }
+            self.add(vec![diagnostic], &allows);
+        }
+    }
+}
 
-    // Recurse...
-    let stmt = stmt.kind();
-    stmt.visit_child_stmts(|stmt| process_stmt_early(stmt, reports, config));
-    stmt.visit_child_exprs(|expr| process_expr_early(expr, reports, config));
+/// Maps the tags of lints to the innermost `allow` tag suppressing them.
+type Allows = HashMap<String, Tag>;
+
+/// Applies the tags to the config and to the `allow` tags in effect. Rather than turning the lints
+/// they allow off, `allow` tags are collected, so the lints still run and what they report can be
+/// recorded as suppressed. This is how unused `allow` tags are found without linting anything
+/// twice. Any other level given to a lint ends the `allow` tags around it.
+fn enter<'a>(tags: &[Tag], config: &'a Config, allows: &'a Allows) -> (Cow<'a, Config>, Cow<'a, Allows>) {
+    let mut allows = Cow::Borrowed(allows);
+    let mut raising_tags = vec![];
+    for tag in tags {
+        match LintLevel::from_str(tag.label()) {
+            Some(LintLevel::Allow) => {
+                for lint in tag.parameters().filter(|lint| config.allow_issue(tag, lint).is_none()) {
+                    allows.to_mut().insert(lint.to_string(), tag.clone());
+                }
+            }
+            Some(_) => {
+                for lint in tag.parameters() {
+                    if allows.contains_key(lint) {
+                        allows.to_mut().remove(lint);
+                    }
+                }
+                raising_tags.push(tag.clone());
+            }
+            None => {}
+        }
+    }
+    (config.with_tags(&raising_tags), allows)
 }
 
-/// Runs an expression through the early pass, running any lint that
-/// implements [EarlyExprPass], as well as collecting information
-/// into the provided [GlobalScopeBuilder].
-///
-/// NOTE: This function is largely auto-generated! See `CONTRIBUTING.md` for
-/// more information.
-pub fn process_expr_early(expr: &Expr, reports: &mut Vec<Diagnostic<FileId>>, config: &Config) {
-    // @early expr calls. Do not remove this comment!
-    run_early_lint_on_expr::<AccessorAlternative>(expr, config, reports);
-    run_early_lint_on_expr::<AndPreference>(expr, config, reports);
-    run_early_lint_on_expr::<AnonymousConstructor>(expr, config, reports);
-    run_early_lint_on_expr::<BoolEquality>(expr, config, reports);
-    run_early_lint_on_expr::<CasingRules>(expr, config, reports);
-    run_early_lint_on_expr::<ConditionWrapper>(expr, config, reports);
-    run_early_lint_on_expr::<Deprecated>(expr, config, reports);
-    run_early_lint_on_expr::<DrawSprite>(expr, config, reports);
-    run_early_lint_on_expr::<DrawText>(expr, config, reports);
-    run_early_lint_on_expr::<EnglishFlavorViolation>(expr, config, reports);
-    run_early_lint_on_expr::<InvalidComparison>(expr, config, reports);
-    run_early_lint_on_expr::<InvalidEquality>(expr, config, reports);
-    run_early_lint_on_expr::<ModPreference>(expr, config, reports);
-    run_early_lint_on_expr::<NonSimplifiedExpression>(expr, config, reports);
-    run_early_lint_on_expr::<NotPreference>(expr, config, reports);
-    run_early_lint_on_expr::<OrPreference>(expr, config, reports);
-    run_early_lint_on_expr::<RoomGoto>(expr, config, reports);
-    run_early_lint_on_expr::<ShowDebugMessage>(expr, config, reports);
-    run_early_lint_on_expr::<SingleEqualsComparison>(expr, config, reports);
-    run_early_lint_on_expr::<SuspicousConstantUsage>(expr, config, reports);
-    run_early_lint_on_expr::<Todo>(expr, config, reports);
-    run_early_lint_on_expr::<TooManyArguments>(expr, config, reports);
-    run_early_lint_on_expr::<UnnecessaryGrouping>(expr, config, reports);
-    run_early_lint_on_expr::<UnusedParameter>(expr, config, reports);
-    // @end early expr calls. Do not remove this comment!
+/// Returns where the primary label of a diagnostic points, if it has one.
+fn primary_location(diagnostic: &Diagnostic<FileId>) -> Option<Location> {
+    diagnostic
+        .labels
+        .iter()
+        .find(|label| label.style == LabelStyle::Primary)
+        .map(|label| Location(label.file_id, Span::new(label.range.start, label.range.end)))
+}
 
-    // Recurse...
-    expr.visit_child_stmts(|stmt| process_stmt_early(stmt, reports, config));
-    expr.visit_child_exprs(|expr| process_expr_early(expr, reports, config));
+/// Returns whether the span, found in the given file, covers the location.
+fn covers(file_id: FileId, span: Span, Location(location_file, location): Location) -> bool {
+    file_id == location_file
+        && span.start() < span.end()
+        && span.start() <= location.start()
+        && location.end() <= span.end()
 }
 
-/// Runs a [Stmt] through the late pass, running any lint that
-/// implements [LateStmtPass].
-///
-/// NOTE: This function is largely auto-generated! See `CONTRIBUTING.md` for
-/// more information.
-pub fn process_stmt_late(stmt: &Stmt, reports: &mut Vec<Diagnostic<FileId>>, config: &Config) {
-    // @late stmt calls. Do not remove this comment!
-    run_late_lint_on_stmt::<MissingCaseMember>(stmt, config, reports);
-    // @end late stmt calls. Do not remove this comment!
+/// Enters the tags of the statement if it covers the location, followed by those of its children
+/// that do, so that the config and `allow` tags end up as they are at the location.
+fn enter_stmt_around(stmt: &Stmt, location: Location, config: &mut Config, allows: &mut Allows) {
+    if !covers(stmt.file_id(), stmt.span(), location) {
+        return;
+    }
+    let (entered_config, entered_allows) = enter(stmt.tags(), config, allows);
+    let (entered_config, entered_allows) = (entered_config.into_owned(), entered_allows.into_owned());
+    *config = entered_config;
+    *allows = entered_allows;
+    stmt.visit_child_stmts(|stmt| enter_stmt_around(stmt, location, config, allows));
+    stmt.visit_child_exprs(|expr| enter_expr_around(expr, location, config, allows));
+}
+
+/// Enters the tags of the expression if it covers the location, followed by those of its children
+/// that do. See [enter_stmt_around].
+fn enter_expr_around(expr: &Expr, location: Location, config: &mut Config, allows: &mut Allows) {
+    if !covers(expr.file_id(), expr.span(), location) {
+        return;
+    }
+    let (entered_config, entered_allows) = enter(expr.tags(), config, allows);
+    let (entered_config, entered_allows) = (entered_config.into_owned(), entered_allows.into_owned());
+    *config = entered_config;
+    *allows = entered_allows;
+    expr.visit_child_stmts(|stmt| enter_stmt_around(stmt, location, config, allows));
+    expr.visit_child_exprs(|expr| enter_expr_around(expr, location, config, allows));
+}
+
+/// Runs every pass over an [Ast] on its own, returning everything the lints found. Files that are
+/// a part of a larger project are instead run through [process_early] as they are parsed, and then
+/// through [process_late] once the whole [Project] is known.
+pub fn process_file(ast: &Ast, config: &Config, lints: &LintRegistry, project: &Project) -> Reports {
+    let mut reports = Reports::default();
+    process_early(ast, &mut reports, config, lints);
+    process_late(ast, &mut reports, config, lints, project);
+    reports
+}
+
+/// Runs an [Ast] through the early pass, running every lint's [Lint::visit_stmt_early] and
+/// [Lint::visit_expr_early] on each of its statements and their children.
+pub fn process_early(ast: &Ast, reports: &mut Reports, config: &Config, lints: &LintRegistry) {
+    let allows = Allows::new();
+    let (config, allows) = enter(ast.tags(), config, &allows);
+    for stmt in ast.stmts() {
+        process_stmt_early(stmt, reports, &config, &allows, lints);
+    }
+}
+
+/// Runs an [Ast] through the Ast pass and the late pass, running every lint's [Lint::visit_ast],
+/// followed by [Lint::visit_body_late], [Lint::visit_stmt_late] and [Lint::visit_expr_late] on
+/// its statements and their children. Then reports any `allow` tags that did not suppress
+/// anything, so the reports must already hold whatever the early pass found in the file.
+pub fn process_late(ast: &Ast, reports: &mut Reports, config: &Config, lints: &LintRegistry, project: &Project) {
+    process_unknown_lints(ast, &config.with_tags(ast.tags()), lints, &mut reports.diagnostics);
+    let allows = Allows::new();
+    let (tagged_config, allows) = enter(ast.tags(), config, &allows);
+    run_ast_lints(ast, &tagged_config, &allows, lints, reports);
+    process_body_late(ast.stmts(), reports, &tagged_config, &allows, lints, project);
+    process_unused_allows(ast, config, lints, reports);
+}
+
+/// Runs every enabled lint's [Lint::visit_ast] on the Ast.
+fn run_ast_lints(ast: &Ast, config: &Config, allows: &Allows, lints: &LintRegistry, reports: &mut Reports) {
+    let mut diagnostics = vec![];
+    for lint in enabled_lints(config, lints) {
+        lint.visit_ast(ast, config, &mut diagnostics);
+    }
+    reports.add_within(diagnostics, ast.stmts(), config, allows, lints);
+}
+
+/// Runs a [Stmt] through the early pass, running every lint's [Lint::visit_stmt_early] and
+/// [Lint::visit_expr_early] on it and its children.
+fn process_stmt_early(stmt: &Stmt, reports: &mut Reports, config: &Config, allows: &Allows, lints: &LintRegistry) {
+    let (config, allows) = enter(stmt.tags(), config, allows);
+    run_early_stmt_lints(stmt, &config, &allows, lints, reports);
 
     // Recurse...
     let stmt = stmt.kind();
-    stmt.visit_child_stmts(|stmt| process_stmt_late(stmt, reports, config));
-    stmt.visit_child_exprs(|expr| process_expr_late(expr, reports, config));
+    stmt.visit_child_stmts(|stmt| process_stmt_early(stmt, reports, &config, &allows, lints));
+    stmt.visit_child_exprs(|expr| process_expr_early(expr, reports, &config, &allows, lints));
 }
 
-/// Runs an expression through the late pass, running any lint that
-/// implements [LateExprPass].
-///
-///  NOTE: This function is largely auto-generated! See `CONTRIBUTING.md`
-/// for more information.
-fn process_expr_late(expr: &Expr, reports: &mut Vec<Diagnostic<FileId>>, config: &Config) {
-    // @late expr calls. Do not remove this comment!
-    run_late_lint_on_expr::<NonConstantDefaultParameter>(expr, config, reports);
-    // @end late expr calls. Do not remove this comment!
+/// Runs every enabled lint's [Lint::visit_stmt_early] on the statement, without recursing.
+fn run_early_stmt_lints(stmt: &Stmt, config: &Config, allows: &Allows, lints: &LintRegistry, reports: &mut Reports) {
+    let mut diagnostics = vec![];
+    for lint in enabled_lints(config, lints) {
+        lint.visit_stmt_early(stmt, config, &mut diagnostics);
+    }
+    reports.add(diagnostics, allows);
+}
+
+/// Runs an expression through the early pass, running every lint's [Lint::visit_stmt_early] and
+/// [Lint::visit_expr_early] on it and its children.
+fn process_expr_early(expr: &Expr, reports: &mut Reports, config: &Config, allows: &Allows, lints: &LintRegistry) {
+    let (config, allows) = enter(expr.tags(), config, allows);
+    run_early_expr_lints(expr, &config, &allows, lints, reports);
 
     // Recurse...
-    expr.visit_child_stmts(|stmt| process_stmt_late(stmt, reports, config));
-    expr.visit_child_exprs(|expr| process_expr_late(expr, reports, config));
+    expr.visit_child_stmts(|stmt| process_stmt_early(stmt, reports, &config, &allows, lints));
+    expr.visit_child_exprs(|expr| process_expr_early(expr, reports, &config, &allows, lints));
 }
 
-/// Performs a lint on an Ast
-fn run_lint_on_ast<T: Lint + AstPass>(ast: &Ast, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
-    if *config.get_lint_level_setting(T::tag(), T::default_level()) != LintLevel::Allow {
-        T::visit_ast(ast, config, reports);
+/// Runs every enabled lint's [Lint::visit_expr_early] on the expression, without recursing.
+fn run_early_expr_lints(expr: &Expr, config: &Config, allows: &Allows, lints: &LintRegistry, reports: &mut Reports) {
+    let mut diagnostics = vec![];
+    for lint in enabled_lints(config, lints) {
+        lint.visit_expr_early(expr, config, &mut diagnostics);
     }
+    reports.add(diagnostics, allows);
 }
 
-/// Performs a given [EarlyStmtPass] on a statement.
-fn run_early_lint_on_stmt<T: Lint + EarlyStmtPass>(
+/// Runs a [Stmt] through the late pass, running every lint's [Lint::visit_stmt_late] and
+/// [Lint::visit_expr_late] on it and its children. The [Body] is the function or file the statement
+/// is in.
+fn process_stmt_late(
     stmt: &Stmt,
+    body: &Body,
+    reports: &mut Reports,
     config: &Config,
-    reports: &mut Vec<Diagnostic<FileId>>,
+    allows: &Allows,
+    lints: &LintRegistry,
+    project: &Project,
 ) {
-    if stmt.tag().is_none_or(|tag| !tag.eq(&("allow", Some(T::tag()))))
-        && *config.get_lint_level_setting(T::tag(), T::default_level()) != LintLevel::Allow
-    {
-        T::visit_stmt_early(stmt, config, reports);
+    let (config, allows) = enter(stmt.tags(), config, allows);
+    let mut diagnostics = vec![];
+    for lint in enabled_lints(&config, lints) {
+        lint.visit_stmt_late(stmt, project, &config, &mut diagnostics);
     }
+    reports.add(diagnostics, &allows);
+
+    // Recurse...
+    let stmt = stmt.kind();
+    stmt.visit_child_stmts(|stmt| process_stmt_late(stmt, body, reports, &config, &allows, lints, project));
+    stmt.visit_child_exprs(|expr| process_expr_late(expr, body, reports, &config, &allows, lints, project));
 }
 
-/// Performs a given [EarlyExprPass] on a statement.
-fn run_early_lint_on_expr<T: Lint + EarlyExprPass>(
+/// Runs an expression through the late pass, running every lint's [Lint::visit_stmt_late] and
+/// [Lint::visit_expr_late] on it and its children, as well as [Lint::visit_body_late] on the
+/// bodies of functions. Everything within a function (including its parameters) is given the
+/// function's own body.
+fn process_expr_late(
     expr: &Expr,
+    body: &Body,
+    reports: &mut Reports,
     config: &Config,
-    reports: &mut Vec<Diagnostic<FileId>>,
+    allows: &Allows,
+    lints: &LintRegistry,
+    project: &Project,
 ) {
-    if expr.tag().is_none_or(|tag| !tag.eq(&("allow", Some(T::tag()))))
-        && *config.get_lint_level_setting(T::tag(), T::default_level()) != LintLevel::Allow
-    {
-        T::visit_expr_early(expr, config, reports);
+    let (config, allows) = enter(expr.tags(), config, allows);
+    let mut diagnostics = vec![];
+    for lint in enabled_lints(&config, lints) {
+        lint.visit_expr_late(expr, body, project, &config, &mut diagnostics);
+    }
+    reports.add(diagnostics, &allows);
+
+    // Recurse...
+    if let Some(function) = expr.kind().as_function() {
+        let body = Body::function(function);
+        run_late_body_lints(&body, &config, &allows, lints, project, reports);
+        process_children_late(expr, &body, reports, &config, &allows, lints, project);
+    } else {
+        process_children_late(expr, body, reports, &config, &allows, lints, project);
     }
 }
 
-/// Performs a given [LateStmtPass] on a statement.
-fn run_late_lint_on_stmt<T: Lint + LateStmtPass>(stmt: &Stmt, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
-    if stmt.tag().is_none_or(|tag| !tag.eq(&("allow", Some(T::tag()))))
-        && *config.get_lint_level_setting(T::tag(), T::default_level()) != LintLevel::Allow
-    {
-        T::visit_stmt_late(stmt, config, reports);
+/// Passes the children of an expression to [process_stmt_late] and [process_expr_late] with the
+/// given body. Kept apart from [process_expr_late] because a function's body borrows from the
+/// expression itself, and [Body] can't be shortened to share a variable with the outer one.
+fn process_children_late(
+    expr: &Expr,
+    body: &Body,
+    reports: &mut Reports,
+    config: &Config,
+    allows: &Allows,
+    lints: &LintRegistry,
+    project: &Project,
+) {
+    expr.visit_child_stmts(|stmt| process_stmt_late(stmt, body, reports, config, allows, lints, project));
+    expr.visit_child_exprs(|expr| process_expr_late(expr, body, reports, config, allows, lints, project));
+}
+
+/// Runs the top level statements of a file through the late pass, running every lint's
+/// [Lint::visit_body_late] on them before passing each statement to [process_stmt_late].
+fn process_body_late(
+    stmts: &[Stmt],
+    reports: &mut Reports,
+    config: &Config,
+    allows: &Allows,
+    lints: &LintRegistry,
+    project: &Project,
+) {
+    let body = Body::new(stmts);
+    run_late_body_lints(&body, config, allows, lints, project, reports);
+    for stmt in stmts {
+        process_stmt_late(stmt, &body, reports, config, allows, lints, project);
     }
 }
 
-/// Performs a given [LateExprPass] on a statement.
-fn run_late_lint_on_expr<T: Lint + LateExprPass>(expr: &Expr, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
-    if expr.tag().is_none_or(|tag| !tag.eq(&("allow", Some(T::tag()))))
-        && *config.get_lint_level_setting(T::tag(), T::default_level()) != LintLevel::Allow
-    {
-        T::visit_expr_late(expr, config, reports);
+/// Runs every enabled lint's [Lint::visit_body_late] on the body, without recursing.
+fn run_late_body_lints(
+    body: &Body,
+    config: &Config,
+    allows: &Allows,
+    lints: &LintRegistry,
+    project: &Project,
+    reports: &mut Reports,
+) {
+    let mut diagnostics = vec![];
+    for lint in enabled_lints(config, lints) {
+        lint.visit_body_late(body, project, config, &mut diagnostics);
     }
+    reports.add_within(diagnostics, body.stmts(), config, allows, lints);
+}
+
+/// Returns every lint in the registry that is not set to [LintLevel::Allow] by the config.
+fn enabled_lints<'a>(config: &'a Config, lints: &'a LintRegistry) -> impl Iterator<Item = &'a dyn Lint> {
+    lints
+        .iter()
+        .filter(|lint| *config.get_lint_level_setting(lint.tag(), lint.default_level()) != LintLevel::Allow)
+}
+
+/// Reports every lint named in a tag that has not been registered if [UnknownLint] is enabled.
+fn process_unknown_lints(ast: &Ast, config: &Config, lints: &LintRegistry, reports: &mut Vec<Diagnostic<FileId>>) {
+    visit_tags(ast, config, |tag, config| {
+        if LintLevel::from_str(tag.label()).is_none()
+            || *config.get_lint_level_setting(UnknownLint.tag(), UnknownLint.default_level()) == LintLevel::Allow
+        {
+            return;
+        }
+        for lint in tag.parameters().filter(|lint| !lints.contains(lint)) {
+            let Location(file_id, span) = tag.location();
+            reports.push(
+                UnknownLint
+                    .diagnostic(config)
+                    .with_message(format!("Unknown lint: `{lint}`"))
+                    .with_labels(vec![
+                        Label::primary(file_id, span).with_message("this tag names a lint that does not exist"),
+                    ]),
+            );
+        }
+    });
+}
+
+/// Reports every `allow` tag in the Ast that did not suppress anything if [UnusedAllow] is enabled
+/// where the tag was written. Must be run once every other lint has run on the file, so that
+/// everything they suppressed has been recorded.
+fn process_unused_allows(ast: &Ast, config: &Config, lints: &LintRegistry, reports: &mut Reports) {
+    let used: HashSet<(Location, &str)> = reports
+        .suppressions
+        .iter()
+        .filter_map(|suppression| Some((suppression.tag().location(), suppression.diagnostic().code.as_deref()?)))
+        .collect();
+    let mut unused = vec![];
+    visit_tags(ast, config, |tag, config| {
+        if LintLevel::from_str(tag.label()) != Some(LintLevel::Allow)
+            || *config.get_lint_level_setting(UnusedAllow.tag(), UnusedAllow.default_level()) == LintLevel::Allow
+        {
+            return;
+        }
+        // The lints that inspect tags themselves are run by the driver rather than recorded as
+        // suppressed, and unknown lints are already reported by `unknown_lint`
+        for lint in tag.parameters().filter(|lint| {
+            *lint != UnusedAllow.tag()
+                && *lint != UnknownLint.tag()
+                && lints.contains(lint)
+                && config.allow_issue(tag, lint).is_none()
+                && !used.contains(&(tag.location(), *lint))
+        }) {
+            let Location(file_id, span) = tag.location();
+            unused.push(
+                UnusedAllow
+                    .diagnostic(config)
+                    .with_message(format!("Unused allow for `{lint}`"))
+                    .with_labels(vec![
+                        Label::primary(file_id, span)
+                            .with_message(format!("no `{lint}` lints were suppressed by this tag")),
+                    ]),
+            );
+        }
+    });
+    reports.diagnostics.append(&mut unused);
 }
 
 /// Creates a Tokio task which will walk through the provided directory in
@@ -252,10 +479,10 @@ pub fn start_gml_discovery(
 pub fn start_file_load(
     mut path_receiver: Receiver<PathBuf>,
 ) -> (
-    Receiver<(FileId, &'static str)>,
+    Receiver<(FileId, PathBuf, &'static str)>,
     JoinHandle<(usize, GmlLibrary, Vec<std::io::Error>)>,
 ) {
-    let (file_sender, file_receiver) = channel::<(FileId, &'static str)>(1000);
+    let (file_sender, file_receiver) = channel::<(FileId, PathBuf, &'static str)>(1000);
     let handle = tokio::task::spawn(async move {
         let mut files = GmlLibrary::new();
         let mut io_errors = vec![];
@@ -265,8 +492,9 @@ pub fn start_file_load(
                 Ok(gml) => {
                     let gml: &'static str = Box::leak(Box::new(gml));
                     lines += gml.lines().count();
-                    let file_id = files.add(path.canonicalize().unwrap().to_str().unwrap().to_string(), gml);
-                    file_sender.send((file_id, gml)).await.unwrap();
+                    let path = path.canonicalize().unwrap();
+                    let file_id = files.add(path.to_str().unwrap().to_string(), gml);
+                    file_sender.send((file_id, path, gml)).await.unwrap();
                 }
                 Err(io_error) => io_errors.push(io_error),
             };
@@ -277,20 +505,21 @@ pub fn start_file_load(
 }
 
 /// Creates a Tokio task which will await gml files through `file_receiever`
-/// and subsequently parse them into an [Ast], pumping them into the
-/// returned Receiver. Closes when the `file_receiever` channel closes.
+/// and subsequently parse them into an [Ast], pumping them (along with the path of their file) into
+/// the returned Receiver. Closes when the `file_receiever` channel closes.
 ///
 /// ### Panics
 /// Panics if the receiver for the sender closes. This should not be possible!
+#[allow(clippy::type_complexity)]
 pub fn start_parse(
-    mut file_receiver: Receiver<(FileId, &'static str)>,
-) -> (Receiver<Ast>, JoinHandle<Vec<Diagnostic<FileId>>>) {
-    let (ast_sender, ast_receiver) = channel::<Ast>(1000);
+    mut file_receiver: Receiver<(FileId, PathBuf, &'static str)>,
+) -> (Receiver<(Ast, PathBuf)>, JoinHandle<Vec<Diagnostic<FileId>>>) {
+    let (ast_sender, ast_receiver) = channel::<(Ast, PathBuf)>(1000);
     let handle = tokio::task::spawn(async move {
         let mut parse_errors = vec![];
-        while let Some((file_id, gml)) = file_receiver.recv().await {
+        while let Some((file_id, path, gml)) = file_receiver.recv().await {
             match parse_gml(gml, &file_id) {
-                Ok(ast) => ast_sender.send(ast).await.unwrap(),
+                Ok(ast) => ast_sender.send((ast, path)).await.unwrap(),
                 Err(parse_error) => parse_errors.push(parse_error),
             }
         }
@@ -308,59 +537,78 @@ pub fn start_parse(
 #[allow(clippy::type_complexity)]
 pub fn start_early_pass(
     config: Arc<Config>,
-    mut ast_receiever: Receiver<Ast>,
+    lints: Arc<LintRegistry>,
+    mut ast_receiever: Receiver<(Ast, PathBuf)>,
 ) -> (
-    Receiver<Stmt>,
+    Receiver<(Ast, PathBuf, Arc<Config>, Vec<Suppression>)>,
     Sender<Vec<Diagnostic<FileId>>>,
     Receiver<Vec<Diagnostic<FileId>>>,
     JoinHandle<()>,
 ) {
     let (report_sender, report_receiver) = channel::<Vec<Diagnostic<FileId>>>(1000);
-    let (stmt_sender, stmt_reciever) = channel::<Stmt>(1000);
+    let (late_pass_sender, late_pass_receiver) = channel::<(Ast, PathBuf, Arc<Config>, Vec<Suppression>)>(1000);
     let sender = report_sender.clone();
     let handle = tokio::task::spawn(async move {
-        while let Some(ast) = ast_receiever.recv().await {
-            let config = config.clone();
-            let mut reports = vec![];
-            process_ast(&ast, &mut reports, config.as_ref());
-            sender.send(reports).await.unwrap();
-            for stmt in ast.unpack() {
-                let mut reports = vec![];
-                process_stmt_early(&stmt, &mut reports, config.as_ref());
-                stmt_sender.send(stmt).await.unwrap();
-                sender.send(reports).await.unwrap();
-            }
+        while let Some((ast, path)) = ast_receiever.recv().await {
+            // Some settings, such as the paths banned calls are allowed in, vary between files
+            let config = match config.for_file(&path) {
+                Cow::Borrowed(_) => config.clone(),
+                Cow::Owned(file_config) => Arc::new(file_config),
+            };
+            let mut reports = Reports::default();
+            process_early(&ast, &mut reports, &config, &lints);
+            // The late pass needs to know what was suppressed to find any unused `allow` tags
+            late_pass_sender
+                .send((ast, path, config, reports.suppressions))
+                .await
+                .unwrap();
+            sender.send(reports.diagnostics).await.unwrap();
         }
     });
-    (stmt_reciever, report_sender, report_receiver, handle)
+    (late_pass_receiver, report_sender, report_receiver, handle)
 }
 
-/// Creates Tokio tasks for all of the provided `StmtIteration`s,
-/// running the late lint pass on them. Returns a handle to another
-/// Tokio task which will collect their finalized [LatePassReport]s.
+/// Creates a Tokio task which will collect every Ast into a [Project], and then run the Ast pass
+/// and late lint pass on each of them with the config of the file they came from. Returns a handle
+/// to another Tokio task which will collect every diagnostic, along with every diagnostic that was
+/// suppressed.
 ///
 /// ### Panics
 /// Panics if the receiver for the sender closes. This should not be possible!
+#[allow(clippy::type_complexity)]
 pub fn start_late_pass(
-    config: Arc<Config>,
-    mut stmt_receiver: Receiver<Stmt>,
+    mut ast_receiver: Receiver<(Ast, PathBuf, Arc<Config>, Vec<Suppression>)>,
+    lints: Arc<LintRegistry>,
     report_sender: Sender<Vec<Diagnostic<FileId>>>,
     mut report_receiver: Receiver<Vec<Diagnostic<FileId>>>,
-) -> JoinHandle<Vec<Diagnostic<FileId>>> {
-    tokio::task::spawn(async move {
-        while let Some(stmt) = stmt_receiver.recv().await {
-            let config = config.clone();
-            let mut reports = vec![];
-            process_stmt_late(&stmt, &mut reports, config.as_ref());
-            report_sender.send(reports).await.unwrap();
+) -> JoinHandle<(Vec<Diagnostic<FileId>>, Vec<Suppression>)> {
+    let late_pass = tokio::task::spawn(async move {
+        // Lints in the late pass can look across the whole project, so every file has to be
+        // indexed before any of them run
+        let mut project = Project::new();
+        let mut files = vec![];
+        while let Some((ast, path, config, suppressions)) = ast_receiver.recv().await {
+            project.add(&ast, &path);
+            files.push((ast, config, suppressions));
+        }
+        let mut suppressions = vec![];
+        for (ast, config, early_suppressions) in files {
+            let mut reports = Reports {
+                diagnostics: vec![],
+                suppressions: early_suppressions,
+            };
+            process_late(&ast, &mut reports, &config, &lints, &project);
+            suppressions.append(&mut reports.suppressions);
+            report_sender.send(reports.diagnostics).await.unwrap();
         }
+        suppressions
     });
     tokio::task::spawn(async move {
         let mut lint_reports = vec![];
         while let Some(mut reports) = report_receiver.recv().await {
             lint_reports.append(&mut reports);
         }
-        lint_reports
+        (lint_reports, late_pass.await.unwrap())
     })
 }
 
@@ -1,4 +1,7 @@
-use crate::{Config, lint::LintLevel};
+use crate::{
+    Config,
+    lint::{LintLevel, LintRegistry, Suppression},
+};
 use codespan_reporting::{
     diagnostic::Diagnostic,
     files::{Error, Files, SimpleFile},
@@ -40,11 +43,17 @@ use super::driver;
 #[derive(Debug, Default)]
 pub struct Duck {
     config: Config,
+    lints: LintRegistry,
 }
 impl Duck {
-    /// Creates a new Duck based on a DuckConfig.
+    /// Creates a new Duck based on a DuckConfig, running every lint built into duck.
     pub fn new(config: Config) -> Self {
-        Self { config }
+        Self::new_with_lints(config, LintRegistry::builtin())
+    }
+
+    /// Creates a new Duck based on a DuckConfig, running the lints in the provided registry.
+    pub fn new_with_lints(config: Config, lints: LintRegistry) -> Self {
+        Self { config, lints }
     }
 
     /// Goes through the entire process of finding, loading, parsing, and
@@ -60,14 +69,16 @@ impl Duck {
     pub async fn run(&self, project_directory: &Path) -> Result<RunSummary, tokio::task::JoinError> {
         // Load everything in and await through the early pass...
         let config_arc = Arc::new(self.config.clone()); // TODO: this clone sucks
+        let lints = Arc::new(self.lints.clone());
         let (path_receiver, walker_handle) =
             driver::start_gml_discovery(project_directory, self.config().ignored_file_paths.clone());
         let (file_receiver, file_handle) = driver::start_file_load(path_receiver);
         let (parse_receiver, parse_handle) = driver::start_parse(file_receiver);
-        let (stmt_receiever, report_sender, report_receiver, _) =
-            driver::start_early_pass(config_arc.clone(), parse_receiver);
-        let mut diagnostics =
-            driver::start_late_pass(config_arc.clone(), stmt_receiever, report_sender, report_receiver).await?;
+        let (ast_receiver, report_sender, report_receiver, early_handle) =
+            driver::start_early_pass(config_arc.clone(), lints.clone(), parse_receiver);
+        let (mut diagnostics, suppressions) =
+            driver::start_late_pass(ast_receiver, lints, report_sender, report_receiver).await?;
+        early_handle.await?;
 
         // Extract any errors that were found...
         let (line_count, library, mut io_errors) = file_handle.await?;
@@ -75,7 +86,13 @@ impl Duck {
         diagnostics.append(&mut parse_handle.await?);
 
         // Return the result!
-        Ok(RunSummary::new(library, diagnostics, io_errors, line_count))
+        Ok(RunSummary::new(
+            library,
+            diagnostics,
+            suppressions,
+            io_errors,
+            line_count,
+        ))
     }
 
     /// The blocking counterpart to [Duck::run].
@@ -100,6 +117,16 @@ impl Duck {
     pub fn config_mut(&mut self) -> &mut Config {
         &mut self.config
     }
+
+    /// Get a reference to the lints duck will run.
+    pub fn lints(&self) -> &LintRegistry {
+        &self.lints
+    }
+
+    /// Gets a mutable reference to the lints duck will run, allowing new lints to be registered.
+    pub fn lints_mut(&mut self) -> &mut LintRegistry {
+        &mut self.lints
+    }
 }
 
 /// The data returned by calling [Duck::run].
@@ -107,13 +134,15 @@ pub struct RunSummary {
     library: GmlLibrary,
     diagonstic_counts: EnumMap<LintLevel, usize>,
     diagnostics: Vec<Diagnostic<FileId>>,
+    suppressions: Vec<Suppression>,
     io_errors: Vec<std::io::Error>,
     lines_parsed: usize,
 }
 impl RunSummary {
-    fn new(
+    pub(crate) fn new(
         library: GmlLibrary,
         diagnostics: Vec<Diagnostic<FileId>>,
+        suppressions: Vec<Suppression>,
         io_errors: Vec<std::io::Error>,
         lines_parsed: usize,
     ) -> Self {
@@ -125,6 +154,7 @@ impl RunSummary {
             library,
             diagonstic_counts,
             diagnostics,
+            suppressions,
             io_errors,
             lines_parsed,
         }
@@ -145,6 +175,11 @@ impl RunSummary {
         self.diagnostics.as_ref()
     }
 
+    /// Get a reference to the diagnostics that were suppressed by `allow` tags during the run.
+    pub fn suppressions(&self) -> &[Suppression] {
+        self.suppressions.as_ref()
+    }
+
     /// Get a reference to the run result's io errors.
     pub fn io_errors(&self) -> &[std::io::Error] {
         self.io_errors.as_ref()
@@ -0,0 +1,136 @@
+use crate::{FileId, RunSummary, lint::LintLevel};
+use codespan_reporting::{
+    diagnostic::{Diagnostic, LabelStyle, Severity},
+    files::Files,
+};
+use serde_json::{Value, json};
+
+impl RunSummary {
+    /// Serializes every diagnostic found in the run into JSON, along with the diagnostics that
+    /// were suppressed by `allow` tags (and the reasons given for them).
+    pub fn to_json(&self) -> String {
+        let diagnostics: Vec<Value> = self
+            .diagnostics()
+            .iter()
+            .map(|diagnostic| self.json_diagnostic(diagnostic))
+            .collect();
+        let suppressed: Vec<Value> = self
+            .suppressions()
+            .iter()
+            .map(|suppression| {
+                let mut value = self.json_diagnostic(suppression.diagnostic());
+                value["reason"] = json!(suppression.tag().reason());
+                value["until"] = json!(suppression.tag().until());
+                value
+            })
+            .collect();
+        serde_json::to_string_pretty(&json!({
+            "diagnostics": diagnostics,
+            "suppressed": suppressed,
+            "warning_count": self.warning_count(),
+            "denial_count": self.denial_count(),
+            "lines_parsed": self.lines_parsed(),
+        }))
+        .unwrap()
+    }
+
+    /// Serializes every diagnostic found in the run into a [SARIF](https://sarifweb.azurewebsites.net/)
+    /// log. Diagnostics that were suppressed by `allow` tags are included as suppressed results,
+    /// using the tag's reason as their justification.
+    pub fn to_sarif(&self) -> String {
+        let mut results: Vec<Value> = self
+            .diagnostics()
+            .iter()
+            .map(|diagnostic| self.sarif_result(diagnostic))
+            .collect();
+        results.extend(self.suppressions().iter().map(|suppression| {
+            let mut result = self.sarif_result(suppression.diagnostic());
+            let mut sarif_suppression = json!({ "kind": "inSource" });
+            if let Some(reason) = suppression.tag().reason() {
+                sarif_suppression["justification"] = json!(reason);
+            }
+            if let Some(until) = suppression.tag().until() {
+                sarif_suppression["properties"] = json!({ "until": until });
+            }
+            result["suppressions"] = json!([sarif_suppression]);
+            result
+        }));
+        serde_json::to_string_pretty(&json!({
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "duck",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "informationUri": "https://github.com/imlazyeye/duck",
+                    }
+                },
+                "results": results,
+            }],
+        }))
+        .unwrap()
+    }
+
+    fn json_diagnostic(&self, diagnostic: &Diagnostic<FileId>) -> Value {
+        let mut value = json!({
+            "lint": diagnostic.code,
+            "level": LintLevel::from(diagnostic.severity).to_str(),
+            "message": diagnostic.message,
+            "notes": diagnostic.notes,
+        });
+        if let Some((file, start, end)) = self.primary_location(diagnostic) {
+            value["file"] = json!(file);
+            value["start"] = json!({ "line": start.line_number, "column": start.column_number });
+            value["end"] = json!({ "line": end.line_number, "column": end.column_number });
+        }
+        value
+    }
+
+    fn sarif_result(&self, diagnostic: &Diagnostic<FileId>) -> Value {
+        let mut result = json!({
+            "ruleId": diagnostic.code.as_deref().unwrap_or("parse_error"),
+            "level": match diagnostic.severity {
+                Severity::Bug | Severity::Error => "error",
+                Severity::Warning => "warning",
+                Severity::Note | Severity::Help => "note",
+            },
+            "message": { "text": diagnostic.message },
+        });
+        if let Some((file, start, end)) = self.primary_location(diagnostic) {
+            result["locations"] = json!([{
+                "physicalLocation": {
+                    "artifactLocation": { "uri": file },
+                    "region": {
+                        "startLine": start.line_number,
+                        "startColumn": start.column_number,
+                        "endLine": end.line_number,
+                        "endColumn": end.column_number,
+                    }
+                }
+            }]);
+        }
+        result
+    }
+
+    /// Returns the file name, start and end of the diagnostic's primary label, if it has one.
+    fn primary_location(
+        &self,
+        diagnostic: &Diagnostic<FileId>,
+    ) -> Option<(
+        String,
+        codespan_reporting::files::Location,
+        codespan_reporting::files::Location,
+    )> {
+        let label = diagnostic
+            .labels
+            .iter()
+            .find(|label| label.style == LabelStyle::Primary)?;
+        let files = self.files();
+        Some((
+            files.name(label.file_id).ok()?,
+            files.location(label.file_id, label.range.start).ok()?,
+            files.location(label.file_id, label.range.end).ok()?,
+        ))
+    }
+}
@@ -20,6 +20,7 @@ mod core {
     pub use crate::core::duck::*;
     mod config;
     pub use config::*;
+    mod output;
 }
 pub use crate::core::*;
 
@@ -28,6 +29,10 @@ pub mod lint {
     #[allow(clippy::module_inception)]
     mod lint;
     pub use lint::*;
+    mod registry;
+    pub use registry::*;
+    mod suppression;
+    pub use suppression::*;
 
     /// Collection of all of the various lints in duck.
     pub mod collection;
@@ -35,6 +40,8 @@ pub mod lint {
     #[cfg(test)]
     mod tests {
         mod lint_tests;
+        mod registry_tests;
+        mod tag_tests;
         mod validity_tests;
     }
 }
@@ -120,17 +127,48 @@ pub mod parse {
     mod ast;
     mod lexer;
     mod parser;
+    mod pattern;
+    mod printer;
+    mod syntax;
     mod utils;
     pub use ast::*;
     pub use gml::*;
     pub use lexer::*;
     pub use parser::*;
+    pub use pattern::*;
+    pub use printer::*;
+    pub use syntax::*;
     pub use utils::*;
 
     #[cfg(test)]
     mod tests;
 }
 
+/// Analyses of gml that are shared between lints, such as control flow graphs.
+pub mod analyze {
+    mod body;
+    mod builtins;
+    mod cfg;
+    mod eval;
+    mod metrics;
+    mod project;
+    pub use body::*;
+    pub use builtins::*;
+    pub use cfg::*;
+    pub use eval::*;
+    pub use metrics::*;
+    pub use project::*;
+
+    #[cfg(test)]
+    mod tests {
+        mod builtins_tests;
+        mod cfg_tests;
+        mod eval_tests;
+        mod metrics_tests;
+        mod project_tests;
+    }
+}
+
 /// Operations to perform static analysis on GML.
 #[allow(missing_docs)]
 #[allow(clippy::unimplemented)]
@@ -5,6 +5,8 @@ mod and_preference;
 pub use and_preference::AndPreference;
 mod anonymous_constructor;
 pub use anonymous_constructor::AnonymousConstructor;
+mod banned_call;
+pub use banned_call::BannedCall;
 mod bool_equality;
 pub use bool_equality::BoolEquality;
 mod casing_rules;
@@ -13,24 +15,42 @@ mod collapsable_if;
 pub use collapsable_if::CollapsableIf;
 mod condition_wrapper;
 pub use condition_wrapper::ConditionWrapper;
+mod constant_condition;
+pub use constant_condition::ConstantCondition;
+mod constructor_misuse;
+pub use constructor_misuse::ConstructorMisuse;
 mod deprecated;
 pub use deprecated::Deprecated;
 mod draw_sprite;
 pub use draw_sprite::DrawSprite;
 mod draw_text;
 pub use draw_text::DrawText;
+mod duplicate_case;
+pub use duplicate_case::DuplicateCase;
+mod duplicate_declaration;
+pub use duplicate_declaration::DuplicateDeclaration;
 mod english_flavor_violation;
 pub use english_flavor_violation::EnglishFlavorViolation;
 mod exit;
 pub use exit::Exit;
 mod global;
 pub use global::Global;
+mod inconsistent_return;
+pub use inconsistent_return::InconsistentReturn;
+mod invalid_allow;
+pub use invalid_allow::InvalidAllow;
 mod invalid_assignment;
 pub use invalid_assignment::InvalidAssignment;
 mod invalid_comparison;
 pub use invalid_comparison::InvalidComparison;
+mod invalid_enum_member;
+pub use invalid_enum_member::InvalidEnumMember;
 mod invalid_equality;
 pub use invalid_equality::InvalidEquality;
+mod line_too_long;
+pub use line_too_long::LineTooLong;
+mod magic_number;
+pub use magic_number::MagicNumber;
 mod missing_case_member;
 pub use missing_case_member::MissingCaseMember;
 mod missing_default_case;
@@ -47,8 +67,12 @@ mod not_preference;
 pub use not_preference::NotPreference;
 mod or_preference;
 pub use or_preference::OrPreference;
+mod pattern_violation;
+pub use pattern_violation::PatternViolation;
 mod room_goto;
 pub use room_goto::RoomGoto;
+mod shadowing;
+pub use shadowing::Shadowing;
 mod show_debug_message;
 pub use show_debug_message::ShowDebugMessage;
 mod single_equals_comparison;
@@ -57,20 +81,48 @@ mod single_switch_case;
 pub use single_switch_case::SingleSwitchCase;
 mod suspicious_constant_usage;
 pub use suspicious_constant_usage::SuspicousConstantUsage;
+mod switch_fallthrough;
+pub use switch_fallthrough::SwitchFallthrough;
 mod switch_without_case;
 pub use switch_without_case::SwitchWithoutCase;
 mod todo;
 pub use todo::Todo;
+mod too_complex;
+pub use too_complex::TooComplex;
+mod too_deeply_nested;
+pub use too_deeply_nested::TooDeeplyNested;
 mod too_many_arguments;
 pub use too_many_arguments::TooManyArguments;
+mod too_many_statements;
+pub use too_many_statements::TooManyStatements;
 mod try_catch;
 pub use try_catch::TryCatch;
 mod unassigned_constructor;
 pub use unassigned_constructor::UnassignedConstructor;
+mod uninitialized_local_variable;
+pub use uninitialized_local_variable::UninitializedLocalVariable;
+mod unknown_function;
+pub use unknown_function::UnknownFunction;
+mod unknown_lint;
+pub use unknown_lint::UnknownLint;
 mod unnecessary_grouping;
 pub use unnecessary_grouping::UnnecessaryGrouping;
+mod unreachable_code;
+pub use unreachable_code::UnreachableCode;
+mod unused_allow;
+pub use unused_allow::UnusedAllow;
+mod unused_enum;
+pub use unused_enum::UnusedEnum;
+mod unused_enum_member;
+pub use unused_enum_member::UnusedEnumMember;
+mod unused_function;
+pub use unused_function::UnusedFunction;
+mod unused_globalvar;
+pub use unused_globalvar::UnusedGlobalvar;
 mod unused_local_variable;
 pub use unused_local_variable::UnusedLocalVariable;
+mod unused_macro;
+pub use unused_macro::UnusedMacro;
 mod unused_parameter;
 pub use unused_parameter::UnusedParameter;
 mod useless_function;
@@ -79,3 +131,81 @@ mod var_prefix_violation;
 pub use var_prefix_violation::VarPrefixViolation;
 mod with_loop;
 pub use with_loop::WithLoop;
+mod wrong_argument_count;
+pub use wrong_argument_count::WrongArgumentCount;
+mod wrong_argument_type;
+pub use wrong_argument_type::WrongArgumentType;
+
+use super::LintRegistry;
+
+/// Registers every lint built into duck.
+pub(super) fn register_builtin_lints(registry: &mut LintRegistry) {
+    registry.register(AccessorAlternative);
+    registry.register(AndPreference);
+    registry.register(AnonymousConstructor);
+    registry.register(BannedCall);
+    registry.register(BoolEquality);
+    registry.register(CasingRules);
+    registry.register(CollapsableIf);
+    registry.register(ConditionWrapper);
+    registry.register(ConstantCondition);
+    registry.register(ConstructorMisuse);
+    registry.register(Deprecated);
+    registry.register(DrawSprite);
+    registry.register(DrawText);
+    registry.register(DuplicateCase);
+    registry.register(DuplicateDeclaration);
+    registry.register(EnglishFlavorViolation);
+    registry.register(Exit);
+    registry.register(Global);
+    registry.register(InconsistentReturn);
+    registry.register(InvalidAllow);
+    registry.register(InvalidAssignment);
+    registry.register(InvalidComparison);
+    registry.register(InvalidEnumMember);
+    registry.register(InvalidEquality);
+    registry.register(LineTooLong);
+    registry.register(MagicNumber);
+    registry.register(MissingCaseMember);
+    registry.register(MissingDefaultCase);
+    registry.register(ModPreference);
+    registry.register(MultiVarDeclaration);
+    registry.register(NonConstantDefaultParameter);
+    registry.register(NonSimplifiedExpression);
+    registry.register(NotPreference);
+    registry.register(OrPreference);
+    registry.register(PatternViolation);
+    registry.register(RoomGoto);
+    registry.register(Shadowing);
+    registry.register(ShowDebugMessage);
+    registry.register(SingleEqualsComparison);
+    registry.register(SingleSwitchCase);
+    registry.register(SuspicousConstantUsage);
+    registry.register(SwitchFallthrough);
+    registry.register(SwitchWithoutCase);
+    registry.register(Todo);
+    registry.register(TooComplex);
+    registry.register(TooDeeplyNested);
+    registry.register(TooManyArguments);
+    registry.register(TooManyStatements);
+    registry.register(TryCatch);
+    registry.register(UnassignedConstructor);
+    registry.register(UninitializedLocalVariable);
+    registry.register(UnknownFunction);
+    registry.register(UnknownLint);
+    registry.register(UnnecessaryGrouping);
+    registry.register(UnreachableCode);
+    registry.register(UnusedAllow);
+    registry.register(UnusedEnum);
+    registry.register(UnusedEnumMember);
+    registry.register(UnusedFunction);
+    registry.register(UnusedGlobalvar);
+    registry.register(UnusedLocalVariable);
+    registry.register(UnusedMacro);
+    registry.register(UnusedParameter);
+    registry.register(UselessFunction);
+    registry.register(VarPrefixViolation);
+    registry.register(WithLoop);
+    registry.register(WrongArgumentCount);
+    registry.register(WrongArgumentType);
+}
@@ -2,51 +2,53 @@ use codespan_reporting::diagnostic::{Diagnostic, Label};
 
 use crate::{
     Config, FileId,
-    lint::{EarlyExprPass, Lint, LintLevel},
+    lint::{Lint, LintLevel},
     parse::{Call, Expr, ExprKind, Literal},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Default)]
 pub struct AccessorAlternative;
 impl Lint for AccessorAlternative {
-    fn explanation() -> &'static str {
+    fn explanation(&self) -> &'static str {
         "GML offers accessors as an alternative to many common functions which are preferable for their readability and brevity."
     }
 
-    fn default_level() -> LintLevel {
+    fn default_level(&self) -> LintLevel {
         LintLevel::Warn
     }
 
-    fn tag() -> &'static str {
+    fn tag(&self) -> &'static str {
         "accessor_alternative"
     }
-}
 
-impl EarlyExprPass for AccessorAlternative {
-    fn visit_expr_early(expr: &Expr, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+    fn visit_expr_early(&self, expr: &Expr, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
         if let ExprKind::Call(Call { left, arguments, .. }) = expr.kind() {
             if let ExprKind::Identifier(identifier) = left.kind() {
                 reports.push(match identifier.lexeme.as_ref() {
-                    "ds_list_find_value" => Self::diagnostic(config)
+                    "ds_list_find_value" => self
+                        .diagnostic(config)
                         .with_message("Use of `ds_list_find_value`")
                         .with_labels(vec![
                             Label::primary(expr.file_id(), expr.span())
                                 .with_message("this can be replaced with an accessor syntax (`list[| index]`"),
                         ]),
 
-                    "ds_grid_get" => Self::diagnostic(config)
+                    "ds_grid_get" => self
+                        .diagnostic(config)
                         .with_message("Use of `ds_grid_get`")
                         .with_labels(vec![
                             Label::primary(expr.file_id(), expr.span())
                                 .with_message("this can be replaced with an accessor syntax (`grid[# x, y]`"),
                         ]),
-                    "ds_map_find_value" => Self::diagnostic(config)
+                    "ds_map_find_value" => self
+                        .diagnostic(config)
                         .with_message("Use of `ds_map_find_value`")
                         .with_labels(vec![
                             Label::primary(expr.file_id(), expr.span())
                                 .with_message("this can be replaced with an accessor syntax (`map[? key]`"),
                         ]),
-                    "array_get" => Self::diagnostic(config)
+                    "array_get" => self
+                        .diagnostic(config)
                         .with_message("Use of `array_get`")
                         .with_labels(vec![
                             Label::primary(expr.file_id(), expr.span())
@@ -61,7 +63,7 @@ impl EarlyExprPass for AccessorAlternative {
                                     ExprKind::Literal(Literal::String(string))
                                         if string.chars().all(|v| v.is_alphanumeric() || v == '_') =>
                                     {
-                                        Self::diagnostic(config)
+                                        self.diagnostic(config)
                                             .with_message("Use of `variable_struct_get`")
                                             .with_labels(vec![
                                                 Label::primary(expr.file_id(), expr.span()).with_message(
@@ -71,7 +73,8 @@ impl EarlyExprPass for AccessorAlternative {
                                     }
 
                                     // If not, they could still use the $ accessor
-                                    _ => Self::diagnostic(config)
+                                    _ => self
+                                        .diagnostic(config)
                                         .with_message("Use of `variable_struct_get`")
                                         .with_labels(vec![Label::primary(expr.file_id(), expr.span()).with_message(
                                             "this can be replaced with an accessor syntax (`struct[$ key]`)",
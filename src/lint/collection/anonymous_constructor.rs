@@ -2,28 +2,26 @@ use codespan_reporting::diagnostic::{Diagnostic, Label};
 
 use crate::{
     Config, FileId,
-    lint::{EarlyExprPass, Lint, LintLevel},
+    lint::{Lint, LintLevel},
     parse::{Expr, ExprKind, Function},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Default)]
 pub struct AnonymousConstructor;
 impl Lint for AnonymousConstructor {
-    fn explanation() -> &'static str {
+    fn explanation(&self) -> &'static str {
         "Constructors should be reserved for larger, higher scoped types."
     }
 
-    fn default_level() -> LintLevel {
+    fn default_level(&self) -> LintLevel {
         LintLevel::Allow
     }
 
-    fn tag() -> &'static str {
+    fn tag(&self) -> &'static str {
         "anonymous_constructor"
     }
-}
 
-impl EarlyExprPass for AnonymousConstructor {
-    fn visit_expr_early(expr: &Expr, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+    fn visit_expr_early(&self, expr: &Expr, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
         if let ExprKind::Function(Function {
             name: None,
             constructor: Some(_),
@@ -31,7 +29,7 @@ impl EarlyExprPass for AnonymousConstructor {
         }) = expr.kind()
         {
             reports.push(
-                Self::diagnostic(config)
+                self.diagnostic(config)
                     .with_message("Use of an anonymous constructor")
                     .with_labels(vec![
                         Label::primary(expr.file_id(), expr.span())
@@ -0,0 +1,63 @@
+use codespan_reporting::diagnostic::{Diagnostic, Label, Severity};
+
+use crate::{
+    Config, FileId,
+    lint::{Lint, LintLevel},
+    parse::{Call, Expr, ExprKind},
+};
+
+#[derive(Debug, PartialEq, Default)]
+pub struct BannedCall;
+impl Lint for BannedCall {
+    fn explanation(&self) -> &'static str {
+        "Studios often have their own frameworks that should be used instead of certain built-in functions. The `banned_calls` rules in your config describe which functions should not be called, and where they are allowed."
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warn
+    }
+
+    fn tag(&self) -> &'static str {
+        "banned_call"
+    }
+
+    fn visit_expr_early(&self, expr: &Expr, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+        let ExprKind::Call(Call { left, .. }) = expr.kind() else {
+            return;
+        };
+        let Some(identifier) = left.kind().as_identifier() else {
+            return;
+        };
+        // The most specific rule wins, and the first of them if several are equally specific
+        let Some(rule) = config
+            .banned_calls
+            .iter()
+            .rev()
+            .filter_map(|rule| Some((rule.specificity(&identifier.lexeme)?, rule)))
+            .max_by_key(|(specificity, _)| *specificity)
+            .map(|(_, rule)| rule)
+        else {
+            return;
+        };
+        let mut diagnostic = self.diagnostic(config);
+        match rule.level {
+            Some(LintLevel::Allow) => return,
+            Some(LintLevel::Warn) => diagnostic.severity = Severity::Warning,
+            Some(LintLevel::Deny) => diagnostic.severity = Severity::Error,
+            None => {}
+        }
+        let message = match &rule.message {
+            Some(message) => format!("Use of `{}`: {message}", identifier.lexeme),
+            None => format!("Use of banned function `{}`", identifier.lexeme),
+        };
+        let label = match &rule.replacement {
+            Some(replacement) => format!("use `{replacement}` instead"),
+            None => "this function is banned by your config".into(),
+        };
+        reports.push(
+            diagnostic
+                .with_message(message)
+                .with_labels(vec![Label::primary(left.file_id(), left.span()).with_message(label)]),
+        );
+    }
+}
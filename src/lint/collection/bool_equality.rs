@@ -2,28 +2,26 @@ use codespan_reporting::diagnostic::{Diagnostic, Label};
 
 use crate::{
     Config, FileId,
-    lint::{EarlyExprPass, Lint, LintLevel},
+    lint::{Lint, LintLevel},
     parse::{Equality, EqualityOp, Expr, ExprKind, Literal},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Default)]
 pub struct BoolEquality;
 impl Lint for BoolEquality {
-    fn explanation() -> &'static str {
+    fn explanation(&self) -> &'static str {
         "Comparing a bool with a bool literal is more verbose than neccesary."
     }
 
-    fn default_level() -> LintLevel {
+    fn default_level(&self) -> LintLevel {
         LintLevel::Allow
     }
 
-    fn tag() -> &'static str {
+    fn tag(&self) -> &'static str {
         "bool_equality"
     }
-}
 
-impl EarlyExprPass for BoolEquality {
-    fn visit_expr_early(expr: &Expr, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+    fn visit_expr_early(&self, expr: &Expr, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
         if let ExprKind::Equality(Equality {
             left,
             op: EqualityOp::Equal(token),
@@ -32,12 +30,14 @@ impl EarlyExprPass for BoolEquality {
         {
             if let Some(literal) = right.kind().as_literal() {
                 reports.push(match literal {
-                    Literal::True => Self::diagnostic(config)
+                    Literal::True => self
+                        .diagnostic(config)
                         .with_message("Equality check with `true`")
                         .with_labels(vec![
                             Label::primary(right.file_id(), right.span()).with_message("this can be omitted"),
                         ]),
-                    Literal::False => Self::diagnostic(config)
+                    Literal::False => self
+                        .diagnostic(config)
                         .with_message("Equality check with `false`")
                         .with_labels(vec![
                             Label::primary(right.file_id(), token.span.start()..right.span().end())
@@ -2,28 +2,26 @@ use codespan_reporting::diagnostic::{Diagnostic, Label};
 
 use crate::{
     Config, FileId,
-    lint::{EarlyStmtPass, Lint, LintLevel},
+    lint::{Lint, LintLevel},
     parse::{If, Stmt, StmtKind},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Default)]
 pub struct CollapsableIf;
 impl Lint for CollapsableIf {
-    fn explanation() -> &'static str {
+    fn explanation(&self) -> &'static str {
         "If statements that contain nothing more than another if statement can be collapsed into a single statement."
     }
 
-    fn default_level() -> LintLevel {
+    fn default_level(&self) -> LintLevel {
         LintLevel::Warn
     }
 
-    fn tag() -> &'static str {
+    fn tag(&self) -> &'static str {
         "collapsable_if"
     }
-}
 
-impl EarlyStmtPass for CollapsableIf {
-    fn visit_stmt_early(stmt: &Stmt, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+    fn visit_stmt_early(&self, stmt: &Stmt, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
         if let StmtKind::If(If {
             body: first_body,
             else_stmt,
@@ -44,7 +42,8 @@ fn inner(stmt: &Stmt, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
         let nested_stmt = block.body.first().unwrap();
         if let StmtKind::If(If { else_stmt: None, .. }) = nested_stmt.kind() {
             reports.push(
-                CollapsableIf::diagnostic(config)
+                CollapsableIf
+                    .diagnostic(config)
                     .with_message("Collapsable if statement")
                     .with_labels(vec![
                         Label::secondary(nested_stmt.file_id(), nested_stmt.span()).with_message("nested if statement"),
@@ -2,33 +2,51 @@ use codespan_reporting::diagnostic::{Diagnostic, Label};
 
 use crate::{
     Config, FileId,
-    lint::{EarlyExprPass, EarlyStmtPass, Lint, LintLevel},
+    lint::{Lint, LintLevel},
     parse::{DoUntil, Expr, ExprKind, If, Repeat, Stmt, StmtKind, Switch, Ternary, While, With},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Default)]
 pub struct ConditionWrapper;
 impl Lint for ConditionWrapper {
-    fn explanation() -> &'static str {
+    fn explanation(&self) -> &'static str {
         "Parenthesis surrounding certain statement expressions are optional in GML, resulting in differing opinions on whether or not to use them. You can select either option via the config."
     }
 
-    fn default_level() -> LintLevel {
+    fn default_level(&self) -> LintLevel {
         LintLevel::Allow
     }
 
-    fn tag() -> &'static str {
+    fn tag(&self) -> &'static str {
         "condition_wrapper"
     }
+
+    fn visit_expr_early(&self, expr: &Expr, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+        if let ExprKind::Ternary(Ternary { condition, .. }) = expr.kind() {
+            self.test(condition, config, reports)
+        }
+    }
+
+    fn visit_stmt_early(&self, stmt: &Stmt, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+        match stmt.kind() {
+            StmtKind::Switch(Switch { identity: expr, .. })
+            | StmtKind::If(If { condition: expr, .. })
+            | StmtKind::DoUntil(DoUntil { condition: expr, .. })
+            | StmtKind::While(While { condition: expr, .. })
+            | StmtKind::With(With { identity: expr, .. })
+            | StmtKind::Repeat(Repeat { tick_counts: expr, .. }) => self.test(expr, config, reports),
+            _ => {}
+        };
+    }
 }
 
 impl ConditionWrapper {
-    pub fn test(expr: &Expr, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+    pub fn test(&self, expr: &Expr, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
         if let Some(grouping) = expr.kind().as_grouping() {
             let (left_token, right_token) = grouping.parenthesis();
             if !config.statement_parentheticals {
                 reports.push(
-                    Self::diagnostic(config)
+                    self.diagnostic(config)
                         .with_message("Unneccesary grouping around condition")
                         .with_labels(vec![
                             Label::primary(expr.file_id(), left_token.span),
@@ -38,7 +56,7 @@ impl ConditionWrapper {
             }
         } else if config.statement_parentheticals {
             reports.push(
-                Self::diagnostic(config)
+                self.diagnostic(config)
                     .with_message("Missing outer grouping in statement")
                     .with_labels(vec![
                         Label::primary(expr.file_id(), expr.span()).with_message("wrap this condition in parenthesis"),
@@ -47,25 +65,3 @@ impl ConditionWrapper {
         }
     }
 }
-
-impl EarlyExprPass for ConditionWrapper {
-    fn visit_expr_early(expr: &Expr, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
-        if let ExprKind::Ternary(Ternary { condition, .. }) = expr.kind() {
-            Self::test(condition, config, reports)
-        }
-    }
-}
-
-impl EarlyStmtPass for ConditionWrapper {
-    fn visit_stmt_early(stmt: &Stmt, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
-        match stmt.kind() {
-            StmtKind::Switch(Switch { identity: expr, .. })
-            | StmtKind::If(If { condition: expr, .. })
-            | StmtKind::DoUntil(DoUntil { condition: expr, .. })
-            | StmtKind::While(While { condition: expr, .. })
-            | StmtKind::With(With { identity: expr, .. })
-            | StmtKind::Repeat(Repeat { tick_counts: expr, .. }) => Self::test(expr, config, reports),
-            _ => {}
-        };
-    }
-}
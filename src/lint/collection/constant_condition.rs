@@ -0,0 +1,162 @@
+use codespan_reporting::diagnostic::{Diagnostic, Label};
+
+use crate::{
+    Config, FileId,
+    analyze::{ControlFlowGraph, Evaluator},
+    lint::{Lint, LintLevel, collection::UnreachableCode},
+    parse::*,
+};
+
+#[derive(Debug, PartialEq, Default)]
+pub struct ConstantCondition;
+impl Lint for ConstantCondition {
+    fn explanation(&self) -> &'static str {
+        "A condition that is always true or always false (such as `if (false)`, `x == x` or `a || true`) makes one of its paths dead code, and is usually left over from debugging or a typo. Loops that are always meant to continue (ie: `while (true)`) are fine as long as something inside them leaves the loop; if nothing does, the loop will never end. Macros are not resolved, as conditions like `if (DEBUG)` are expected to change between configurations. When `unreachable_code` is enabled, it reports the branches that never run instead."
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warn
+    }
+
+    fn tag(&self) -> &'static str {
+        "constant_condition"
+    }
+
+    fn visit_stmt_early(&self, stmt: &Stmt, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+        match stmt.kind() {
+            StmtKind::If(If {
+                condition, else_stmt, ..
+            }) => {
+                if let Some(value) = Self::value(condition) {
+                    // Leaves the branch that never runs to `unreachable_code`
+                    if (!value || else_stmt.is_some()) && Self::reported_as_unreachable(condition, config) {
+                        return;
+                    }
+                    let consequence = if value {
+                        "the body always runs"
+                    } else {
+                        "the body never runs"
+                    };
+                    self.report(condition, value, consequence, config, reports);
+                }
+            }
+            StmtKind::While(While { condition, .. }) | StmtKind::For(For { condition, .. }) => {
+                match Self::value(condition) {
+                    Some(true) => self.check_exit(stmt, condition, config, reports),
+                    Some(false) if !Self::reported_as_unreachable(condition, config) => {
+                        self.report(condition, false, "the loop never runs", config, reports)
+                    }
+                    _ => {}
+                }
+            }
+            StmtKind::DoUntil(DoUntil { condition, .. }) => match Self::value(condition) {
+                Some(true) => self.report(condition, true, "the loop only ever runs once", config, reports),
+                Some(false) => self.check_exit(stmt, condition, config, reports),
+                None => {}
+            },
+            _ => {}
+        }
+    }
+
+    fn visit_expr_early(&self, expr: &Expr, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+        if let ExprKind::Ternary(Ternary { condition, .. }) = expr.kind() {
+            if let Some(value) = Self::value(condition) {
+                let consequence = if value {
+                    "the false value is never used"
+                } else {
+                    "the true value is never used"
+                };
+                self.report(condition, value, consequence, config, reports);
+            }
+        }
+    }
+}
+
+impl ConstantCondition {
+    /// Returns the value the condition always has, if it is constant or compares something with
+    /// itself.
+    fn value(condition: &Expr) -> Option<bool> {
+        if let Some(value) = Evaluator::new().evaluate(condition).and_then(|value| value.as_bool()) {
+            return Some(value);
+        }
+        let mut condition = condition;
+        while let Some(grouping) = condition.kind().as_grouping() {
+            condition = &grouping.inner;
+        }
+        let Equality { left, op, right } = condition.kind().as_equality()?;
+        if !Self::is_pure(left) || !Self::is_pure(right) {
+            return None;
+        }
+        let printer = Printer::default();
+        if printer.expr(left) != printer.expr(right) {
+            return None;
+        }
+        Some(matches!(
+            op,
+            EqualityOp::Equal(_) | EqualityOp::GreaterThanOrEqual(_) | EqualityOp::LessThanOrEqual(_)
+        ))
+    }
+
+    /// Returns whether the branch a constant condition rules out is reported by [UnreachableCode]
+    /// instead, which is the case when it is enabled and can evaluate the condition itself.
+    fn reported_as_unreachable(condition: &Expr, config: &Config) -> bool {
+        *config.get_lint_level_setting(UnreachableCode.tag(), UnreachableCode.default_level()) != LintLevel::Allow
+            && Evaluator::new()
+                .evaluate(condition)
+                .is_some_and(|value| value.as_bool().is_some())
+    }
+
+    /// Returns whether evaluating the expression twice is sure to produce the same value, as it
+    /// makes no calls and changes nothing.
+    fn is_pure(expr: &Expr) -> bool {
+        match expr.kind() {
+            ExprKind::Call(_) | ExprKind::Postfix(_) | ExprKind::Function(_) => return false,
+            ExprKind::Unary(Unary {
+                op: UnaryOp::Increment(_) | UnaryOp::Decrement(_),
+                ..
+            }) => return false,
+            _ => {}
+        }
+        let mut pure = true;
+        expr.visit_child_exprs(|expr| pure &= Self::is_pure(expr));
+        pure
+    }
+
+    /// Reports a loop whose condition always continues it, if nothing within it can leave.
+    fn check_exit(&self, stmt: &Stmt, condition: &Expr, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+        let cfg = ControlFlowGraph::new(std::slice::from_ref(stmt));
+        if cfg.is_reachable(cfg.exit()) {
+            return;
+        }
+        reports.push(
+            self.diagnostic(config)
+                .with_message("Infinite loop")
+                .with_labels(vec![
+                    Label::primary(condition.file_id(), condition.span())
+                        .with_message("this condition never changes, and nothing in the loop leaves it"),
+                    Label::secondary(stmt.file_id(), stmt.span()).with_message("this loop will never end"),
+                ])
+                .with_notes(vec![
+                    "add a `break`, `return` or `exit`, or change the condition".into(),
+                ]),
+        );
+    }
+
+    fn report(
+        &self,
+        condition: &Expr,
+        value: bool,
+        consequence: &str,
+        config: &Config,
+        reports: &mut Vec<Diagnostic<FileId>>,
+    ) {
+        reports.push(
+            self.diagnostic(config)
+                .with_message(format!("Condition is always {value}"))
+                .with_labels(vec![
+                    Label::primary(condition.file_id(), condition.span())
+                        .with_message(format!("this is always {value}, so {consequence}")),
+                ]),
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use codespan_reporting::diagnostic::{Diagnostic, Label};
+
+use crate::{
+    Config, FileId,
+    analyze::{Body, DeclarationKind, GML_FUNCTIONS, Project},
+    lint::{Lint, LintLevel},
+    parse::{Call, Expr, ExprKind, Field, Location, ParseVisitor, Stmt},
+};
+
+#[derive(Debug, PartialEq, Default)]
+pub struct ConstructorMisuse;
+impl Lint for ConstructorMisuse {
+    fn explanation(&self) -> &'static str {
+        "Calling a constructor without `new` runs it against whatever instance or struct is calling it, and using `new` on a function that is not a constructor does not create the struct it appears to. A constructor that inherits from a constructor that doesn't exist will crash as soon as it is created."
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Deny
+    }
+
+    fn tag(&self) -> &'static str {
+        "constructor_misuse"
+    }
+
+    fn visit_body_late(&self, body: &Body, project: &Project, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+        // Calls are found by walking the body rather than in `visit_expr_late`, as a constructor's
+        // inheritance (ie: `: Parent()`) is a call without `new` that must not be reported
+        if let Some(function) = body.as_function() {
+            for parameter in function.parameters.iter() {
+                if let Field::Initialized(stmt) = parameter {
+                    self.visit_stmt(stmt, body, project, config, reports);
+                }
+            }
+        }
+        for stmt in body.stmts() {
+            self.visit_stmt(stmt, body, project, config, reports);
+        }
+    }
+}
+
+impl ConstructorMisuse {
+    fn visit_stmt(
+        &self,
+        stmt: &Stmt,
+        body: &Body,
+        project: &Project,
+        config: &Config,
+        reports: &mut Vec<Diagnostic<FileId>>,
+    ) {
+        stmt.visit_child_stmts(|stmt| self.visit_stmt(stmt, body, project, config, reports));
+        stmt.visit_child_exprs(|expr| self.visit_expr(expr, body, project, config, reports));
+    }
+
+    /// Checks every call in the expression, without entering nested functions (which are visited
+    /// as bodies of their own).
+    fn visit_expr(
+        &self,
+        expr: &Expr,
+        body: &Body,
+        project: &Project,
+        config: &Config,
+        reports: &mut Vec<Diagnostic<FileId>>,
+    ) {
+        match expr.kind() {
+            ExprKind::Function(_) => return,
+            ExprKind::Call(call) => self.check_call(expr, call, body, project, config, reports),
+            _ => {}
+        }
+        expr.visit_child_stmts(|stmt| self.visit_stmt(stmt, body, project, config, reports));
+        expr.visit_child_exprs(|expr| self.visit_expr(expr, body, project, config, reports));
+    }
+
+    fn check_call(
+        &self,
+        expr: &Expr,
+        call: &Call,
+        body: &Body,
+        project: &Project,
+        config: &Config,
+        reports: &mut Vec<Diagnostic<FileId>>,
+    ) {
+        // A parameter or local variable hides any constructor of the same name
+        let Some(callee) = call
+            .left
+            .kind()
+            .as_identifier()
+            .filter(|callee| !body.is_local(&callee.lexeme))
+        else {
+            return;
+        };
+        let declarations = project.declarations(&callee.lexeme);
+        if declarations.is_empty() {
+            if call.uses_new && GML_FUNCTIONS.contains_key(&callee.lexeme) {
+                reports.push(
+                    self.diagnostic(config)
+                        .with_message(format!("Use of `new` on `{callee}`"))
+                        .with_labels(vec![
+                            Label::primary(expr.file_id(), expr.span())
+                                .with_message(format!("`{callee}` is a built-in function, not a constructor")),
+                        ]),
+                );
+            }
+            return;
+        }
+        // If the function is declared more than once, we can't know which one is being called
+        let [declaration] = declarations else {
+            return;
+        };
+        let Location(file_id, span) = declaration.location();
+        match (declaration.kind(), call.uses_new) {
+            (DeclarationKind::Constructor, false) => reports.push(
+                self.diagnostic(config)
+                    .with_message(format!("Constructor `{callee}` called without `new`"))
+                    .with_labels(vec![
+                        Label::primary(expr.file_id(), expr.span())
+                            .with_message(format!("add `new` to create a `{callee}`")),
+                        Label::secondary(file_id, span)
+                            .with_message(format!("`{callee}` is declared as a constructor here")),
+                    ]),
+            ),
+            (DeclarationKind::Function, true) => reports.push(
+                self.diagnostic(config)
+                    .with_message(format!("Use of `new` on `{callee}`"))
+                    .with_labels(vec![
+                        Label::primary(expr.file_id(), expr.span())
+                            .with_message(format!("`{callee}` is not a constructor")),
+                        Label::secondary(file_id, span).with_message(format!("`{callee}` is declared here")),
+                    ]),
+            ),
+            (DeclarationKind::Constructor, true) => {
+                let Some((parent, Location(parent_file_id, parent_span))) = declaration.parent() else {
+                    return;
+                };
+                let parent_label = match project.declarations(parent) {
+                    [] => format!("`{parent}` is not declared anywhere in the project"),
+                    declarations
+                        if !declarations
+                            .iter()
+                            .any(|declaration| declaration.kind() == DeclarationKind::Constructor) =>
+                    {
+                        format!("`{parent}` is not a constructor")
+                    }
+                    _ => return,
+                };
+                reports.push(
+                    self.diagnostic(config)
+                        .with_message(format!("`{callee}` inherits from an invalid constructor"))
+                        .with_labels(vec![
+                            Label::primary(expr.file_id(), expr.span())
+                                .with_message(format!("creating a `{callee}` will fail here")),
+                            Label::secondary(parent_file_id, parent_span).with_message(parent_label),
+                        ]),
+                );
+            }
+            _ => {}
+        }
+    }
+}
@@ -2,31 +2,30 @@ use codespan_reporting::diagnostic::{Diagnostic, Label};
 
 use crate::{
     FileId,
-    lint::{EarlyExprPass, EarlyStmtPass, Lint, LintLevel},
+    analyze::GML_FUNCTIONS,
+    lint::{Lint, LintLevel},
     parse::{Access, Call, Expr, ExprKind, Globalvar, Stmt, StmtKind},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Default)]
 pub struct Deprecated;
 impl Lint for Deprecated {
-    fn explanation() -> &'static str {
+    fn explanation(&self) -> &'static str {
         "Deprecated features are liable to be removed at any time and should be avoided."
     }
 
-    fn default_level() -> LintLevel {
+    fn default_level(&self) -> LintLevel {
         LintLevel::Warn
     }
 
-    fn tag() -> &'static str {
+    fn tag(&self) -> &'static str {
         "deprecated"
     }
-}
 
-impl EarlyStmtPass for Deprecated {
-    fn visit_stmt_early(stmt: &Stmt, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
+    fn visit_stmt_early(&self, stmt: &Stmt, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
         if let StmtKind::Globalvar(Globalvar { name }) = stmt.kind() {
             reports.push(
-                Self::diagnostic(config)
+                self.diagnostic(config)
                     .with_message("Use of `globalvar`")
                     .with_labels(vec![
                         Label::primary(stmt.file_id(), stmt.span())
@@ -35,15 +34,16 @@ impl EarlyStmtPass for Deprecated {
             );
         }
     }
-}
 
-impl EarlyExprPass for Deprecated {
-    fn visit_expr_early(expr: &Expr, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
+    fn visit_expr_early(&self, expr: &Expr, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
         if let ExprKind::Call(Call { left, .. }) = expr.kind() {
             if let ExprKind::Identifier(identifier) = left.kind() {
-                if gm_deprecated_functions().contains(&identifier.lexeme.as_str()) {
+                if GML_FUNCTIONS
+                    .get(&identifier.lexeme)
+                    .is_some_and(|function| function.is_deprecated())
+                {
                     reports.push(
-                        Self::diagnostic(config)
+                        self.diagnostic(config)
                             .with_message(format!("Use of deprecated function: {}", identifier.lexeme))
                             .with_labels(vec![
                                 Label::primary(left.file_id(), left.span()).with_message("this function is deprecated"),
@@ -53,7 +53,7 @@ impl EarlyExprPass for Deprecated {
             }
         } else if let ExprKind::Access(Access::Array { index_two: Some(_), .. }) = expr.kind() {
             reports.push(
-                Self::diagnostic(config)
+                self.diagnostic(config)
                     .with_message("Use of 2d array")
                     .with_labels(vec![
                         Label::primary(expr.file_id(), expr.span())
@@ -63,16 +63,3 @@ impl EarlyExprPass for Deprecated {
         }
     }
 }
-
-fn gm_deprecated_functions() -> &'static [&'static str] {
-    &[
-        "array_height_2d",
-        "array_length_2d",
-        "array_length_2d",
-        "buffer_surface_copy",
-        "variable_struct_get",
-        "variable_struct_set",
-        "variable_struct_get_names",
-        "variable_struct_names_count",
-    ]
-}
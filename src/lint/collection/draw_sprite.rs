@@ -2,33 +2,31 @@ use codespan_reporting::diagnostic::{Diagnostic, Label};
 
 use crate::{
     FileId,
-    lint::{EarlyExprPass, Lint, LintLevel},
+    lint::{Lint, LintLevel},
     parse::{Call, Expr, ExprKind},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Default)]
 pub struct DrawSprite;
 impl Lint for DrawSprite {
-    fn explanation() -> &'static str {
+    fn explanation(&self) -> &'static str {
         "Projects that implement their own rendering backend may wish to be restrictive around when and where the `draw_sprite` functions are called."
     }
 
-    fn default_level() -> LintLevel {
+    fn default_level(&self) -> LintLevel {
         LintLevel::Allow
     }
 
-    fn tag() -> &'static str {
+    fn tag(&self) -> &'static str {
         "draw_sprite"
     }
-}
 
-impl EarlyExprPass for DrawSprite {
-    fn visit_expr_early(expr: &Expr, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
+    fn visit_expr_early(&self, expr: &Expr, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
         if let ExprKind::Call(Call { left, .. }) = expr.kind() {
             if let ExprKind::Identifier(identifier) = left.kind() {
                 if gm_draw_sprite_functions().contains(&identifier.lexeme.as_str()) {
                     reports.push(
-                        Self::diagnostic(config)
+                        self.diagnostic(config)
                             .with_message(format!("Use of `{}`", identifier.lexeme))
                             .with_labels(vec![
                                 Label::primary(left.file_id(), left.span())
@@ -2,33 +2,31 @@ use codespan_reporting::diagnostic::{Diagnostic, Label};
 
 use crate::{
     Config, FileId,
-    lint::{EarlyExprPass, Lint, LintLevel},
+    lint::{Lint, LintLevel},
     parse::{Call, Expr, ExprKind},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Default)]
 pub struct DrawText;
 impl Lint for DrawText {
-    fn explanation() -> &'static str {
+    fn explanation(&self) -> &'static str {
         "Projects that implement their own UI frameworks / localization may wish to be restrictive around when and where the `draw_text` functions are called."
     }
 
-    fn default_level() -> LintLevel {
+    fn default_level(&self) -> LintLevel {
         LintLevel::Allow
     }
 
-    fn tag() -> &'static str {
+    fn tag(&self) -> &'static str {
         "draw_text"
     }
-}
 
-impl EarlyExprPass for DrawText {
-    fn visit_expr_early(expr: &Expr, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+    fn visit_expr_early(&self, expr: &Expr, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
         if let ExprKind::Call(Call { left, .. }) = expr.kind() {
             if let ExprKind::Identifier(identifier) = left.kind() {
                 if gm_draw_text_functions().contains(&identifier.lexeme.as_str()) {
                     reports.push(
-                        Self::diagnostic(config)
+                        self.diagnostic(config)
                             .with_message(format!("Use of `{}`", identifier.lexeme))
                             .with_labels(vec![
                                 Label::primary(left.file_id(), left.span())
@@ -0,0 +1,93 @@
+use codespan_reporting::diagnostic::{Diagnostic, Label};
+
+use crate::{
+    Config, FileId,
+    analyze::{DeclarationKind, Evaluator, Project, Value},
+    lint::{Lint, LintLevel},
+    parse::{Expr, ExprKind, Stmt, StmtKind},
+};
+
+#[derive(Debug, PartialEq, Default)]
+pub struct DuplicateCase;
+impl Lint for DuplicateCase {
+    fn explanation(&self) -> &'static str {
+        "Only the first case matching a value in a switch statement is ever run, so any later case with the same value is dead code. Literals, enum members and macros are resolved to their values, so `case 1:` and `case ONE:` are caught as well."
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Deny
+    }
+
+    fn tag(&self) -> &'static str {
+        "duplicate_case"
+    }
+
+    fn visit_stmt_late(&self, stmt: &Stmt, project: &Project, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+        let StmtKind::Switch(switch) = stmt.kind() else {
+            return;
+        };
+        let mut matched: Vec<(CaseValue, &Expr)> = vec![];
+        for case in switch.cases.iter() {
+            let Some(value) = Self::resolve(case.identity(), project) else {
+                continue;
+            };
+            if let Some((_, first)) = matched.iter().find(|(other, _)| *other == value) {
+                reports.push(
+                    self.diagnostic(config)
+                        .with_message("Duplicate switch case")
+                        .with_labels(vec![
+                            Label::primary(case.identity().file_id(), case.identity().span())
+                                .with_message(format!("this case matches `{value}`, which is already matched above")),
+                            Label::secondary(first.file_id(), first.span())
+                                .with_message(format!("`{value}` is first matched here")),
+                        ]),
+                );
+            } else {
+                matched.push((value, case.identity()));
+            }
+        }
+    }
+}
+
+impl DuplicateCase {
+    /// Resolves the value a case matches, if it is constant. Enum members and macros whose values
+    /// can't be worked out are still compared by name.
+    fn resolve(expr: &Expr, project: &Project) -> Option<CaseValue> {
+        match Evaluator::with_project(project).evaluate(expr) {
+            Some(Value::String(string)) => Some(CaseValue::String(string)),
+            Some(value) => value.as_real().map(CaseValue::Number),
+            None => {
+                let name = match expr.kind() {
+                    ExprKind::Identifier(identifier) => identifier.lexeme.clone(),
+                    kind => {
+                        let (left, right) = kind.as_dot_access()?;
+                        format!("{}.{}", left.as_identifier()?.lexeme, right.lexeme)
+                    }
+                };
+                matches!(
+                    project.declarations(&name).first()?.kind(),
+                    DeclarationKind::EnumMember | DeclarationKind::Macro
+                )
+                .then_some(CaseValue::Symbol(name))
+            }
+        }
+    }
+}
+
+/// A constant value matched by a switch case.
+#[derive(Debug, PartialEq)]
+enum CaseValue {
+    Number(f64),
+    String(String),
+    /// A constant whose value is unknown, but which is always equal to itself.
+    Symbol(String),
+}
+impl std::fmt::Display for CaseValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CaseValue::Number(value) => write!(f, "{value}"),
+            CaseValue::String(string) => write!(f, "\"{string}\""),
+            CaseValue::Symbol(name) => f.write_str(name),
+        }
+    }
+}
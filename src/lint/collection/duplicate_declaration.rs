@@ -0,0 +1,101 @@
+use codespan_reporting::diagnostic::{Diagnostic, Label};
+
+use crate::{
+    Config, FileId,
+    analyze::{Declaration, DeclarationKind, Project},
+    lint::{Lint, LintLevel},
+    parse::{Function, Location, Stmt, StmtKind},
+};
+
+#[derive(Debug, PartialEq, Default)]
+pub struct DuplicateDeclaration;
+impl Lint for DuplicateDeclaration {
+    fn explanation(&self) -> &'static str {
+        "GameMaker allows two scripts to declare a global function, constructor, enum or macro with the same name, and silently uses whichever one it happens to compile last. Macros bound to a configuration (`#macro Config:NAME`) are only compared against macros for the same configuration, as overriding a macro per configuration is intended."
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Deny
+    }
+
+    fn tag(&self) -> &'static str {
+        "duplicate_declaration"
+    }
+
+    fn visit_stmt_late(&self, stmt: &Stmt, project: &Project, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+        let (name, description) = match stmt.kind() {
+            StmtKind::Macro(mac) => (&mac.name, "macro"),
+            StmtKind::Enum(gml_enum) => (&gml_enum.name, "enum"),
+            _ => match stmt.kind().as_expr_stmt().and_then(|expr| expr.kind().as_function()) {
+                Some(Function {
+                    name: Some(name),
+                    constructor: Some(_),
+                    ..
+                }) => (name, "constructor"),
+                Some(Function { name: Some(name), .. }) => (name, "function"),
+                _ => return,
+            },
+        };
+        let location = Location(stmt.file_id(), name.span);
+        let Some(declaration) = project
+            .declarations(&name.lexeme)
+            .iter()
+            .find(|declaration| declaration.location() == location)
+        else {
+            return;
+        };
+
+        // Every declaration after the first (ordered by path, so that reports are stable between
+        // runs) is reported alongside the first one
+        let mut collisions: Vec<&Declaration> = project
+            .declarations(&name.lexeme)
+            .iter()
+            .filter(|other| Self::collides(declaration, other))
+            .collect();
+        if collisions.len() < 2 {
+            return;
+        }
+        collisions.sort_by_key(|other| (project.path(other.location().0), other.location().1.start()));
+        let first = collisions[0];
+        if first.location() == location {
+            return;
+        }
+        let Location(first_file_id, first_span) = first.location();
+        reports.push(
+            self.diagnostic(config)
+                .with_message(format!("Duplicate global {description} `{name}`"))
+                .with_labels(vec![
+                    Label::primary(stmt.file_id(), name.span).with_message(format!(
+                        "`{name}` is also declared {} here",
+                        Self::describe(declaration)
+                    )),
+                    Label::secondary(first_file_id, first_span)
+                        .with_message(format!("`{name}` is declared {} here", Self::describe(first))),
+                ]),
+        );
+    }
+}
+
+impl DuplicateDeclaration {
+    /// Returns whether the two declarations fight over the same name. Functions and constructors
+    /// share a namespace, and macros only collide with macros bound to the same configuration.
+    fn collides(declaration: &Declaration, other: &Declaration) -> bool {
+        use DeclarationKind::*;
+        match (declaration.kind(), other.kind()) {
+            (Function | Constructor, Function | Constructor) | (Enum, Enum) => true,
+            (Macro, Macro) => declaration.config() == other.config(),
+            _ => false,
+        }
+    }
+
+    fn describe(declaration: &Declaration) -> &'static str {
+        match declaration.kind() {
+            DeclarationKind::Constructor => "as a constructor",
+            DeclarationKind::Function => "as a function",
+            DeclarationKind::Macro => "as a macro",
+            DeclarationKind::Enum => "as an enum",
+            DeclarationKind::EnumMember => "as an enum member",
+            DeclarationKind::Globalvar => "as a globalvar",
+        }
+    }
+}
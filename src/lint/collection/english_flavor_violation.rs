@@ -4,28 +4,26 @@ use once_cell::sync::Lazy;
 
 use crate::{
     EnglishFlavor, FileId,
-    lint::{EarlyExprPass, Lint, LintLevel},
+    lint::{Lint, LintLevel},
     parse::{Call, Expr, ExprKind},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Default)]
 pub struct EnglishFlavorViolation;
 impl Lint for EnglishFlavorViolation {
-    fn explanation() -> &'static str {
+    fn explanation(&self) -> &'static str {
         "GML has many duplicated function names for the sake of supporting both British and American spelling. For consistency, codebases should stick to one."
     }
 
-    fn default_level() -> LintLevel {
+    fn default_level(&self) -> LintLevel {
         LintLevel::Allow
     }
 
-    fn tag() -> &'static str {
+    fn tag(&self) -> &'static str {
         "english_flavor_violation"
     }
-}
 
-impl EarlyExprPass for EnglishFlavorViolation {
-    fn visit_expr_early(expr: &Expr, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
+    fn visit_expr_early(&self, expr: &Expr, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
         let english_flavor = &config.english_flavor;
         if let ExprKind::Call(Call { left, .. }) = expr.kind() {
             if let ExprKind::Identifier(identifier) = left.kind() {
@@ -35,7 +33,7 @@ impl EarlyExprPass for EnglishFlavorViolation {
                             BRITISH_TO_AMERICAN_KEYWORDS.get_by_left(identifier.lexeme.as_str())
                         {
                             reports.push(
-                                Self::diagnostic(config)
+                                self.diagnostic(config)
                                     .with_message(format!("Use of British spelling `{}`", identifier.lexeme))
                                     .with_labels(vec![
                                         Label::primary(left.file_id(), left.span())
@@ -49,7 +47,7 @@ impl EarlyExprPass for EnglishFlavorViolation {
                             BRITISH_TO_AMERICAN_KEYWORDS.get_by_right(identifier.lexeme.as_str())
                         {
                             reports.push(
-                                Self::diagnostic(config)
+                                self.diagnostic(config)
                                     .with_message(format!("Use of American spelling `{}`", identifier.lexeme))
                                     .with_labels(vec![
                                         Label::primary(left.file_id(), left.span())
@@ -2,30 +2,28 @@ use codespan_reporting::diagnostic::{Diagnostic, Label};
 
 use crate::{
     FileId,
-    lint::{EarlyStmtPass, Lint, LintLevel},
+    lint::{Lint, LintLevel},
     parse::{Stmt, StmtKind},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Default)]
 pub struct Exit;
 impl Lint for Exit {
-    fn explanation() -> &'static str {
+    fn explanation(&self) -> &'static str {
         "`return` can always be used in place of exit, which provides more consistency across your codebase."
     }
 
-    fn default_level() -> LintLevel {
+    fn default_level(&self) -> LintLevel {
         LintLevel::Allow
     }
 
-    fn tag() -> &'static str {
+    fn tag(&self) -> &'static str {
         "exit"
     }
-}
 
-impl EarlyStmtPass for Exit {
-    fn visit_stmt_early(stmt: &Stmt, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
+    fn visit_stmt_early(&self, stmt: &Stmt, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
         if let StmtKind::Exit = stmt.kind() {
-            reports.push(Self::diagnostic(config).with_message("Use of `exit`").with_labels(vec![
+            reports.push(self.diagnostic(config).with_message("Use of `exit`").with_labels(vec![
                 Label::primary(stmt.file_id(), stmt.span()).with_message("replace this with `return`"),
             ]));
         }
@@ -2,33 +2,31 @@ use codespan_reporting::diagnostic::{Diagnostic, Label};
 
 use crate::{
     FileId,
-    lint::{EarlyStmtPass, Lint, LintLevel},
+    lint::{Lint, LintLevel},
     parse::{Access, Assignment, ExprKind, Globalvar, Stmt, StmtKind},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Default)]
 pub struct Global;
 impl Lint for Global {
-    fn explanation() -> &'static str {
+    fn explanation(&self) -> &'static str {
         "While useful at times, global variables reduce saftey since they can be accessed or mutated anywhere, and provide no guarentee that they've already been initiailized."
     }
 
-    fn default_level() -> LintLevel {
+    fn default_level(&self) -> LintLevel {
         LintLevel::Allow
     }
 
-    fn tag() -> &'static str {
+    fn tag(&self) -> &'static str {
         "global"
     }
-}
 
-impl EarlyStmtPass for Global {
-    fn visit_stmt_early(stmt: &Stmt, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
+    fn visit_stmt_early(&self, stmt: &Stmt, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
         match stmt.kind() {
             StmtKind::Assignment(Assignment { left, .. }) => {
                 if let ExprKind::Access(Access::Global { .. }) = left.kind() {
                     reports.push(
-                        Self::diagnostic(config)
+                        self.diagnostic(config)
                             .with_message("Use of global variable")
                             .with_labels(vec![
                                 Label::primary(left.file_id(), left.span())
@@ -39,7 +37,7 @@ impl EarlyStmtPass for Global {
             }
             StmtKind::Globalvar(Globalvar { .. }) => {
                 reports.push(
-                    Self::diagnostic(config)
+                    self.diagnostic(config)
                         .with_message("Use of global variable")
                         .with_labels(vec![
                             Label::primary(stmt.file_id(), stmt.span())
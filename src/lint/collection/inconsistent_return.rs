@@ -0,0 +1,94 @@
+use codespan_reporting::diagnostic::{Diagnostic, Label};
+
+use crate::{
+    Config, FileId,
+    analyze::{Body, Project, Step},
+    lint::{Lint, LintLevel},
+    parse::*,
+};
+
+#[derive(Debug, PartialEq, Default)]
+pub struct InconsistentReturn;
+impl Lint for InconsistentReturn {
+    fn explanation(&self) -> &'static str {
+        "When a function returns a value on some paths, but reaches its end or uses a bare `return` or `exit` on others, those paths silently return `undefined`. This is usually a path that was forgotten. Constructors can't return values at all, so any value they return is discarded."
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warn
+    }
+
+    fn tag(&self) -> &'static str {
+        "inconsistent_return"
+    }
+
+    fn visit_body_late(&self, body: &Body, _project: &Project, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+        let Some(function) = body.as_function() else {
+            return;
+        };
+        let cfg = body.cfg();
+        let mut value_returns = vec![];
+        let mut bare_returns = vec![];
+        for (_, block) in cfg.blocks().filter(|(id, _)| cfg.is_reachable(*id)) {
+            for step in block.steps() {
+                let Step::Stmt(stmt) = step else {
+                    continue;
+                };
+                match stmt.kind() {
+                    StmtKind::Return(Return { value: Some(_) }) => value_returns.push(*stmt),
+                    StmtKind::Return(Return { value: None }) | StmtKind::Exit => bare_returns.push(*stmt),
+                    _ => {}
+                }
+            }
+        }
+
+        if function.constructor.is_some() {
+            if !value_returns.is_empty() {
+                reports.push(
+                    self.diagnostic(config)
+                        .with_message("Constructor returns a value")
+                        .with_labels(
+                            value_returns
+                                .iter()
+                                .map(|stmt| {
+                                    Label::primary(stmt.file_id(), stmt.span())
+                                        .with_message("this value is discarded, as constructors can't return values")
+                                })
+                                .collect(),
+                        ),
+                );
+            }
+            return;
+        }
+
+        let Some(value_return) = value_returns.first() else {
+            return;
+        };
+        let mut labels: Vec<Label<FileId>> = bare_returns
+            .iter()
+            .map(|stmt| Label::primary(stmt.file_id(), stmt.span()).with_message("this returns `undefined`"))
+            .collect();
+        if cfg.is_reachable(cfg.end()) {
+            let end = match function.body.kind() {
+                StmtKind::Block(Block {
+                    delimiters: Some((_, right)),
+                    ..
+                }) => right.span.start()..right.span.end(),
+                _ => function.body.span().end()..function.body.span().end(),
+            };
+            labels.push(
+                Label::primary(function.body.file_id(), end)
+                    .with_message("this function can reach its end without returning a value"),
+            );
+        }
+        if labels.is_empty() {
+            return;
+        }
+        labels.push(Label::secondary(value_return.file_id(), value_return.span()).with_message("this returns a value"));
+        reports.push(
+            self.diagnostic(config)
+                .with_message("Inconsistent return")
+                .with_labels(labels),
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use codespan_reporting::diagnostic::{Diagnostic, Label};
+
+use crate::{
+    Config, FileId,
+    lint::{Lint, LintLevel, SuppressionIssue, visit_tags},
+    parse::{Ast, Location},
+};
+
+#[derive(Debug, PartialEq, Default)]
+pub struct InvalidAllow;
+impl Lint for InvalidAllow {
+    fn explanation(&self) -> &'static str {
+        "Allow tags that have expired, have a malformed date, or are missing a reason required by your config no longer suppress anything."
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warn
+    }
+
+    fn tag(&self) -> &'static str {
+        "invalid_allow"
+    }
+
+    fn visit_ast(&self, ast: &Ast, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+        visit_tags(ast, config, |tag, config| {
+            if LintLevel::from_str(tag.label()) != Some(LintLevel::Allow)
+                || *config.get_lint_level_setting(self.tag(), self.default_level()) == LintLevel::Allow
+            {
+                return;
+            }
+            let Some((lint, issue)) = tag
+                .parameters()
+                .find_map(|lint| config.allow_issue(tag, lint).map(|issue| (lint, issue)))
+            else {
+                return;
+            };
+            let (message, label) = match issue {
+                SuppressionIssue::Expired(date) => (
+                    format!("Expired allow for `{lint}`"),
+                    format!("this tag stopped suppressing lints after {date}"),
+                ),
+                SuppressionIssue::InvalidDate(date) => (
+                    format!("Invalid date in allow for `{lint}`: `{date}`"),
+                    "dates must be real days written as `YYYY-MM-DD`".into(),
+                ),
+                SuppressionIssue::MissingReason => (
+                    format!("Missing reason in allow for `{lint}`"),
+                    "your config requires a reason for this lint (ie: `reason = \"...\"`)".into(),
+                ),
+            };
+            let Location(file_id, span) = tag.location();
+            reports.push(
+                self.diagnostic(config)
+                    .with_message(message)
+                    .with_labels(vec![Label::primary(file_id, span).with_message(label)]),
+            );
+        });
+    }
+}
@@ -1,28 +1,26 @@
 use crate::{
     Config, FileId,
-    lint::{EarlyStmtPass, Lint, LintLevel},
+    lint::{Lint, LintLevel},
     parse::{Assignment, ExprKind, Stmt, StmtKind},
 };
 use codespan_reporting::diagnostic::{Diagnostic, Label};
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Default)]
 pub struct InvalidAssignment;
 impl Lint for InvalidAssignment {
-    fn explanation() -> &'static str {
+    fn explanation(&self) -> &'static str {
         "Certain assignment patterns are valid in gml but are undefined behavior and have no valid use cases."
     }
 
-    fn default_level() -> LintLevel {
+    fn default_level(&self) -> LintLevel {
         LintLevel::Deny
     }
 
-    fn tag() -> &'static str {
+    fn tag(&self) -> &'static str {
         "invalid_assignment"
     }
-}
 
-impl EarlyStmtPass for InvalidAssignment {
-    fn visit_stmt_early(stmt: &Stmt, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+    fn visit_stmt_early(&self, stmt: &Stmt, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
         if let StmtKind::Assignment(Assignment {
             left,
             op: operator,
@@ -45,7 +43,7 @@ impl EarlyStmtPass for InvalidAssignment {
             };
             if !is_valid {
                 reports.push(
-                    Self::diagnostic(config)
+                    self.diagnostic(config)
                         .with_message("Invalid assignment target")
                         .with_labels(vec![
                             Label::primary(left.file_id(), operator.token().span.start()..right.span().end())
@@ -0,0 +1,118 @@
+use codespan_reporting::diagnostic::{Diagnostic, Label};
+
+use crate::{
+    Config, FileId,
+    analyze::{DeclarationKind, Project},
+    lint::{Lint, LintLevel},
+    parse::{Enum, Evaluation, Expr, ExprKind, Grouping, Stmt, StmtKind, Unary, UnaryOp},
+};
+
+#[derive(Debug, PartialEq, Default)]
+pub struct InvalidEnumMember;
+impl Lint for InvalidEnumMember {
+    fn explanation(&self) -> &'static str {
+        "Enum members must be initialized with constant numbers, and two members sharing a value can't be told apart (which breaks any switch statement over the enum). Members initialized directly with another member (ie: `Default = Dir.Left`) are treated as intentional aliases. The member named after the `length_enum_member_name` config option must be last, or it won't count every member."
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Deny
+    }
+
+    fn tag(&self) -> &'static str {
+        "invalid_enum_member"
+    }
+
+    fn visit_stmt_late(&self, stmt: &Stmt, project: &Project, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+        let StmtKind::Enum(Enum { name, members }) = stmt.kind() else {
+            return;
+        };
+        let mut values: Vec<(f64, &Expr)> = vec![];
+        for (index, member) in members.iter().enumerate() {
+            let member_name = member.name_expr();
+            if let Some(initializer) = member
+                .assignment_value()
+                .filter(|value| !Self::is_constant(value, project))
+            {
+                reports.push(
+                    self.diagnostic(config)
+                        .with_message(format!("Non-constant initializer for `{}.{}`", name, member.name()))
+                        .with_labels(vec![
+                            Label::primary(initializer.file_id(), initializer.span())
+                                .with_message("enum members can only be initialized with constant numbers"),
+                        ]),
+                );
+            }
+
+            if member.name() == config.length_enum_member_name && index + 1 < members.len() {
+                reports.push(
+                    self.diagnostic(config)
+                        .with_message(format!("`{}.{}` is not the last member", name, member.name()))
+                        .with_labels(vec![
+                            Label::primary(member_name.file_id(), member_name.span())
+                                .with_message("move this to the end of the enum, so that it counts every member"),
+                        ]),
+                );
+            }
+
+            let Some(value) = project.enum_member_value(&format!("{}.{}", name, member.name())) else {
+                continue;
+            };
+            let Some((_, other)) = values.iter().find(|(other_value, _)| *other_value == value) else {
+                values.push((value, member_name));
+                continue;
+            };
+            if member
+                .assignment_value()
+                .and_then(|value| value.kind().as_dot_access())
+                .is_some_and(|(left, right)| {
+                    left.as_identifier().is_some_and(|left| left.lexeme == name.lexeme)
+                        && other
+                            .kind()
+                            .as_identifier()
+                            .is_some_and(|other| other.lexeme == right.lexeme)
+                })
+            {
+                continue;
+            }
+            reports.push(
+                self.diagnostic(config)
+                    .with_message(format!("Duplicate value in enum `{name}`"))
+                    .with_labels(vec![
+                        Label::primary(member_name.file_id(), member_name.span())
+                            .with_message(format!("`{}` is {value}, which is already taken", member.name())),
+                        Label::secondary(other.file_id(), other.span()).with_message(format!("`{other}` is {value}")),
+                    ]),
+            );
+        }
+    }
+}
+
+impl InvalidEnumMember {
+    /// Returns whether the expression is made up only of numbers, operators, enum members and
+    /// macros.
+    fn is_constant(expr: &Expr, project: &Project) -> bool {
+        match expr.kind() {
+            ExprKind::Literal(literal) => literal.as_number().is_some(),
+            ExprKind::Grouping(Grouping { inner, .. }) => Self::is_constant(inner, project),
+            ExprKind::Unary(Unary { op, right }) => {
+                !matches!(op, UnaryOp::Increment(_) | UnaryOp::Decrement(_)) && Self::is_constant(right, project)
+            }
+            ExprKind::Evaluation(Evaluation { left, right, .. }) => {
+                Self::is_constant(left, project) && Self::is_constant(right, project)
+            }
+            ExprKind::Identifier(identifier) => project
+                .declarations(&identifier.lexeme)
+                .iter()
+                .any(|declaration| declaration.kind() == DeclarationKind::Macro),
+            kind => kind
+                .as_dot_access()
+                .and_then(|(left, _)| left.as_identifier())
+                .is_some_and(|left| {
+                    project
+                        .declarations(&left.lexeme)
+                        .iter()
+                        .any(|declaration| declaration.kind() == DeclarationKind::Enum)
+                }),
+        }
+    }
+}
@@ -2,32 +2,39 @@ use codespan_reporting::diagnostic::{Diagnostic, Label};
 
 use crate::{
     Config, FileId,
-    lint::{EarlyExprPass, Lint, LintLevel},
+    lint::{Lint, LintLevel},
     parse::{Equality, Expr, ExprKind},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Default)]
 pub struct InvalidEquality;
 impl Lint for InvalidEquality {
-    fn explanation() -> &'static str {
+    fn explanation(&self) -> &'static str {
         "Certain types allow equality checks in gml but are undefined behavior and have no valid use cases."
     }
 
-    fn default_level() -> LintLevel {
+    fn default_level(&self) -> LintLevel {
         LintLevel::Deny
     }
 
-    fn tag() -> &'static str {
+    fn tag(&self) -> &'static str {
         "invalid_equality"
     }
+
+    fn visit_expr_early(&self, expr: &Expr, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+        if let ExprKind::Equality(Equality { left, right, .. }) = expr.kind() {
+            self.test_expr(left, config, reports);
+            self.test_expr(right, config, reports);
+        }
+    }
 }
 
 impl InvalidEquality {
-    fn test_expr(expr: &Expr, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+    fn test_expr(&self, expr: &Expr, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
         let is_valid = !matches!(expr.kind(), ExprKind::Function(_));
         if !is_valid {
             reports.push(
-                Self::diagnostic(config)
+                self.diagnostic(config)
                     .with_message("Invalid equality")
                     .with_labels(vec![
                         Label::primary(expr.file_id(), expr.span())
@@ -37,12 +44,3 @@ impl InvalidEquality {
         }
     }
 }
-
-impl EarlyExprPass for InvalidEquality {
-    fn visit_expr_early(expr: &Expr, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
-        if let ExprKind::Equality(Equality { left, right, .. }) = expr.kind() {
-            Self::test_expr(left, config, reports);
-            Self::test_expr(right, config, reports);
-        }
-    }
-}
@@ -0,0 +1,48 @@
+use codespan_reporting::diagnostic::{Diagnostic, Label};
+
+use crate::{
+    Config, FileId,
+    lint::{Lint, LintLevel},
+    parse::Ast,
+};
+
+#[derive(Debug, PartialEq, Default)]
+pub struct LineTooLong;
+impl Lint for LineTooLong {
+    fn explanation(&self) -> &'static str {
+        "Long lines are hard to read, especially side by side or in a diff. The limit is set by the `max_line_length` config option."
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Allow
+    }
+
+    fn tag(&self) -> &'static str {
+        "line_too_long"
+    }
+
+    fn visit_ast(&self, ast: &Ast, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+        let Some(file_id) = ast.stmts().first().map(|stmt| stmt.file_id()) else {
+            return;
+        };
+        let mut start = 0;
+        for line in ast.source().split_inclusive('\n') {
+            let text = line.trim_end_matches(['\r', '\n']);
+            let length = text.chars().count();
+            if length > config.max_line_length {
+                // Point at everything past the limit
+                let overflow = text
+                    .char_indices()
+                    .nth(config.max_line_length)
+                    .map_or(0, |(index, _)| index);
+                reports.push(self.diagnostic(config).with_message("Line too long").with_labels(vec![
+                    Label::primary(file_id, start + overflow..start + text.len()).with_message(format!(
+                        "this line is {length} characters long, but the maximum is set to {}",
+                        config.max_line_length
+                    )),
+                ]));
+            }
+            start += line.len();
+        }
+    }
+}
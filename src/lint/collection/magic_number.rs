@@ -0,0 +1,104 @@
+use codespan_reporting::diagnostic::{Diagnostic, Label};
+use colored::Colorize;
+
+use crate::{
+    Config, FileId,
+    lint::{Lint, LintLevel},
+    parse::*,
+};
+
+#[derive(Debug, PartialEq, Default)]
+pub struct MagicNumber;
+impl Lint for MagicNumber {
+    fn explanation(&self) -> &'static str {
+        "Numbers written directly into code (such as `if (hp < 37)`) don't explain what they mean, and have to be hunted down one by one when they need to change. Giving them a name with a macro or an enum keeps them in one place. Enum and macro definitions, array indices of `0` and `1`, and the numbers in the `allowed_magic_numbers` config option are ignored."
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Allow
+    }
+
+    fn tag(&self) -> &'static str {
+        "magic_number"
+    }
+
+    // Initialized enum members are statements of their own, so the file is walked from the top
+    // where the enums around them are known. The driver still applies the tags around each number.
+    fn visit_ast(&self, ast: &Ast, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+        for stmt in ast.stmts() {
+            self.visit_stmt(stmt, config, reports);
+        }
+    }
+}
+
+impl MagicNumber {
+    fn visit_stmt(&self, stmt: &Stmt, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+        // Enum members are exactly where numbers should be given names. (Macro bodies are raw gml,
+        // and are never parsed into expressions to begin with.)
+        if matches!(stmt.kind(), StmtKind::Enum(_)) {
+            return;
+        }
+        stmt.visit_child_stmts(|stmt| self.visit_stmt(stmt, config, reports));
+        stmt.visit_child_exprs(|expr| self.visit_expr(expr, config, reports));
+    }
+
+    fn visit_expr(&self, expr: &Expr, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+        if let Some(value) = Self::number(expr) {
+            if !config.allowed_magic_numbers.contains(&value) {
+                self.report(expr, config, reports);
+            }
+            return;
+        }
+        if let ExprKind::Access(Access::Array {
+            left,
+            index_one,
+            index_two,
+            ..
+        }) = expr.kind()
+        {
+            // The first and second elements of an array are rarely anything but themselves
+            self.visit_expr(left, config, reports);
+            for index in std::iter::once(index_one).chain(index_two) {
+                if !Self::number(index).is_some_and(|value| value == 0.0 || value == 1.0) {
+                    self.visit_expr(index, config, reports);
+                }
+            }
+            return;
+        }
+        expr.visit_child_stmts(|stmt| self.visit_stmt(stmt, config, reports));
+        expr.visit_child_exprs(|expr| self.visit_expr(expr, config, reports));
+    }
+
+    /// Returns the value of the expression if it is a number literal, including negated ones.
+    fn number(expr: &Expr) -> Option<f64> {
+        match expr.kind() {
+            ExprKind::Literal(literal @ (Literal::Real(_) | Literal::Hex(_))) => literal.as_number(),
+            ExprKind::Unary(Unary {
+                op: UnaryOp::Negative(_),
+                right,
+            }) => match right.kind() {
+                ExprKind::Literal(literal @ (Literal::Real(_) | Literal::Hex(_))) => {
+                    literal.as_number().map(|value| -value)
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn report(&self, expr: &Expr, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+        let number = Printer::default().expr(expr);
+        reports.push(
+            self.diagnostic(config)
+                .with_message(format!("Magic number `{number}`"))
+                .with_labels(vec![
+                    Label::primary(expr.file_id(), expr.span())
+                        .with_message("this number has no name to explain what it means"),
+                ])
+                .with_notes(vec![format!(
+                    "{}: extract this value into a macro (ie: `#macro NAME {number}`) or an enum member",
+                    "help".bold(),
+                )]),
+        );
+    }
+}
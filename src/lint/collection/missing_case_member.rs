@@ -1,28 +1,33 @@
 use crate::{
     FileId,
-    lint::{LateStmtPass, Lint, LintLevel},
+    analyze::Project,
+    lint::{Lint, LintLevel},
     parse::Stmt,
 };
 use codespan_reporting::diagnostic::Diagnostic;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Default)]
 pub struct MissingCaseMember;
 impl Lint for MissingCaseMember {
-    fn explanation() -> &'static str {
+    fn explanation(&self) -> &'static str {
         "Switch statements matching over an enum typically want to cover all possible cases if they do not implement a default case."
     }
 
-    fn tag() -> &'static str {
+    fn tag(&self) -> &'static str {
         "missing_case_member"
     }
 
-    fn default_level() -> LintLevel {
+    fn default_level(&self) -> LintLevel {
         LintLevel::Warn
     }
-}
 
-impl LateStmtPass for MissingCaseMember {
-    fn visit_stmt_late(_stmt: &Stmt, _config: &crate::Config, _reports: &mut Vec<Diagnostic<FileId>>) {
+    fn visit_stmt_late(
+        &self,
+        _stmt: &Stmt,
+        _project: &Project,
+        _config: &crate::Config,
+        _reports: &mut Vec<Diagnostic<FileId>>,
+    ) {
         // if let StmtType::Switch(switch) = stmt.kind() {
         //     // Ignore switches that don't pertain to this lint
         //     // TODO: Check for user supplied crash calls here, and enable the lint if they're in
@@ -98,7 +103,7 @@ impl LateStmtPass for MissingCaseMember {
         //             }
         //         }
         //         reports.push(
-        //             Self::diagnostic(config)
+        //             self.diagnostic(config)
         //                 .with_message("Missing case members in switch statement")
         //                 .with_labels(labels)
         //                 .with_notes(notes),
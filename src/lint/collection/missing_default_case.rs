@@ -2,28 +2,26 @@ use codespan_reporting::diagnostic::{Diagnostic, Label};
 
 use crate::{
     FileId,
-    lint::{EarlyStmtPass, Lint, LintLevel},
+    lint::{Lint, LintLevel},
     parse::{Stmt, StmtKind},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Default)]
 pub struct MissingDefaultCase;
 impl Lint for MissingDefaultCase {
-    fn explanation() -> &'static str {
+    fn explanation(&self) -> &'static str {
         "Switch statements are often used to express all possible outcomes of a limited data set, but by not implementing a default case, no code will run to handle any alternate or unexpected values."
     }
 
-    fn default_level() -> LintLevel {
+    fn default_level(&self) -> LintLevel {
         LintLevel::Allow
     }
 
-    fn tag() -> &'static str {
+    fn tag(&self) -> &'static str {
         "missing_default_case"
     }
-}
 
-impl EarlyStmtPass for MissingDefaultCase {
-    fn visit_stmt_early(stmt: &Stmt, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
+    fn visit_stmt_early(&self, stmt: &Stmt, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
         if let StmtKind::Switch(switch) = stmt.kind() {
             if switch.default_case().is_none() {
                 let final_position = switch
@@ -33,7 +31,7 @@ impl EarlyStmtPass for MissingDefaultCase {
                     .and_then(|case| case.iter_body_statements().last().map(|stmt| stmt.span().end()))
                     .unwrap_or_else(|| stmt.span().end());
                 reports.push(
-                    Self::diagnostic(config)
+                    self.diagnostic(config)
                         .with_message("Missing default case")
                         .with_labels(vec![
                             Label::primary(stmt.file_id(), stmt.span())
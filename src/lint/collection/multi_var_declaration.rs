@@ -2,32 +2,30 @@ use codespan_reporting::diagnostic::{Diagnostic, Label};
 
 use crate::{
     FileId,
-    lint::{EarlyStmtPass, Lint, LintLevel},
+    lint::{Lint, LintLevel},
     parse::{LocalVariables, Stmt, StmtKind},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Default)]
 pub struct MultiVarDeclaration;
 impl Lint for MultiVarDeclaration {
-    fn explanation() -> &'static str {
+    fn explanation(&self) -> &'static str {
         "While GML allows you to create multiple local variables at once, it can often lead to confusing syntax that would read better with each variable seperated."
     }
 
-    fn default_level() -> LintLevel {
+    fn default_level(&self) -> LintLevel {
         LintLevel::Allow
     }
 
-    fn tag() -> &'static str {
+    fn tag(&self) -> &'static str {
         "multi_var_declaration"
     }
-}
 
-impl EarlyStmtPass for MultiVarDeclaration {
-    fn visit_stmt_early(stmt: &Stmt, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
+    fn visit_stmt_early(&self, stmt: &Stmt, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
         if let StmtKind::LocalVariables(LocalVariables { declarations }) = stmt.kind() {
             if declarations.len() > 1 {
                 reports.push(
-                    Self::diagnostic(config)
+                    self.diagnostic(config)
                         .with_message("Multiple local variables declared at once")
                         .with_labels(vec![
                             Label::primary(stmt.file_id(), stmt.span())
@@ -2,24 +2,52 @@ use codespan_reporting::diagnostic::Diagnostic;
 
 use crate::{
     FileId,
-    lint::{LateExprPass, Lint, LintLevel},
+    analyze::{Body, Project},
+    lint::{Lint, LintLevel},
     parse::Expr,
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Default)]
 pub struct NonConstantDefaultParameter;
 impl Lint for NonConstantDefaultParameter {
-    fn explanation() -> &'static str {
+    fn explanation(&self) -> &'static str {
         "Expressive default parameters are not supported in most languages due to their instability and tendency to hide important logic execution from the caller."
     }
 
-    fn default_level() -> LintLevel {
+    fn default_level(&self) -> LintLevel {
         LintLevel::Warn
     }
 
-    fn tag() -> &'static str {
+    fn tag(&self) -> &'static str {
         "non_constant_default_parameter"
     }
+
+    fn visit_expr_late(
+        &self,
+        _expr: &Expr,
+        _body: &Body,
+        _project: &Project,
+        _config: &crate::Config,
+        _reports: &mut Vec<Diagnostic<FileId>>,
+    ) {
+        // if let ExprType::Function(Function { parameters, .. }) = expr.kind() {
+        //     for param in parameters {
+        //         if let Some(default_expr) = param.assignment_value() {
+        //             let constant = Self::is_constant(default_expr, global_scope);
+        //             if !constant {
+        //                 reports.push(
+        //                     self.diagnostic(config)
+        //                         .with_message("Non constant default parameter")
+        //                         .with_labels(vec![
+        //                             Label::primary(default_expr.file_id(), default_expr.span())
+        //                                 .with_message("this parameter's default value is not
+        // constant"),                         ]),
+        //                 );
+        //             }
+        //         }
+        //     }
+        // }
+    }
 }
 
 impl NonConstantDefaultParameter {
@@ -46,25 +74,3 @@ impl NonConstantDefaultParameter {
         false
     }
 }
-
-impl LateExprPass for NonConstantDefaultParameter {
-    fn visit_expr_late(_expr: &Expr, _config: &crate::Config, _reports: &mut Vec<Diagnostic<FileId>>) {
-        // if let ExprType::Function(Function { parameters, .. }) = expr.kind() {
-        //     for param in parameters {
-        //         if let Some(default_expr) = param.assignment_value() {
-        //             let constant = Self::is_constant(default_expr, global_scope);
-        //             if !constant {
-        //                 reports.push(
-        //                     Self::diagnostic(config)
-        //                         .with_message("Non constant default parameter")
-        //                         .with_labels(vec![
-        //                             Label::primary(default_expr.file_id(), default_expr.span())
-        //                                 .with_message("this parameter's default value is not
-        // constant"),                         ]),
-        //                 );
-        //             }
-        //         }
-        //     }
-        // }
-    }
-}
@@ -1,38 +1,27 @@
 use crate::{
     FileId,
-    lint::{EarlyExprPass, Lint, LintLevel},
+    analyze::Evaluator,
+    lint::{Lint, LintLevel},
     parse::{Evaluation, EvaluationOp, Expr, ExprKind, Grouping, Literal},
 };
 use codespan_reporting::diagnostic::{Diagnostic, Label};
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Default)]
 pub struct NonSimplifiedExpression;
 impl Lint for NonSimplifiedExpression {
-    fn explanation() -> &'static str {
+    fn explanation(&self) -> &'static str {
         "Operating on two constant numbers can be reduced for brevity."
     }
 
-    fn default_level() -> LintLevel {
+    fn default_level(&self) -> LintLevel {
         LintLevel::Warn
     }
 
-    fn tag() -> &'static str {
+    fn tag(&self) -> &'static str {
         "non_simplified_expression"
     }
-}
-
-impl NonSimplifiedExpression {
-    fn filter_groups(expr: &Expr) -> Option<&Expr> {
-        match expr.kind() {
-            ExprKind::Grouping(Grouping { inner, .. }) => Self::filter_groups(inner),
-            ExprKind::Literal(_) => Some(expr),
-            _ => None,
-        }
-    }
-}
 
-impl EarlyExprPass for NonSimplifiedExpression {
-    fn visit_expr_early(expr: &Expr, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
+    fn visit_expr_early(&self, expr: &Expr, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
         if let ExprKind::Evaluation(Evaluation { left, op, right }) = expr.kind() {
             let left = Self::filter_groups(left).unwrap_or(left);
             let right = Self::filter_groups(right).unwrap_or(right);
@@ -71,9 +60,25 @@ impl EarlyExprPass for NonSimplifiedExpression {
                 _ => return,
             };
 
-            reports.push(Self::diagnostic(config).with_message(message).with_labels(vec![
-                Label::primary(expr.file_id(), expr.span()).with_message("this expression can be reduced"),
-            ]));
+            let label = match Evaluator::new().evaluate(expr) {
+                Some(value) => format!("this expression can be reduced to `{value}`"),
+                None => "this expression can be reduced".into(),
+            };
+            reports.push(
+                self.diagnostic(config)
+                    .with_message(message)
+                    .with_labels(vec![Label::primary(expr.file_id(), expr.span()).with_message(label)]),
+            );
+        }
+    }
+}
+
+impl NonSimplifiedExpression {
+    fn filter_groups(expr: &Expr) -> Option<&Expr> {
+        match expr.kind() {
+            ExprKind::Grouping(Grouping { inner, .. }) => Self::filter_groups(inner),
+            ExprKind::Literal(_) => Some(expr),
+            _ => None,
         }
     }
 }
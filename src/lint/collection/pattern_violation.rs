@@ -0,0 +1,67 @@
+use codespan_reporting::diagnostic::{Diagnostic, Label, Severity};
+
+use crate::{
+    Config, FileId, PatternRule,
+    lint::{Lint, LintLevel},
+    parse::{Expr, Location, PatternMatch, Stmt},
+};
+
+#[derive(Debug, PartialEq, Default)]
+pub struct PatternViolation;
+impl Lint for PatternViolation {
+    fn explanation(&self) -> &'static str {
+        "Many code patterns a studio wants to avoid are too specific for a built-in lint. The `pattern_rules` in your config describe the structure of code that should be reported, and optionally what it should be rewritten to."
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warn
+    }
+
+    fn tag(&self) -> &'static str {
+        "pattern_violation"
+    }
+
+    fn visit_expr_early(&self, expr: &Expr, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+        for rule in config.pattern_rules.iter() {
+            if let Some(pattern_match) = rule.pattern().ok().and_then(|pattern| pattern.match_expr(expr)) {
+                self.report(rule, pattern_match, config, reports);
+            }
+        }
+    }
+
+    fn visit_stmt_early(&self, stmt: &Stmt, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+        for rule in config.pattern_rules.iter() {
+            if let Some(pattern_match) = rule.pattern().ok().and_then(|pattern| pattern.match_stmt(stmt)) {
+                self.report(rule, pattern_match, config, reports);
+            }
+        }
+    }
+}
+
+impl PatternViolation {
+    fn report(
+        &self,
+        rule: &PatternRule,
+        pattern_match: PatternMatch,
+        config: &Config,
+        reports: &mut Vec<Diagnostic<FileId>>,
+    ) {
+        let mut diagnostic = self.diagnostic(config);
+        match rule.level {
+            Some(LintLevel::Allow) => return,
+            Some(LintLevel::Warn) => diagnostic.severity = Severity::Warning,
+            Some(LintLevel::Deny) => diagnostic.severity = Severity::Error,
+            None => {}
+        }
+        let Location(file_id, span) = pattern_match.location();
+        let label = match &rule.rewrite {
+            Some(rewrite) => format!("replace this with `{}`", pattern_match.rewrite(rewrite)),
+            None => format!("this matches `{}`", rule.pattern),
+        };
+        reports.push(
+            diagnostic
+                .with_message(rule.message.clone())
+                .with_labels(vec![Label::primary(file_id, span).with_message(label)]),
+        );
+    }
+}
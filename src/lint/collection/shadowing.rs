@@ -0,0 +1,200 @@
+use codespan_reporting::diagnostic::{Diagnostic, Label};
+
+use crate::{
+    Config, FileId,
+    analyze::{Body, Project},
+    lint::{Lint, LintLevel},
+    parse::{
+        Expr, ExprKind, Field, Location, MISC_GML_GLOBAL_VARIABLES, MISC_GML_INSTANCE_VARIABLES, ParseVisitor, Stmt,
+        StmtKind, TryCatch,
+    },
+};
+
+#[derive(Debug, PartialEq, Default)]
+pub struct Shadowing;
+impl Lint for Shadowing {
+    fn explanation(&self) -> &'static str {
+        "A local variable or parameter that shares a name with something else hides it for the rest of its scope, so code that means to use the original (such as an object's built-in `x`, the built-in `room`, a global function, or the counter of an outer loop) silently uses the local instead. Each kind of shadowing can be turned off in the `shadowing_rules` config option."
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warn
+    }
+
+    fn tag(&self) -> &'static str {
+        "shadowing"
+    }
+
+    fn visit_body_late(&self, body: &Body, project: &Project, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+        let parameters = body
+            .as_function()
+            .map_or(&[][..], |function| function.parameters.as_slice());
+        let mut walker = ScopeWalker {
+            lint: self,
+            project,
+            config,
+            parameters,
+            scopes: vec![vec![]],
+            reports,
+        };
+        for parameter in parameters {
+            walker.check_global(parameter.name_expr(), "Parameter");
+        }
+        for stmt in body.stmts() {
+            walker.visit_stmt(stmt);
+        }
+    }
+}
+
+/// Walks through a body, keeping track of the local variables declared in each block around the
+/// current statement. Nested functions are skipped, as they are visited as bodies of their own.
+struct ScopeWalker<'a, 'r> {
+    lint: &'a Shadowing,
+    project: &'a Project,
+    config: &'a Config,
+    parameters: &'a [Field],
+    /// The local variables declared in each block around the current statement.
+    scopes: Vec<Vec<(String, Location)>>,
+    reports: &'r mut Vec<Diagnostic<FileId>>,
+}
+impl ScopeWalker<'_, '_> {
+    fn visit_stmt(&mut self, stmt: &Stmt) {
+        match stmt.kind() {
+            StmtKind::LocalVariables(variables) => {
+                for field in variables.declarations.iter() {
+                    if let Some(value) = field.assignment_value() {
+                        self.visit_expr(value);
+                    }
+                    self.declare(field.name_expr());
+                }
+            }
+            StmtKind::Block(_) | StmtKind::For(_) => {
+                self.scopes.push(vec![]);
+                self.visit_children(stmt);
+                self.scopes.pop();
+            }
+            StmtKind::TryCatch(TryCatch {
+                try_body,
+                catch_expr,
+                catch_body,
+                finally_body,
+            }) => {
+                self.visit_stmt(try_body);
+                self.scopes.push(vec![]);
+                self.declare(catch_expr);
+                self.visit_stmt(catch_body);
+                self.scopes.pop();
+                if let Some(finally_body) = finally_body {
+                    self.visit_stmt(finally_body);
+                }
+            }
+            _ => self.visit_children(stmt),
+        }
+    }
+
+    fn visit_children(&mut self, stmt: &Stmt) {
+        stmt.visit_child_stmts(|stmt| self.visit_stmt(stmt));
+        stmt.visit_child_exprs(|expr| self.visit_expr(expr));
+    }
+
+    fn visit_expr(&mut self, expr: &Expr) {
+        if matches!(expr.kind(), ExprKind::Function(_)) {
+            return;
+        }
+        expr.visit_child_stmts(|stmt| self.visit_stmt(stmt));
+        expr.visit_child_exprs(|expr| self.visit_expr(expr));
+    }
+
+    /// Checks a new local variable against everything it could shadow, reporting the first match,
+    /// and then adds it to the current scope.
+    fn declare(&mut self, name_expr: &Expr) {
+        let Some(name) = Self::name(name_expr) else {
+            return;
+        };
+        let rules = self.config.shadowing_rules;
+        let parameter = self
+            .parameters
+            .iter()
+            .map(Field::name_expr)
+            .find(|parameter| Self::name(parameter) == Some(name))
+            .map(|parameter| Location(parameter.file_id(), parameter.span()));
+        // Only the blocks around this one count, as declaring the same local twice in one block
+        // just reassigns it
+        let outer_local = self.scopes[..self.scopes.len() - 1]
+            .iter()
+            .flatten()
+            .find(|(local, _)| local == name)
+            .map(|(_, location)| *location);
+        if let Some(parameter) = parameter.filter(|_| rules.check_parameters) {
+            self.report(
+                name_expr,
+                format!("Local variable `{name}` shadows a parameter"),
+                format!("this hides the parameter `{name}`"),
+                Some((parameter, "the parameter is declared here".into())),
+            );
+        } else if let Some(outer_local) = outer_local.filter(|_| rules.check_locals) {
+            self.report(
+                name_expr,
+                format!("Local variable `{name}` shadows another local variable"),
+                format!("this reuses the `{name}` declared around it"),
+                Some((outer_local, format!("`{name}` is first declared here"))),
+            );
+        } else {
+            self.check_global(name_expr, "Local variable");
+        }
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.push((name.to_string(), Location(name_expr.file_id(), name_expr.span())));
+        }
+    }
+
+    /// Checks a local variable or parameter against the built-in instance and global variables and
+    /// the project's global symbols.
+    fn check_global(&mut self, name_expr: &Expr, kind: &str) {
+        let Some(name) = Self::name(name_expr) else {
+            return;
+        };
+        let rules = self.config.shadowing_rules;
+        if rules.check_instance_variables
+            && self.project.runs_on_instance(name_expr.file_id())
+            && MISC_GML_INSTANCE_VARIABLES.contains(name)
+        {
+            self.report(
+                name_expr,
+                format!("{kind} `{name}` shadows a built-in instance variable"),
+                format!("this hides the instance's own `{name}`"),
+                None,
+            );
+        } else if rules.check_globals && MISC_GML_GLOBAL_VARIABLES.contains(name) {
+            self.report(
+                name_expr,
+                format!("{kind} `{name}` shadows a built-in global variable"),
+                format!("this hides the built-in global `{name}`"),
+                None,
+            );
+        } else if let Some(declaration) = self.project.declarations(name).first().filter(|_| rules.check_globals) {
+            self.report(
+                name_expr,
+                format!("{kind} `{name}` shadows a global"),
+                format!("this hides the global `{name}`"),
+                Some((declaration.location(), format!("`{name}` is declared here"))),
+            );
+        }
+    }
+
+    fn report(&mut self, name_expr: &Expr, message: String, label: String, other: Option<(Location, String)>) {
+        let mut labels = vec![Label::primary(name_expr.file_id(), name_expr.span()).with_message(label)];
+        if let Some((Location(file_id, span), label)) = other {
+            labels.push(Label::secondary(file_id, span).with_message(label));
+        }
+        self.reports.push(
+            self.lint
+                .diagnostic(self.config)
+                .with_message(message)
+                .with_labels(labels),
+        );
+    }
+
+    fn name(expr: &Expr) -> Option<&str> {
+        expr.kind().as_identifier().map(|identifier| identifier.lexeme.as_str())
+    }
+}
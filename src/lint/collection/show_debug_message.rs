@@ -2,33 +2,31 @@ use codespan_reporting::diagnostic::{Diagnostic, Label};
 
 use crate::{
     FileId,
-    lint::{EarlyExprPass, Lint, LintLevel},
+    lint::{Lint, LintLevel},
     parse::{Call, Expr, ExprKind},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Default)]
 pub struct ShowDebugMessage;
 impl Lint for ShowDebugMessage {
-    fn explanation() -> &'static str {
+    fn explanation(&self) -> &'static str {
         "Projects often implement their own logging framework and wish to avoid unwrapped prints to the console."
     }
 
-    fn default_level() -> LintLevel {
+    fn default_level(&self) -> LintLevel {
         LintLevel::Allow
     }
 
-    fn tag() -> &'static str {
+    fn tag(&self) -> &'static str {
         "show_debug_message"
     }
-}
 
-impl EarlyExprPass for ShowDebugMessage {
-    fn visit_expr_early(expr: &Expr, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
+    fn visit_expr_early(&self, expr: &Expr, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
         if let ExprKind::Call(Call { left, .. }) = expr.kind() {
             if let ExprKind::Identifier(identifier) = left.kind() {
                 if identifier.lexeme == "show_debug_message" {
                     reports.push(
-                        Self::diagnostic(config)
+                        self.diagnostic(config)
                             .with_message("Use of `show_debug_message`")
                             .with_labels(vec![
                                 Label::primary(left.file_id(), left.span())
@@ -2,28 +2,26 @@ use codespan_reporting::diagnostic::{Diagnostic, Label};
 
 use crate::{
     Config, FileId,
-    lint::{EarlyExprPass, Lint, LintLevel},
+    lint::{Lint, LintLevel},
     parse::{Equality, EqualityOp, Expr, ExprKind, Token, TokenKind},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Default)]
 pub struct SingleEqualsComparison;
 impl Lint for SingleEqualsComparison {
-    fn explanation() -> &'static str {
+    fn explanation(&self) -> &'static str {
         "The single-equals token can be used for both assignments and equalities in gml. This is atypical of most languages, and can lead to inconsistancies or bugs in projects."
     }
 
-    fn default_level() -> LintLevel {
+    fn default_level(&self) -> LintLevel {
         LintLevel::Warn
     }
 
-    fn tag() -> &'static str {
+    fn tag(&self) -> &'static str {
         "single_equals_comparison"
     }
-}
 
-impl EarlyExprPass for SingleEqualsComparison {
-    fn visit_expr_early(expr: &Expr, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+    fn visit_expr_early(&self, expr: &Expr, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
         if let ExprKind::Equality(Equality {
             op:
                 EqualityOp::Equal(Token {
@@ -34,7 +32,7 @@ impl EarlyExprPass for SingleEqualsComparison {
         }) = expr.kind()
         {
             reports.push(
-                Self::diagnostic(config)
+                self.diagnostic(config)
                     .with_message("Comparison with `=`")
                     .with_labels(vec![
                         Label::primary(expr.file_id(), *span).with_message("use `==` instead of `=`"),
@@ -2,28 +2,26 @@ use codespan_reporting::diagnostic::{Diagnostic, Label};
 
 use crate::{
     FileId,
-    lint::{EarlyStmtPass, Lint, LintLevel},
+    lint::{Lint, LintLevel},
     parse::{Stmt, StmtKind, Switch},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Default)]
 pub struct SingleSwitchCase;
 impl Lint for SingleSwitchCase {
-    fn explanation() -> &'static str {
+    fn explanation(&self) -> &'static str {
         "Switch statements that only match on a single element can be reduced to an `if` statement."
     }
 
-    fn default_level() -> LintLevel {
+    fn default_level(&self) -> LintLevel {
         LintLevel::Warn
     }
 
-    fn tag() -> &'static str {
+    fn tag(&self) -> &'static str {
         "single_switch_case"
     }
-}
 
-impl EarlyStmtPass for SingleSwitchCase {
-    fn visit_stmt_early(stmt: &Stmt, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
+    fn visit_stmt_early(&self, stmt: &Stmt, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
         if let StmtKind::Switch(Switch {
             cases, default_case, ..
         }) = stmt.kind()
@@ -31,7 +29,7 @@ impl EarlyStmtPass for SingleSwitchCase {
             if cases.len() == 1 {
                 if default_case.is_some() {
                     reports.push(
-                        Self::diagnostic(config)
+                        self.diagnostic(config)
                             .with_message("Switch statement with single case")
                             .with_labels(vec![
                                 Label::primary(stmt.file_id(), stmt.span())
@@ -40,7 +38,7 @@ impl EarlyStmtPass for SingleSwitchCase {
                     );
                 } else {
                     reports.push(
-                        Self::diagnostic(config)
+                        self.diagnostic(config)
                             .with_message("Switch statement with single case")
                             .with_labels(vec![
                                 Label::primary(stmt.file_id(), stmt.span())
@@ -2,59 +2,43 @@ use codespan_reporting::diagnostic::{Diagnostic, Label};
 
 use crate::{
     FileId,
-    lint::{EarlyExprPass, EarlyStmtPass, Lint, LintLevel},
+    lint::{Lint, LintLevel},
     parse::{
         Assignment, AssignmentOp, Equality, EqualityOp, Evaluation, EvaluationOp, Expr, ExprKind, Literal, Logical,
         Stmt, StmtKind,
     },
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Default)]
 pub struct SuspicousConstantUsage;
 impl Lint for SuspicousConstantUsage {
-    fn explanation() -> &'static str {
+    fn explanation(&self) -> &'static str {
         "Using a constant outside of equalities and direct assignments is likely unintended or misunderstood code."
     }
 
-    fn default_level() -> LintLevel {
+    fn default_level(&self) -> LintLevel {
         LintLevel::Deny
     }
 
-    fn tag() -> &'static str {
+    fn tag(&self) -> &'static str {
         "suspicious_constant_usage"
     }
-}
-
-impl SuspicousConstantUsage {
-    fn report_expr(expr: &Expr, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
-        reports.push(
-            Self::diagnostic(config)
-                .with_message("Suspicious constant usage")
-                .with_labels(vec![
-                    Label::primary(expr.file_id(), expr.span()).with_message("using this operator..."),
-                    Label::primary(expr.file_id(), expr.span())
-                        .with_message("...with this literal, which is not a coherent operation"),
-                ]),
-        );
-    }
-}
 
-impl EarlyExprPass for SuspicousConstantUsage {
-    fn visit_expr_early(expr: &Expr, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
+    fn visit_expr_early(&self, expr: &Expr, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
         match expr.kind() {
             ExprKind::Evaluation(Evaluation {
                 op: operator, right, ..
             }) => {
                 if let Some(literal) = right.kind().as_literal() {
                     if literal_is_suspicous(literal, OpWrapper::Evaluation(*operator)) {
-                        Self::report_expr(right, config, reports);
+                        self.report_expr(right, config, reports);
                     }
                 }
             }
             ExprKind::Logical(Logical { op: _, right, .. }) => {
                 if let Some(literal) = right.kind().as_literal() {
                     if literal_is_suspicous(literal, OpWrapper::Logical) {
-                        Self::report_expr(right, config, reports);
+                        self.report_expr(right, config, reports);
                     }
                 }
             }
@@ -63,16 +47,15 @@ impl EarlyExprPass for SuspicousConstantUsage {
             }) => {
                 if let Some(literal) = right.kind().as_literal() {
                     if literal_is_suspicous(literal, OpWrapper::Equality(*operator)) {
-                        Self::report_expr(right, config, reports);
+                        self.report_expr(right, config, reports);
                     }
                 }
             }
             _ => {}
         }
     }
-}
-impl EarlyStmtPass for SuspicousConstantUsage {
-    fn visit_stmt_early(stmt: &Stmt, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
+
+    fn visit_stmt_early(&self, stmt: &Stmt, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
         if let StmtKind::Assignment(Assignment {
             op: operator, right, ..
         }) = stmt.kind()
@@ -83,7 +66,7 @@ impl EarlyStmtPass for SuspicousConstantUsage {
             ) {
                 if let Some(literal) = right.kind().as_literal() {
                     if literal_is_suspicous(literal, OpWrapper::Assignment(*operator)) {
-                        Self::report_expr(right, config, reports);
+                        self.report_expr(right, config, reports);
                     }
                 }
             }
@@ -91,6 +74,20 @@ impl EarlyStmtPass for SuspicousConstantUsage {
     }
 }
 
+impl SuspicousConstantUsage {
+    fn report_expr(&self, expr: &Expr, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
+        reports.push(
+            self.diagnostic(config)
+                .with_message("Suspicious constant usage")
+                .with_labels(vec![
+                    Label::primary(expr.file_id(), expr.span()).with_message("using this operator..."),
+                    Label::primary(expr.file_id(), expr.span())
+                        .with_message("...with this literal, which is not a coherent operation"),
+                ]),
+        );
+    }
+}
+
 fn literal_is_suspicous(literal: &Literal, operation_wrapper: OpWrapper) -> bool {
     match operation_wrapper {
         OpWrapper::Assignment(AssignmentOp::Identity(_)) => return false,
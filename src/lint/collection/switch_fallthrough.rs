@@ -0,0 +1,66 @@
+use codespan_reporting::diagnostic::{Diagnostic, Label};
+
+use crate::{
+    Config, FileId,
+    analyze::ControlFlowGraph,
+    lint::{Lint, LintLevel},
+    parse::{Stmt, StmtKind, Switch},
+};
+
+#[derive(Debug, PartialEq, Default)]
+pub struct SwitchFallthrough;
+impl Lint for SwitchFallthrough {
+    fn explanation(&self) -> &'static str {
+        "A case that doesn't end in `break`, `return`, `exit`, `throw` or `continue` carries on into the next case, which is easy to do by accident. Cases with no body are meant to share the next case's body and are ignored, and an intended fallthrough can be marked with a `// fallthrough` comment at the end of the case."
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warn
+    }
+
+    fn tag(&self) -> &'static str {
+        "switch_fallthrough"
+    }
+
+    fn visit_stmt_early(&self, stmt: &Stmt, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+        let StmtKind::Switch(Switch {
+            cases, default_case, ..
+        }) = stmt.kind()
+        else {
+            return;
+        };
+        for (index, case) in cases.iter().enumerate() {
+            // The last case only falls into something if there's a default case after it
+            let next = match cases.get(index + 1) {
+                Some(next) => format!("`case {}`", next.identity()),
+                None if default_case.is_some() => "`default`".into(),
+                None => continue,
+            };
+            let Some(last) = case.body().last() else {
+                continue;
+            };
+            if case.has_fallthrough_comment() {
+                continue;
+            }
+            // Breaks that aren't inside a loop leave a case body's graph as if they were returns,
+            // so the end of the body is only reachable if the case falls through
+            let cfg = ControlFlowGraph::new(case.body());
+            if !cfg.is_reachable(cfg.end()) {
+                continue;
+            }
+            reports.push(
+                self.diagnostic(config)
+                    .with_message("Switch case falls through")
+                    .with_labels(vec![
+                        Label::primary(last.file_id(), last.span())
+                            .with_message(format!("after this, the case carries on into {next}")),
+                        Label::secondary(case.identity().file_id(), case.identity().span())
+                            .with_message("in this case"),
+                    ])
+                    .with_notes(vec![
+                        "add a `break`, or a `// fallthrough` comment if this is intended".into(),
+                    ]),
+            );
+        }
+    }
+}
@@ -2,32 +2,30 @@ use codespan_reporting::diagnostic::{Diagnostic, Label};
 
 use crate::{
     FileId,
-    lint::{EarlyStmtPass, Lint, LintLevel},
+    lint::{Lint, LintLevel},
     parse::{Stmt, StmtKind, Switch},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Default)]
 pub struct SwitchWithoutCase;
 impl Lint for SwitchWithoutCase {
-    fn explanation() -> &'static str {
+    fn explanation(&self) -> &'static str {
         "A switch statement is unncessary if it contains now cases."
     }
 
-    fn default_level() -> LintLevel {
+    fn default_level(&self) -> LintLevel {
         LintLevel::Warn
     }
 
-    fn tag() -> &'static str {
+    fn tag(&self) -> &'static str {
         "switch_without_case"
     }
-}
 
-impl EarlyStmtPass for SwitchWithoutCase {
-    fn visit_stmt_early(stmt: &Stmt, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
+    fn visit_stmt_early(&self, stmt: &Stmt, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
         if let StmtKind::Switch(Switch { cases, .. }) = stmt.kind() {
             if cases.is_empty() {
                 reports.push(
-                    Self::diagnostic(config)
+                    self.diagnostic(config)
                         .with_message("Switch statement with no cases")
                         .with_labels(vec![
                             Label::primary(stmt.file_id(), stmt.span()).with_message("Remove this switch statement"),
@@ -0,0 +1,38 @@
+use codespan_reporting::diagnostic::{Diagnostic, Label};
+
+use crate::{
+    Config, FileId,
+    analyze::{Body, Project},
+    lint::{Lint, LintLevel},
+    parse::Location,
+};
+
+#[derive(Debug, PartialEq, Default)]
+pub struct TooComplex;
+impl Lint for TooComplex {
+    fn explanation(&self) -> &'static str {
+        "Functions and events with many branches, loops and conditions have so many paths through them that they become hard to follow and to test, and should be broken up into smaller functions. The limit is set by the `max_complexity` config option."
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warn
+    }
+
+    fn tag(&self) -> &'static str {
+        "too_complex"
+    }
+
+    fn visit_body_late(&self, body: &Body, _project: &Project, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+        let complexity = body.metrics().cyclomatic_complexity();
+        let Some(Location(file_id, span)) = body.location().filter(|_| complexity > config.max_complexity) else {
+            return;
+        };
+        reports.push(self.diagnostic(config).with_message("Too complex").with_labels(vec![
+            Label::primary(file_id, span).with_message(format!(
+                "{} has a cyclomatic complexity of {complexity}, but the maximum is set to {}",
+                body.describe(),
+                config.max_complexity
+            )),
+        ]));
+    }
+}
@@ -0,0 +1,44 @@
+use codespan_reporting::diagnostic::{Diagnostic, Label};
+
+use crate::{
+    Config, FileId,
+    analyze::{Body, Project},
+    lint::{Lint, LintLevel},
+    parse::Location,
+};
+
+#[derive(Debug, PartialEq, Default)]
+pub struct TooDeeplyNested;
+impl Lint for TooDeeplyNested {
+    fn explanation(&self) -> &'static str {
+        "Code nested within many branches and loops is hard to read, as every level adds another condition to keep in mind. Returning early or moving the inner code into its own function keeps things flat. The limit is set by the `max_nesting_depth` config option."
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warn
+    }
+
+    fn tag(&self) -> &'static str {
+        "too_deeply_nested"
+    }
+
+    fn visit_body_late(&self, body: &Body, _project: &Project, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+        let metrics = body.metrics();
+        let Some(Location(file_id, span)) = metrics
+            .deepest()
+            .filter(|_| metrics.nesting_depth() > config.max_nesting_depth)
+        else {
+            return;
+        };
+        reports.push(
+            self.diagnostic(config)
+                .with_message("Too deeply nested")
+                .with_labels(vec![Label::primary(file_id, span).with_message(format!(
+                    "this is nested {} levels deep within {}, but the maximum is set to {}",
+                    metrics.nesting_depth(),
+                    body.describe(),
+                    config.max_nesting_depth
+                ))]),
+        );
+    }
+}
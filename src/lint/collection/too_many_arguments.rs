@@ -2,28 +2,26 @@ use codespan_reporting::diagnostic::{Diagnostic, Label};
 
 use crate::{
     Config, FileId,
-    lint::{EarlyExprPass, Lint, LintLevel},
+    lint::{Lint, LintLevel},
     parse::{Expr, ExprKind, Field, Function},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Default)]
 pub struct TooManyArguments;
 impl Lint for TooManyArguments {
-    fn explanation() -> &'static str {
+    fn explanation(&self) -> &'static str {
         "Functions with lots of parameters quickly become confusing and indicate a need for structural change."
     }
 
-    fn default_level() -> LintLevel {
+    fn default_level(&self) -> LintLevel {
         LintLevel::Warn
     }
 
-    fn tag() -> &'static str {
+    fn tag(&self) -> &'static str {
         "too_many_arguments"
     }
-}
 
-impl EarlyExprPass for TooManyArguments {
-    fn visit_expr_early(expr: &Expr, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+    fn visit_expr_early(&self, expr: &Expr, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
         if let ExprKind::Function(Function { parameters, .. }) = expr.kind() {
             if parameters.len() > config.max_arguments {
                 let start = parameters.first().unwrap().name_expr().span().start();
@@ -32,7 +30,7 @@ impl EarlyExprPass for TooManyArguments {
                     Field::Initialized(stmt) => stmt.span().end(),
                 };
                 reports.push(
-                    Self::diagnostic(config)
+                    self.diagnostic(config)
                         .with_message("Too many arguments")
                         .with_labels(vec![Label::primary(expr.file_id(), start..end).with_message(format!(
                             "using {} arguments, but the maximum is set to {}",
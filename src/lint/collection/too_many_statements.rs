@@ -0,0 +1,40 @@
+use codespan_reporting::diagnostic::{Diagnostic, Label};
+
+use crate::{
+    Config, FileId,
+    analyze::{Body, Project},
+    lint::{Lint, LintLevel},
+    parse::Location,
+};
+
+#[derive(Debug, PartialEq, Default)]
+pub struct TooManyStatements;
+impl Lint for TooManyStatements {
+    fn explanation(&self) -> &'static str {
+        "Long functions and events tend to do many things at once, which makes them hard to understand and to reuse. Splitting them into smaller functions with clear names helps. The limit is set by the `max_statements` config option."
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warn
+    }
+
+    fn tag(&self) -> &'static str {
+        "too_many_statements"
+    }
+
+    fn visit_body_late(&self, body: &Body, _project: &Project, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+        let count = body.metrics().statement_count();
+        let Some(Location(file_id, span)) = body.location().filter(|_| count > config.max_statements) else {
+            return;
+        };
+        reports.push(
+            self.diagnostic(config)
+                .with_message("Too many statements")
+                .with_labels(vec![Label::primary(file_id, span).with_message(format!(
+                    "{} has {count} statements, but the maximum is set to {}",
+                    body.describe(),
+                    config.max_statements
+                ))]),
+        );
+    }
+}
@@ -2,31 +2,29 @@ use codespan_reporting::diagnostic::{Diagnostic, Label};
 
 use crate::{
     FileId,
-    lint::{EarlyStmtPass, Lint, LintLevel},
+    lint::{Lint, LintLevel},
     parse::{Stmt, StmtKind},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Default)]
 pub struct TryCatch;
 impl Lint for TryCatch {
-    fn explanation() -> &'static str {
+    fn explanation(&self) -> &'static str {
         "GML's try/catch will collect all errors as opposed to the precise ones wanted, allowing them to accidently catch errors that should not be surpressed."
     }
 
-    fn default_level() -> LintLevel {
+    fn default_level(&self) -> LintLevel {
         LintLevel::Allow
     }
 
-    fn tag() -> &'static str {
+    fn tag(&self) -> &'static str {
         "try_catch"
     }
-}
 
-impl EarlyStmtPass for TryCatch {
-    fn visit_stmt_early(stmt: &Stmt, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
+    fn visit_stmt_early(&self, stmt: &Stmt, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
         if let StmtKind::TryCatch(..) = stmt.kind() {
             reports.push(
-                Self::diagnostic(config)
+                self.diagnostic(config)
                     .with_message("Use of `try` / `catch`")
                     .with_labels(vec![Label::primary(stmt.file_id(), stmt.span())]),
             );
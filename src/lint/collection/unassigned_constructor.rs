@@ -1,32 +1,30 @@
 use crate::{
     Config, FileId,
-    lint::{EarlyStmtPass, Lint, LintLevel},
+    lint::{Lint, LintLevel},
     parse::{Call, ExprKind, Stmt, StmtKind},
 };
 use codespan_reporting::diagnostic::{Diagnostic, Label};
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Default)]
 pub struct UnassignedConstructor;
 impl Lint for UnassignedConstructor {
-    fn explanation() -> &'static str {
+    fn explanation(&self) -> &'static str {
         "Invoking a constructor function without saving the new struct is often a mistake. If the constructor is saving a refernce of itself within its own declaration, this should still be given a wrapper function so that the behavior is not hidden. Avoiding this as an intentional pattern allows this lint to better alert you to mistakes."
     }
 
-    fn default_level() -> LintLevel {
+    fn default_level(&self) -> LintLevel {
         LintLevel::Warn
     }
 
-    fn tag() -> &'static str {
+    fn tag(&self) -> &'static str {
         "unassigned_constructor"
     }
-}
 
-impl EarlyStmtPass for UnassignedConstructor {
-    fn visit_stmt_early(stmt: &Stmt, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+    fn visit_stmt_early(&self, stmt: &Stmt, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
         if let StmtKind::Expr(expr) = stmt.kind() {
             if let ExprKind::Call(Call { uses_new: true, .. }) = expr.kind() {
                 reports.push(
-                    Self::diagnostic(config)
+                    self.diagnostic(config)
                         .with_message("Unassigned constructor")
                         .with_labels(vec![
                             Label::primary(expr.file_id(), expr.span())
@@ -0,0 +1,162 @@
+use codespan_reporting::diagnostic::{Diagnostic, Label};
+use hashbrown::{HashMap, HashSet};
+
+use crate::{
+    Config, FileId,
+    analyze::{Body, Project, Step},
+    lint::{Lint, LintLevel},
+    parse::*,
+};
+
+#[derive(Debug, PartialEq, Default)]
+pub struct UninitializedLocalVariable;
+impl Lint for UninitializedLocalVariable {
+    fn explanation(&self) -> &'static str {
+        "Local variables declared without a value (`var foo;`) must be assigned before they are read, or GameMaker will crash. When a variable is only assigned on some paths (such as within an if statement), it is easy to miss the paths where it is read without a value."
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warn
+    }
+
+    fn tag(&self) -> &'static str {
+        "uninitialized_local_variable"
+    }
+
+    fn visit_body_late(&self, body: &Body, _project: &Project, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+        let cfg = body.cfg();
+        let mut declarations = HashMap::new();
+        for (_, block) in cfg.blocks() {
+            for step in block.steps() {
+                if let StmtKind::LocalVariables(LocalVariables { declarations: fields }) = step.stmt().kind() {
+                    for field in fields.iter().filter(|field| field.assignment_value().is_none()) {
+                        declarations.insert(field.name().to_string(), field.name_expr().location());
+                    }
+                }
+            }
+        }
+        if declarations.is_empty() {
+            return;
+        }
+
+        // Find the variables that may be unassigned at the start of each block...
+        let block_count = cfg.blocks().count();
+        let mut unassigned: Vec<Option<Unassigned>> = vec![None; block_count];
+        unassigned[cfg.entry().index()] = Some(Unassigned::default());
+        let mut worklist = vec![cfg.entry()];
+        while let Some(id) = worklist.pop() {
+            let mut state = unassigned[id.index()].clone().unwrap_or_default();
+            for step in cfg.block(id).steps() {
+                transfer(step, &mut state, &mut |_, _| {});
+            }
+            for successor in cfg.block(id).successors() {
+                match &mut unassigned[successor.index()] {
+                    Some(entry) => {
+                        let previous_len = entry.len();
+                        entry.extend(state.iter().cloned());
+                        if entry.len() != previous_len {
+                            worklist.push(*successor);
+                        }
+                    }
+                    entry => {
+                        *entry = Some(state.clone());
+                        worklist.push(*successor);
+                    }
+                }
+            }
+        }
+
+        // ...and report every read that happens while they may be
+        for (id, block) in cfg.blocks() {
+            let Some(mut state) = unassigned[id.index()].clone() else {
+                continue;
+            };
+            for step in block.steps() {
+                transfer(step, &mut state, &mut |name, expr| {
+                    let Location(file_id, span) = expr.location();
+                    let mut labels = vec![
+                        Label::primary(file_id, span)
+                            .with_message(format!("`{name}` may not have been assigned a value yet")),
+                    ];
+                    if let Some(Location(file_id, span)) = declarations.get(name) {
+                        labels.push(Label::secondary(*file_id, *span).with_message("declared here without a value"));
+                    }
+                    reports.push(
+                        self.diagnostic(config)
+                            .with_message(format!("Use of possibly uninitialized local variable `{name}`"))
+                            .with_labels(labels),
+                    );
+                });
+            }
+        }
+    }
+}
+
+/// The names of the local variables that may not have been assigned a value.
+type Unassigned = HashSet<String>;
+
+/// Updates the unassigned variables with the effects of the step, calling `on_read` for every
+/// unassigned variable it reads. A variable that was read is considered assigned afterwards, as
+/// the game would have crashed otherwise.
+fn transfer(step: &Step, state: &mut Unassigned, on_read: &mut dyn FnMut(&str, &Expr)) {
+    let stmt = match step {
+        Step::Branch(_, expr) => return read(expr, state, on_read),
+        Step::Stmt(stmt) => stmt,
+    };
+    match stmt.kind() {
+        StmtKind::LocalVariables(LocalVariables { declarations }) => {
+            for field in declarations.iter() {
+                match field.assignment_value() {
+                    Some(value) => {
+                        read(value, state, on_read);
+                        state.remove(field.name());
+                    }
+                    None => {
+                        state.insert(field.name().to_string());
+                    }
+                }
+            }
+        }
+        StmtKind::Assignment(Assignment { left, op, right }) => {
+            read(right, state, on_read);
+            let is_identity = matches!(op, AssignmentOp::Identity(_));
+            match left.kind() {
+                ExprKind::Identifier(identifier) => {
+                    if !is_identity {
+                        read(left, state, on_read);
+                    }
+                    state.remove(identifier.lexeme.as_str());
+                }
+                // Assigning to an index of an unassigned variable creates a new array
+                ExprKind::Access(Access::Array {
+                    left: array,
+                    index_one,
+                    index_two,
+                    ..
+                }) if is_identity && array.kind().as_identifier().is_some() => {
+                    read(index_one, state, on_read);
+                    if let Some(index_two) = index_two {
+                        read(index_two, state, on_read);
+                    }
+                    state.remove(array.kind().as_identifier().unwrap().lexeme.as_str());
+                }
+                _ => read(left, state, on_read),
+            }
+        }
+        _ => stmt.visit_child_exprs(|expr| read(expr, state, on_read)),
+    }
+}
+
+/// Calls `on_read` for every unassigned variable read within the expression, without entering the
+/// bodies of functions (which can't access the local variables around them).
+fn read(expr: &Expr, state: &mut Unassigned, on_read: &mut dyn FnMut(&str, &Expr)) {
+    match expr.kind() {
+        ExprKind::Function(_) => {}
+        ExprKind::Identifier(identifier) => {
+            if state.remove(identifier.lexeme.as_str()) {
+                on_read(&identifier.lexeme, expr);
+            }
+        }
+        _ => expr.visit_child_exprs(|expr| read(expr, state, on_read)),
+    }
+}
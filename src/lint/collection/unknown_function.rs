@@ -0,0 +1,93 @@
+use codespan_reporting::diagnostic::{Diagnostic, Label};
+
+use crate::{
+    Config, FileId,
+    analyze::{Body, GML_FUNCTIONS, Project},
+    lint::{Lint, LintLevel},
+    parse::{Call, Expr, ExprKind, MISC_GML_CONSTANTS, MISC_GML_GLOBAL_VARIABLES, MISC_GML_INSTANCE_VARIABLES},
+};
+
+#[derive(Debug, PartialEq, Default)]
+pub struct UnknownFunction;
+impl Lint for UnknownFunction {
+    fn explanation(&self) -> &'static str {
+        "A call to a function that is not declared anywhere in the project, but whose name is a small typo away from one of GameMaker's built-in functions, will crash the game when it is reached. Names that are local variables, parameters, or variables assigned in the same script or object are assumed to hold methods, and are never reported."
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warn
+    }
+
+    fn tag(&self) -> &'static str {
+        "unknown_function"
+    }
+
+    fn visit_expr_late(
+        &self,
+        expr: &Expr,
+        body: &Body,
+        project: &Project,
+        config: &Config,
+        reports: &mut Vec<Diagnostic<FileId>>,
+    ) {
+        let ExprKind::Call(Call { left, .. }) = expr.kind() else {
+            return;
+        };
+        let Some(callee) = left.kind().as_identifier() else {
+            return;
+        };
+        let name = callee.lexeme.as_str();
+        if GML_FUNCTIONS.contains_key(name)
+            || !project.declarations(name).is_empty()
+            || MISC_GML_CONSTANTS.contains(name)
+            || MISC_GML_INSTANCE_VARIABLES.contains(name)
+            || MISC_GML_GLOBAL_VARIABLES.contains(name)
+            || body.is_local(name)
+            || project.is_assigned(left.file_id(), name)
+        {
+            return;
+        }
+        let Some(suggestion) = Self::closest_builtin(name) else {
+            return;
+        };
+        reports.push(
+            self.diagnostic(config)
+                .with_message(format!("Unknown function `{name}`"))
+                .with_labels(vec![
+                    Label::primary(left.file_id(), left.span()).with_message(format!("did you mean `{suggestion}`?")),
+                ]),
+        );
+    }
+}
+
+impl UnknownFunction {
+    /// Returns the built-in function that shares the name's prefix (ie: `draw` in `draw_sprite`)
+    /// and is the fewest edits away from it, as long as it is close enough to be a typo.
+    fn closest_builtin(name: &str) -> Option<&'static str> {
+        let prefix = name.split('_').next()?;
+        let allowed = if name.len() > 8 { 2 } else { 1 };
+        GML_FUNCTIONS
+            .keys()
+            .filter(|builtin| builtin.split('_').next() == Some(prefix))
+            .map(|builtin| (Self::distance(name, builtin), builtin.as_str()))
+            .filter(|(distance, _)| *distance <= allowed)
+            .min()
+            .map(|(_, builtin)| builtin)
+    }
+
+    /// Returns the number of single character insertions, deletions and substitutions needed to
+    /// turn one string into the other.
+    fn distance(a: &str, b: &str) -> usize {
+        let b: Vec<char> = b.chars().collect();
+        let mut previous: Vec<usize> = (0..=b.len()).collect();
+        for (i, a_char) in a.chars().enumerate() {
+            let mut current = vec![i + 1];
+            for (j, b_char) in b.iter().enumerate() {
+                let substitution = previous[j] + usize::from(a_char != *b_char);
+                current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+            }
+            previous = current;
+        }
+        previous[b.len()]
+    }
+}
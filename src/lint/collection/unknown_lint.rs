@@ -0,0 +1,19 @@
+use crate::lint::{Lint, LintLevel};
+
+/// Unlike most lints, this one is run directly by the driver, as it needs to know every lint that
+/// has been registered. See `driver::process_unknown_lints`.
+#[derive(Debug, PartialEq, Default)]
+pub struct UnknownLint;
+impl Lint for UnknownLint {
+    fn explanation(&self) -> &'static str {
+        "Tags that name lints which do not exist (often due to a typo) silently do nothing, leaving the code they were meant to affect unchanged."
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warn
+    }
+
+    fn tag(&self) -> &'static str {
+        "unknown_lint"
+    }
+}
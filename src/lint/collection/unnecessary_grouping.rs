@@ -1,44 +1,26 @@
 use crate::{
     FileId,
-    lint::{EarlyExprPass, EarlyStmtPass, Lint, LintLevel},
+    lint::{Lint, LintLevel},
     parse::{Expr, ExprKind, ParseVisitor, Stmt, StmtKind},
 };
 use codespan_reporting::diagnostic::{Diagnostic, Label};
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Default)]
 pub struct UnnecessaryGrouping;
 impl Lint for UnnecessaryGrouping {
-    fn explanation() -> &'static str {
+    fn explanation(&self) -> &'static str {
         "Parenthesis around an expression that do not change how the logic is executed are redundant and can be removed."
     }
 
-    fn default_level() -> LintLevel {
+    fn default_level(&self) -> LintLevel {
         LintLevel::Warn
     }
 
-    fn tag() -> &'static str {
+    fn tag(&self) -> &'static str {
         "unnecessary_grouping"
     }
-}
-
-impl UnnecessaryGrouping {
-    fn test(expr: &Expr, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
-        if let ExprKind::Grouping(grouping) = expr.kind() {
-            let (left_token, right_token) = grouping.parenthesis();
-            reports.push(
-                Self::diagnostic(config)
-                    .with_message("Unnecessary grouping")
-                    .with_labels(vec![
-                        Label::primary(expr.file_id(), left_token.span),
-                        Label::primary(expr.file_id(), right_token.span),
-                    ]),
-            );
-        }
-    }
-}
 
-impl EarlyExprPass for UnnecessaryGrouping {
-    fn visit_expr_early(expr: &Expr, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
+    fn visit_expr_early(&self, expr: &Expr, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
         match expr.kind() {
             // These are the blessed expressions that utilize groupings in meaningful ways
             ExprKind::Logical(_)
@@ -57,13 +39,11 @@ impl EarlyExprPass for UnnecessaryGrouping {
             | ExprKind::Call(_)
             | ExprKind::Grouping(_)
             | ExprKind::Literal(_)
-            | ExprKind::Identifier(_) => expr.visit_child_exprs(|expr| Self::test(expr, config, reports)),
+            | ExprKind::Identifier(_) => expr.visit_child_exprs(|expr| self.test(expr, config, reports)),
         }
     }
-}
 
-impl EarlyStmtPass for UnnecessaryGrouping {
-    fn visit_stmt_early(stmt: &Stmt, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
+    fn visit_stmt_early(&self, stmt: &Stmt, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
         match stmt.kind() {
             // These are a style preference, which instead is linted by `condition_wrapper`.
             StmtKind::TryCatch(_)
@@ -77,9 +57,25 @@ impl EarlyStmtPass for UnnecessaryGrouping {
 
             // These should not directly own groupings
             StmtKind::Return(_) | StmtKind::Throw(_) | StmtKind::Delete(_) | StmtKind::Assignment(_) => {
-                stmt.visit_child_exprs(|expr| Self::test(expr, config, reports))
+                stmt.visit_child_exprs(|expr| self.test(expr, config, reports))
             }
             _ => {}
         };
     }
 }
+
+impl UnnecessaryGrouping {
+    fn test(&self, expr: &Expr, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
+        if let ExprKind::Grouping(grouping) = expr.kind() {
+            let (left_token, right_token) = grouping.parenthesis();
+            reports.push(
+                self.diagnostic(config)
+                    .with_message("Unnecessary grouping")
+                    .with_labels(vec![
+                        Label::primary(expr.file_id(), left_token.span),
+                        Label::primary(expr.file_id(), right_token.span),
+                    ]),
+            );
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use codespan_reporting::diagnostic::{Diagnostic, Label};
+
+use crate::{
+    Config, FileId,
+    analyze::{Body, ControlFlowGraph, Evaluator, Project},
+    lint::{Lint, LintLevel},
+    parse::*,
+};
+
+#[derive(Debug, PartialEq, Default)]
+pub struct UnreachableCode;
+impl Lint for UnreachableCode {
+    fn explanation(&self) -> &'static str {
+        "Code that follows a `return`, `exit`, `throw`, `break` or `continue`, or that sits behind a condition that is always false, will never run. This is almost always a mistake, and can hide bugs where code was expected to run. Macros, enums and named functions are declared before the game runs, so they are fine anywhere."
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warn
+    }
+
+    fn tag(&self) -> &'static str {
+        "unreachable_code"
+    }
+
+    fn visit_body_late(&self, body: &Body, _project: &Project, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+        self.visit_stmts(body.stmts(), body.cfg(), config, reports);
+    }
+}
+
+impl UnreachableCode {
+    /// Reports the statements following the first one that control flow can't continue past, and
+    /// then checks the statements before it.
+    fn visit_stmts(
+        &self,
+        stmts: &[Stmt],
+        cfg: &ControlFlowGraph,
+        config: &Config,
+        reports: &mut Vec<Diagnostic<FileId>>,
+    ) {
+        for (index, stmt) in stmts.iter().enumerate() {
+            if !Self::is_reachable(stmt, cfg) {
+                let dead: Vec<&Stmt> = stmts[index..].iter().filter(|stmt| !Self::is_hoisted(stmt)).collect();
+                // `break` after a `return` in a switch case is a common (and harmless) habit
+                if index != 0 && dead.iter().any(|stmt| !matches!(stmt.kind(), StmtKind::Break)) {
+                    let previous = &stmts[index - 1];
+                    let start = dead[0].span().start();
+                    let end = dead.last().unwrap().span().end();
+                    reports.push(
+                        self.diagnostic(config)
+                            .with_message("Unreachable code")
+                            .with_labels(vec![
+                                Label::primary(stmt.file_id(), start..end).with_message("this code will never run"),
+                                Label::secondary(previous.file_id(), previous.span())
+                                    .with_message("any code after this will never run"),
+                            ]),
+                    );
+                }
+                return;
+            }
+            self.visit_stmt(stmt, cfg, config, reports);
+        }
+    }
+
+    fn visit_stmt(&self, stmt: &Stmt, cfg: &ControlFlowGraph, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+        match stmt.kind() {
+            StmtKind::Block(block) => self.visit_stmts(&block.body, cfg, config, reports),
+            StmtKind::If(If {
+                condition,
+                body,
+                else_stmt,
+                ..
+            }) => {
+                self.visit_branch(body, condition, true, cfg, config, reports);
+                if let Some(else_stmt) = else_stmt {
+                    self.visit_branch(else_stmt, condition, false, cfg, config, reports);
+                }
+            }
+            StmtKind::While(While { condition, body }) | StmtKind::For(For { condition, body, .. }) => {
+                self.visit_branch(body, condition, true, cfg, config, reports)
+            }
+            StmtKind::DoUntil(DoUntil { body, .. })
+            | StmtKind::Repeat(Repeat { body, .. })
+            | StmtKind::With(With { body, .. }) => self.visit_stmt(body, cfg, config, reports),
+            StmtKind::Switch(switch) => {
+                for case in switch.cases() {
+                    self.visit_stmts(case.body(), cfg, config, reports);
+                }
+                if let Some(default_case) = switch.default_case() {
+                    self.visit_stmts(default_case, cfg, config, reports);
+                }
+            }
+            StmtKind::TryCatch(TryCatch {
+                try_body,
+                catch_body,
+                finally_body,
+                ..
+            }) => {
+                self.visit_stmt(try_body, cfg, config, reports);
+                self.visit_stmt(catch_body, cfg, config, reports);
+                if let Some(finally_body) = finally_body {
+                    self.visit_stmt(finally_body, cfg, config, reports);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Reports the branch if its condition always has the other value than the one it runs on, and
+    /// otherwise checks the statements within it.
+    fn visit_branch(
+        &self,
+        branch: &Stmt,
+        condition: &Expr,
+        runs_on: bool,
+        cfg: &ControlFlowGraph,
+        config: &Config,
+        reports: &mut Vec<Diagnostic<FileId>>,
+    ) {
+        match Self::condition_value(condition) {
+            Some(value) if value != runs_on => reports.push(
+                self.diagnostic(config)
+                    .with_message("Unreachable code")
+                    .with_labels(vec![
+                        Label::primary(branch.file_id(), branch.span()).with_message("this code will never run"),
+                        Label::secondary(condition.file_id(), condition.span())
+                            .with_message(format!("this condition is always {value}")),
+                    ]),
+            ),
+            _ => self.visit_stmt(branch, cfg, config, reports),
+        }
+    }
+
+    /// Returns the value of a condition that is constant, and so only ever takes one branch.
+    /// Macros are not resolved, as they are expected to change between configurations.
+    fn condition_value(condition: &Expr) -> Option<bool> {
+        Evaluator::new().evaluate(condition)?.as_bool()
+    }
+
+    /// Returns whether the statement is declared at compile time (macros, enums and named
+    /// functions), making its position in the code irrelevant.
+    fn is_hoisted(stmt: &Stmt) -> bool {
+        match stmt.kind() {
+            StmtKind::Macro(_) | StmtKind::Enum(_) => true,
+            StmtKind::Expr(expr) => expr
+                .kind()
+                .as_function()
+                .is_some_and(|function| function.name.is_some()),
+            _ => false,
+        }
+    }
+
+    fn is_reachable(stmt: &Stmt, cfg: &ControlFlowGraph) -> bool {
+        cfg.block_of(stmt).is_some_and(|block| cfg.is_reachable(block))
+    }
+}
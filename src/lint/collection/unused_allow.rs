@@ -0,0 +1,19 @@
+use crate::lint::{Lint, LintLevel};
+
+/// Unlike most lints, this one is run directly by the driver, as it needs to know what every other
+/// lint would have reported. See `driver::process_unused_allows`.
+#[derive(Debug, PartialEq, Default)]
+pub struct UnusedAllow;
+impl Lint for UnusedAllow {
+    fn explanation(&self) -> &'static str {
+        "Allow tags that no longer suppress anything are clutter, and can hide new issues if the code they cover changes later on."
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Allow
+    }
+
+    fn tag(&self) -> &'static str {
+        "unused_allow"
+    }
+}
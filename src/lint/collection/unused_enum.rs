@@ -0,0 +1,43 @@
+use codespan_reporting::diagnostic::{Diagnostic, Label};
+
+use crate::{
+    Config, FileId,
+    analyze::{DeclarationKind, Project},
+    lint::{Lint, LintLevel},
+    parse::{Stmt, StmtKind},
+};
+
+#[derive(Debug, PartialEq, Default)]
+pub struct UnusedEnum;
+impl Lint for UnusedEnum {
+    fn explanation(&self) -> &'static str {
+        "Enums that are never referenced anywhere in the project are dead code."
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Allow
+    }
+
+    fn tag(&self) -> &'static str {
+        "unused_enum"
+    }
+
+    fn visit_stmt_late(&self, stmt: &Stmt, project: &Project, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+        let StmtKind::Enum(gml_enum) = stmt.kind() else {
+            return;
+        };
+        if project
+            .find(stmt.file_id(), &gml_enum.name.lexeme, DeclarationKind::Enum)
+            .is_some_and(|declaration| project.is_unused(declaration, config))
+        {
+            reports.push(
+                self.diagnostic(config)
+                    .with_message(format!("Unused enum `{}`", gml_enum.name))
+                    .with_labels(vec![
+                        Label::primary(stmt.file_id(), gml_enum.name.span)
+                            .with_message("this is never used anywhere in the project"),
+                    ]),
+            );
+        }
+    }
+}
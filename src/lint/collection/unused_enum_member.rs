@@ -0,0 +1,53 @@
+use codespan_reporting::diagnostic::{Diagnostic, Label};
+
+use crate::{
+    Config, FileId,
+    analyze::{DeclarationKind, Project},
+    lint::{Lint, LintLevel},
+    parse::{Location, Stmt, StmtKind},
+};
+
+#[derive(Debug, PartialEq, Default)]
+pub struct UnusedEnumMember;
+impl Lint for UnusedEnumMember {
+    fn explanation(&self) -> &'static str {
+        "Enum members that are never referenced anywhere in the project are dead code. Members named after the `length_enum_member_name` config option (such as `Len`) are ignored, as they often exist just to count the others, and unused enums are left to `unused_enum`."
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Allow
+    }
+
+    fn tag(&self) -> &'static str {
+        "unused_enum_member"
+    }
+
+    fn visit_stmt_late(&self, stmt: &Stmt, project: &Project, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+        let StmtKind::Enum(gml_enum) = stmt.kind() else {
+            return;
+        };
+        if !project.is_referenced(&gml_enum.name.lexeme) {
+            return;
+        }
+        for member in gml_enum
+            .members
+            .iter()
+            .filter(|member| member.name() != config.length_enum_member_name)
+        {
+            let name = format!("{}.{}", gml_enum.name.lexeme, member.name());
+            if project
+                .find(stmt.file_id(), &name, DeclarationKind::EnumMember)
+                .is_some_and(|declaration| project.is_unused(declaration, config))
+            {
+                let Location(file_id, span) = member.name_expr().location();
+                reports.push(
+                    self.diagnostic(config)
+                        .with_message(format!("Unused enum member `{name}`"))
+                        .with_labels(vec![
+                            Label::primary(file_id, span).with_message("this is never used anywhere in the project"),
+                        ]),
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use codespan_reporting::diagnostic::{Diagnostic, Label};
+
+use crate::{
+    Config, FileId,
+    analyze::{DeclarationKind, Project},
+    lint::{Lint, LintLevel},
+    parse::{Function, Stmt},
+};
+
+#[derive(Debug, PartialEq, Default)]
+pub struct UnusedFunction;
+impl Lint for UnusedFunction {
+    fn explanation(&self) -> &'static str {
+        "Global functions and constructors that are never referenced anywhere in the project are dead code. Scripts that are called from outside of gml (or by names built at runtime) can be exempted with the `public_api_paths` and `used_symbols` config options."
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Allow
+    }
+
+    fn tag(&self) -> &'static str {
+        "unused_function"
+    }
+
+    fn visit_stmt_late(&self, stmt: &Stmt, project: &Project, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+        let Some(Function {
+            name: Some(name),
+            constructor,
+            ..
+        }) = stmt.kind().as_expr_stmt().and_then(|expr| expr.kind().as_function())
+        else {
+            return;
+        };
+        let (kind, description) = match constructor {
+            Some(_) => (DeclarationKind::Constructor, "constructor"),
+            None => (DeclarationKind::Function, "function"),
+        };
+        if project
+            .find(stmt.file_id(), &name.lexeme, kind)
+            .is_some_and(|declaration| project.is_unused(declaration, config))
+        {
+            reports.push(
+                self.diagnostic(config)
+                    .with_message(format!("Unused {description} `{name}`"))
+                    .with_labels(vec![
+                        Label::primary(stmt.file_id(), name.span)
+                            .with_message("this is never used anywhere in the project"),
+                    ]),
+            );
+        }
+    }
+}
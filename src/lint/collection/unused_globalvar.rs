@@ -0,0 +1,43 @@
+use codespan_reporting::diagnostic::{Diagnostic, Label};
+
+use crate::{
+    Config, FileId,
+    analyze::{DeclarationKind, Project},
+    lint::{Lint, LintLevel},
+    parse::{Globalvar, Stmt, StmtKind},
+};
+
+#[derive(Debug, PartialEq, Default)]
+pub struct UnusedGlobalvar;
+impl Lint for UnusedGlobalvar {
+    fn explanation(&self) -> &'static str {
+        "Variables declared with `globalvar` that are never referenced anywhere in the project (either directly or through `global.`) are dead code."
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Allow
+    }
+
+    fn tag(&self) -> &'static str {
+        "unused_globalvar"
+    }
+
+    fn visit_stmt_late(&self, stmt: &Stmt, project: &Project, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+        let StmtKind::Globalvar(Globalvar { name }) = stmt.kind() else {
+            return;
+        };
+        if project
+            .find(stmt.file_id(), &name.lexeme, DeclarationKind::Globalvar)
+            .is_some_and(|declaration| project.is_unused(declaration, config))
+        {
+            reports.push(
+                self.diagnostic(config)
+                    .with_message(format!("Unused globalvar `{name}`"))
+                    .with_labels(vec![
+                        Label::primary(stmt.file_id(), name.span)
+                            .with_message("this is never used anywhere in the project"),
+                    ]),
+            );
+        }
+    }
+}
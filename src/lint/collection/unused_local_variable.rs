@@ -1,31 +1,29 @@
 use codespan_reporting::diagnostic::{Diagnostic, Label};
 use colored::Colorize;
-use hashbrown::{HashMap, HashSet};
+use hashbrown::HashMap;
 
 use crate::{
     Config, FileId,
-    lint::{AstPass, EarlyExprPass, Lint, LintLevel},
-    parse::{Ast, Block, Expr, ExprKind, For, Function, Identifier, ParseVisitor, Stmt, StmtKind},
+    lint::{Lint, LintLevel},
+    parse::{Ast, Block, Expr, ExprKind, Function, Identifier, ParseVisitor, Stmt, StmtKind},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Default)]
 pub struct UnusedLocalVariable;
 impl Lint for UnusedLocalVariable {
-    fn explanation() -> &'static str {
+    fn explanation(&self) -> &'static str {
         "Unused local variables are at best clutter and at worst the source of hard-to-spot bug."
     }
 
-    fn default_level() -> LintLevel {
+    fn default_level(&self) -> LintLevel {
         LintLevel::Warn
     }
 
-    fn tag() -> &'static str {
+    fn tag(&self) -> &'static str {
         "unused_local_variable"
     }
-}
 
-impl AstPass for UnusedLocalVariable {
-    fn visit_ast(ast: &Ast, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+    fn visit_ast(&self, ast: &Ast, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
         analyze_scope_on_stmts(ast.stmts(), config, reports);
     }
 }
@@ -36,7 +34,8 @@ fn analyze_scope_on_stmts(stmts: &[Stmt], config: &Config, reports: &mut Vec<Dia
 
     for (_, (ident, file_id)) in scope.iter() {
         reports.push(
-            UnusedLocalVariable::diagnostic(config)
+            UnusedLocalVariable
+                .diagnostic(config)
                 .with_message("Unused local variable")
                 .with_labels(vec![
                     Label::primary(*file_id, ident.span).with_message(format!("Unused local variable: {}", ident)),
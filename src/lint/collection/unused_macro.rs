@@ -0,0 +1,43 @@
+use codespan_reporting::diagnostic::{Diagnostic, Label};
+
+use crate::{
+    Config, FileId,
+    analyze::{DeclarationKind, Project},
+    lint::{Lint, LintLevel},
+    parse::{Stmt, StmtKind},
+};
+
+#[derive(Debug, PartialEq, Default)]
+pub struct UnusedMacro;
+impl Lint for UnusedMacro {
+    fn explanation(&self) -> &'static str {
+        "Macros that are never referenced anywhere in the project are dead code, and make it harder to tell which settings actually matter."
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Allow
+    }
+
+    fn tag(&self) -> &'static str {
+        "unused_macro"
+    }
+
+    fn visit_stmt_late(&self, stmt: &Stmt, project: &Project, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+        let StmtKind::Macro(mac) = stmt.kind() else {
+            return;
+        };
+        if project
+            .find(stmt.file_id(), &mac.name.lexeme, DeclarationKind::Macro)
+            .is_some_and(|declaration| project.is_unused(declaration, config))
+        {
+            reports.push(
+                self.diagnostic(config)
+                    .with_message(format!("Unused macro `{}`", mac.name))
+                    .with_labels(vec![
+                        Label::primary(stmt.file_id(), mac.name.span)
+                            .with_message("this is never used anywhere in the project"),
+                    ]),
+            );
+        }
+    }
+}
@@ -3,28 +3,26 @@ use colored::Colorize;
 
 use crate::{
     Config, FileId,
-    lint::{EarlyExprPass, Lint, LintLevel},
+    lint::{Lint, LintLevel},
     parse::{Expr, ExprKind, Function, Identifier, ParseVisitor, Stmt},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Default)]
 pub struct UnusedParameter;
 impl Lint for UnusedParameter {
-    fn explanation() -> &'static str {
+    fn explanation(&self) -> &'static str {
         "Parameters that are never referenced are often a canary towards a larger bug."
     }
 
-    fn default_level() -> LintLevel {
+    fn default_level(&self) -> LintLevel {
         LintLevel::Warn
     }
 
-    fn tag() -> &'static str {
+    fn tag(&self) -> &'static str {
         "unused_parameter"
     }
-}
 
-impl EarlyExprPass for UnusedParameter {
-    fn visit_expr_early(expr: &Expr, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+    fn visit_expr_early(&self, expr: &Expr, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
         if let ExprKind::Function(Function {
             parameters,
             name: _,
@@ -53,7 +51,7 @@ impl EarlyExprPass for UnusedParameter {
 
             for parameter in parameters_to_find.iter() {
                 reports.push(
-                    Self::diagnostic(config)
+                    self.diagnostic(config)
                         .with_message("Unused parameter")
                         .with_labels(vec![
                             Label::primary(expr.file_id(), parameter.span)
@@ -3,32 +3,30 @@ use colored::Colorize;
 
 use crate::{
     Config, FileId,
-    lint::{EarlyStmtPass, Lint, LintLevel},
+    lint::{Lint, LintLevel},
     parse::{ExprKind, Function, Stmt, StmtKind},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Default)]
 pub struct UselessFunction;
 impl Lint for UselessFunction {
-    fn explanation() -> &'static str {
+    fn explanation(&self) -> &'static str {
         "Anonymous functions that are not assigned to a variable can never be referenced."
     }
 
-    fn default_level() -> LintLevel {
+    fn default_level(&self) -> LintLevel {
         LintLevel::Deny
     }
 
-    fn tag() -> &'static str {
+    fn tag(&self) -> &'static str {
         "useless_function"
     }
-}
 
-impl EarlyStmtPass for UselessFunction {
-    fn visit_stmt_early(stmt: &Stmt, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+    fn visit_stmt_early(&self, stmt: &Stmt, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
         if let StmtKind::Expr(expr) = stmt.kind() {
             if let ExprKind::Function(Function { name: None, .. }) = expr.kind() {
                 reports.push(
-                    Self::diagnostic(config)
+                    self.diagnostic(config)
                         .with_message("Useless function")
                         .with_labels(vec![
                             Label::primary(expr.file_id(), expr.span())
@@ -2,31 +2,29 @@ use codespan_reporting::diagnostic::{Diagnostic, Label};
 
 use crate::{
     FileId,
-    lint::{EarlyStmtPass, Lint, LintLevel},
+    lint::{Lint, LintLevel},
     parse::{Stmt, StmtKind},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Default)]
 pub struct WithLoop;
 impl Lint for WithLoop {
-    fn explanation() -> &'static str {
+    fn explanation(&self) -> &'static str {
         "The `with` loop allows your code's context to suddenly change, both making it more difficult to read (as a given line of code is no longer promised to be executing in the scope expected from the file), but also making it more difficult to track down all of the places an object is modified."
     }
 
-    fn default_level() -> LintLevel {
+    fn default_level(&self) -> LintLevel {
         LintLevel::Allow
     }
 
-    fn tag() -> &'static str {
+    fn tag(&self) -> &'static str {
         "with_loop"
     }
-}
 
-impl EarlyStmtPass for WithLoop {
-    fn visit_stmt_early(stmt: &Stmt, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
+    fn visit_stmt_early(&self, stmt: &Stmt, config: &crate::Config, reports: &mut Vec<Diagnostic<FileId>>) {
         if let StmtKind::With(..) = stmt.kind() {
             reports.push(
-                Self::diagnostic(config)
+                self.diagnostic(config)
                     .with_message("Use of `with`")
                     .with_labels(vec![Label::primary(stmt.file_id(), stmt.span())]),
             );
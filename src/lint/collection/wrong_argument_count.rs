@@ -0,0 +1,100 @@
+use codespan_reporting::diagnostic::{Diagnostic, Label};
+
+use crate::{
+    Config, FileId,
+    analyze::{Arity, Body, DeclarationKind, GML_FUNCTIONS, Project},
+    lint::{Lint, LintLevel},
+    parse::{Call, Expr, ExprKind, Location},
+};
+
+#[derive(Debug, PartialEq, Default)]
+pub struct WrongArgumentCount;
+impl Lint for WrongArgumentCount {
+    fn explanation(&self) -> &'static str {
+        "Calling a function with fewer arguments than it requires leaves its parameters `undefined`, and passing more than it takes silently discards them. Parameters with default values are optional, and functions that read `argument_count` or `argument[n]` are assumed to handle extra arguments themselves. Calls to GameMaker's built-in functions are checked against their documented signatures."
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warn
+    }
+
+    fn tag(&self) -> &'static str {
+        "wrong_argument_count"
+    }
+
+    fn visit_expr_late(
+        &self,
+        expr: &Expr,
+        body: &Body,
+        project: &Project,
+        config: &Config,
+        reports: &mut Vec<Diagnostic<FileId>>,
+    ) {
+        let ExprKind::Call(Call { left, arguments, .. }) = expr.kind() else {
+            return;
+        };
+        // A parameter or local variable hides any function of the same name
+        let Some(callee) = left
+            .kind()
+            .as_identifier()
+            .filter(|callee| !body.is_local(&callee.lexeme))
+        else {
+            return;
+        };
+        let declarations = project.declarations(&callee.lexeme);
+        if declarations.is_empty() {
+            if let Some(builtin) = GML_FUNCTIONS.get(&callee.lexeme) {
+                let Some(arity) = builtin.arity().filter(|arity| !arity.accepts(arguments.len())) else {
+                    return;
+                };
+                reports.push(
+                    Self::report(self.diagnostic(config), expr, &callee.lexeme, arity, arguments.len()).with_notes(
+                        vec![format!("the signature is `{}`", builtin.signature(&callee.lexeme))],
+                    ),
+                );
+            }
+            return;
+        }
+        // If the function is declared more than once, we can't know which one is being called
+        let [declaration] = declarations else {
+            return;
+        };
+        if !matches!(
+            declaration.kind(),
+            DeclarationKind::Function | DeclarationKind::Constructor
+        ) {
+            return;
+        }
+        let Some(arity) = declaration.arity().filter(|arity| !arity.accepts(arguments.len())) else {
+            return;
+        };
+        let Location(file_id, span) = declaration.location();
+        let mut diagnostic = Self::report(self.diagnostic(config), expr, &callee.lexeme, arity, arguments.len());
+        diagnostic
+            .labels
+            .push(Label::secondary(file_id, span).with_message(format!("`{callee}` is declared here")));
+        reports.push(diagnostic);
+    }
+}
+
+impl WrongArgumentCount {
+    fn report(
+        diagnostic: Diagnostic<FileId>,
+        expr: &Expr,
+        callee: &str,
+        arity: Arity,
+        count: usize,
+    ) -> Diagnostic<FileId> {
+        let message = if count < arity.minimum() {
+            format!("Too few arguments to `{callee}`")
+        } else {
+            format!("Too many arguments to `{callee}`")
+        };
+        diagnostic
+            .with_message(message)
+            .with_labels(vec![Label::primary(expr.file_id(), expr.span()).with_message(format!(
+                "`{callee}` takes {arity}, but {count} {} given here",
+                if count == 1 { "was" } else { "were" },
+            ))])
+    }
+}
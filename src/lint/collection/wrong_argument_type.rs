@@ -0,0 +1,119 @@
+use codespan_reporting::diagnostic::{Diagnostic, Label};
+
+use crate::{
+    Config, FileId,
+    analyze::{Body, GML_FUNCTIONS, GmlType, Project},
+    lint::{Lint, LintLevel},
+    parse::{Call, Expr, ExprKind, Literal},
+};
+
+#[derive(Debug, PartialEq, Default)]
+pub struct WrongArgumentType;
+impl Lint for WrongArgumentType {
+    fn explanation(&self) -> &'static str {
+        "Passing a literal of the wrong type to one of GameMaker's built-in functions (such as a string where a number is expected) either crashes the game or quietly does the wrong thing. Only literal arguments are checked, as duck does not know the type of anything else."
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warn
+    }
+
+    fn tag(&self) -> &'static str {
+        "wrong_argument_type"
+    }
+
+    fn visit_expr_late(
+        &self,
+        expr: &Expr,
+        body: &Body,
+        project: &Project,
+        config: &Config,
+        reports: &mut Vec<Diagnostic<FileId>>,
+    ) {
+        let ExprKind::Call(Call { left, arguments, .. }) = expr.kind() else {
+            return;
+        };
+        // A parameter, local variable, variable or function of the same name hides the built-in
+        let Some(callee) = left.kind().as_identifier().filter(|callee| {
+            !body.is_local(&callee.lexeme)
+                && !project.is_assigned(left.file_id(), &callee.lexeme)
+                && project.declarations(&callee.lexeme).is_empty()
+        }) else {
+            return;
+        };
+        let Some(builtin) = GML_FUNCTIONS.get(&callee.lexeme) else {
+            return;
+        };
+        for (parameter, argument) in builtin.parameters().iter().zip(arguments) {
+            let Some(found) = Self::literal_type(argument) else {
+                continue;
+            };
+            if Self::accepts(parameter.ty(), found) {
+                continue;
+            }
+            reports.push(
+                self.diagnostic(config)
+                    .with_message(format!("Wrong argument type for `{callee}`"))
+                    .with_labels(vec![Label::primary(argument.file_id(), argument.span()).with_message(
+                        format!(
+                            "`{}` expects {}, but this is {}",
+                            parameter.name(),
+                            Self::describe(parameter.ty()),
+                            Self::describe(found)
+                        ),
+                    )])
+                    .with_notes(vec![format!(
+                        "the signature is `{}`",
+                        builtin.signature(&callee.lexeme)
+                    )]),
+            );
+        }
+    }
+}
+
+impl WrongArgumentType {
+    /// Returns the type of the expression if it is a literal whose type is certain.
+    fn literal_type(expr: &Expr) -> Option<GmlType> {
+        match expr.kind() {
+            ExprKind::Literal(Literal::String(_)) => Some(GmlType::String),
+            ExprKind::Literal(Literal::Real(_) | Literal::Hex(_) | Literal::True | Literal::False) => {
+                Some(GmlType::Real)
+            }
+            ExprKind::Literal(Literal::Array(_)) => Some(GmlType::Array),
+            ExprKind::Literal(Literal::Struct(_)) => Some(GmlType::Struct),
+            ExprKind::Function(_) => Some(GmlType::Function),
+            _ => None,
+        }
+    }
+
+    /// Returns whether a parameter of the given type accepts a literal of the found type. This is
+    /// deliberately lenient: numbers are accepted as strings, as many string functions convert
+    /// their arguments, and numbers are accepted as functions, as scripts used to be referred to by
+    /// their index.
+    fn accepts(expected: GmlType, found: GmlType) -> bool {
+        match expected {
+            GmlType::Real | GmlType::Bool => found == GmlType::Real,
+            GmlType::String => matches!(found, GmlType::String | GmlType::Real),
+            GmlType::Array => found == GmlType::Array,
+            GmlType::Struct => matches!(found, GmlType::Struct | GmlType::Function),
+            GmlType::Function => matches!(found, GmlType::Function | GmlType::Real),
+            _ => true,
+        }
+    }
+
+    fn describe(ty: GmlType) -> &'static str {
+        match ty {
+            GmlType::Real => "a number",
+            GmlType::String => "a string",
+            GmlType::Bool => "a boolean",
+            GmlType::Array => "an array",
+            GmlType::Struct => "a struct",
+            GmlType::Function => "a function",
+            GmlType::Any => "any value",
+            GmlType::Asset => "an asset",
+            GmlType::Id => "an id",
+            GmlType::Pointer => "a pointer",
+            GmlType::Void => "nothing",
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{
     Config, FileId,
+    analyze::{Body, Project},
     parse::{Ast, Expr, Stmt},
 };
 use codespan_reporting::diagnostic::{Diagnostic, Severity};
@@ -11,69 +12,76 @@ use colored::Colorize;
 /// a lint that prevents switch statements from having no default case should be
 /// called `MissingDefaultCase`, not, say, `DefaultCaseInSwitch`. This makes
 /// tagging read more clearly (ie: `#[allow(missing_default_case)])`).
-pub trait Lint {
+///
+/// Lints run by implementing any of the `visit_*` functions, each of which does nothing by
+/// default. To be run, a lint must be registered in the [LintRegistry] used by duck.
+pub trait Lint: Send + Sync {
     /// Returns the string tag for this Lint.
-    fn tag() -> &'static str;
+    fn tag(&self) -> &'static str;
 
     /// Returns the default LintLevel for this Lint.
-    fn default_level() -> LintLevel;
+    fn default_level(&self) -> LintLevel;
 
     /// Returns an explanation of what the lint does and why it is useful.
-    fn explanation() -> &'static str;
+    fn explanation(&self) -> &'static str;
 
     /// Creates a diagnostic based on the user's lint level for this lint.
     #[must_use = "Diagnostics made by lints must be put into the reports collection."]
-    fn diagnostic(config: &Config) -> Diagnostic<FileId> {
-        let level = config
-            .lint_levels
-            .iter()
-            .find(|(key, _)| key.as_str() == Self::tag())
-            .map_or_else(Self::default_level, |(_, level)| *level);
-        match level {
+    fn diagnostic(&self, config: &Config) -> Diagnostic<FileId> {
+        match *config.get_lint_level_setting(self.tag(), self.default_level()) {
             LintLevel::Allow => unreachable!(),
             LintLevel::Warn => Diagnostic::warning(),
             LintLevel::Deny => Diagnostic::error(),
         }
+        .with_code(self.tag())
         .with_notes(vec![format!(
             "{}: for more information, run `{}`",
             "Note".bold(),
-            format!("duck explain {}", Self::tag()).bold(),
+            format!("duck explain {}", self.tag()).bold(),
         )])
     }
-}
 
-/// Lints who run a pass on an entire Ast.
-pub trait AstPass {
     /// Runs on the Ast in the Ast pass.
-    fn visit_ast(ast: &Ast, config: &Config, reports: &mut Vec<Diagnostic<FileId>>);
-}
+    fn visit_ast(&self, _ast: &Ast, _config: &Config, _reports: &mut Vec<Diagnostic<FileId>>) {}
 
-/// Lints who run an early pass on statements (before type information has been
-/// collected).
-pub trait EarlyStmtPass {
-    /// Runs on statements in the early pass.
-    fn visit_stmt_early(stmt: &Stmt, config: &Config, reports: &mut Vec<Diagnostic<FileId>>);
-}
+    /// Runs on statements in the early pass (before type information has been collected).
+    fn visit_stmt_early(&self, _stmt: &Stmt, _config: &Config, _reports: &mut Vec<Diagnostic<FileId>>) {}
 
-/// Lints who run an early pass on expressions (before type information has been
-/// collected).
-pub trait EarlyExprPass {
-    /// Runs on expressions in the early pass.
-    fn visit_expr_early(expr: &Expr, config: &Config, reports: &mut Vec<Diagnostic<FileId>>);
-}
+    /// Runs on expressions in the early pass (before type information has been collected).
+    fn visit_expr_early(&self, _expr: &Expr, _config: &Config, _reports: &mut Vec<Diagnostic<FileId>>) {}
 
-/// Lints who run a late pass on statements (after type information has been
-/// collected).
-pub trait LateStmtPass {
-    /// Runs on statements in the late pass.
-    fn visit_stmt_late(stmt: &Stmt, config: &Config, reports: &mut Vec<Diagnostic<FileId>>);
-}
+    /// Runs on statements in the late pass (after every file in the [Project] has been indexed).
+    fn visit_stmt_late(
+        &self,
+        _stmt: &Stmt,
+        _project: &Project,
+        _config: &Config,
+        _reports: &mut Vec<Diagnostic<FileId>>,
+    ) {
+    }
 
-/// Lints who run a late pass on expresions (after type information has been
-/// collected).
-pub trait LateExprPass {
-    /// Runs on expressions in the late pass.
-    fn visit_expr_late(expr: &Expr, config: &Config, reports: &mut Vec<Diagnostic<FileId>>);
+    /// Runs on expressions in the late pass (after every file in the [Project] has been indexed),
+    /// along with the [Body] of the function or file the expression is in.
+    fn visit_expr_late(
+        &self,
+        _expr: &Expr,
+        _body: &Body,
+        _project: &Project,
+        _config: &Config,
+        _reports: &mut Vec<Diagnostic<FileId>>,
+    ) {
+    }
+
+    /// Runs in the late pass on the top level of every file and the body of every function, giving
+    /// access to analyses of the whole body such as its control flow graph.
+    fn visit_body_late(
+        &self,
+        _body: &Body,
+        _project: &Project,
+        _config: &Config,
+        _reports: &mut Vec<Diagnostic<FileId>>,
+    ) {
+    }
 }
 
 /// The three different levels a lint can be set to, changing how it will be
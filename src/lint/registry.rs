@@ -0,0 +1,94 @@
+use super::{Lint, collection::register_builtin_lints};
+use std::sync::Arc;
+
+/// The collection of every lint duck will run. By default, the registry contains all of the lints
+/// built into duck, but any type implementing [Lint] can be registered, allowing other crates to
+/// provide their own lints.
+///
+/// ```
+/// use codespan_reporting::diagnostic::Diagnostic;
+/// use duck::{
+///     Config, Duck, FileId,
+///     lint::{Lint, LintLevel, LintRegistry},
+///     parse::Expr,
+/// };
+///
+/// struct NoFooCalls;
+/// impl Lint for NoFooCalls {
+///     fn tag(&self) -> &'static str {
+///         "no_foo_calls"
+///     }
+///
+///     fn default_level(&self) -> LintLevel {
+///         LintLevel::Warn
+///     }
+///
+///     fn explanation(&self) -> &'static str {
+///         "Our studio does not call `foo`."
+///     }
+///
+///     fn visit_expr_early(
+///         &self,
+///         expr: &Expr,
+///         config: &Config,
+///         reports: &mut Vec<Diagnostic<FileId>>,
+///     ) {
+///         // ...
+///     }
+/// }
+///
+/// let mut lints = LintRegistry::default();
+/// lints.register(NoFooCalls);
+/// let duck = Duck::new_with_lints(Config::default(), lints);
+/// ```
+#[derive(Clone)]
+pub struct LintRegistry {
+    lints: Vec<Arc<dyn Lint>>,
+}
+impl LintRegistry {
+    /// Creates a registry with no lints in it.
+    pub fn new() -> Self {
+        Self { lints: vec![] }
+    }
+
+    /// Creates a registry containing every lint built into duck.
+    pub fn builtin() -> Self {
+        let mut registry = Self::new();
+        register_builtin_lints(&mut registry);
+        registry
+    }
+
+    /// Registers a lint. If a lint with the same tag has already been registered, it is replaced.
+    pub fn register(&mut self, lint: impl Lint + 'static) {
+        let lint: Arc<dyn Lint> = Arc::new(lint);
+        match self.lints.iter_mut().find(|other| other.tag() == lint.tag()) {
+            Some(other) => *other = lint,
+            None => self.lints.push(lint),
+        }
+    }
+
+    /// Returns the lint with the given tag, if it has been registered.
+    pub fn get(&self, tag: &str) -> Option<&dyn Lint> {
+        self.iter().find(|lint| lint.tag() == tag)
+    }
+
+    /// Returns whether a lint with the given tag has been registered.
+    pub fn contains(&self, tag: &str) -> bool {
+        self.get(tag).is_some()
+    }
+
+    /// Returns an iterator over every registered lint.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Lint> {
+        self.lints.iter().map(|lint| lint.as_ref())
+    }
+}
+impl Default for LintRegistry {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+impl std::fmt::Debug for LintRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter().map(|lint| lint.tag())).finish()
+    }
+}
@@ -0,0 +1,135 @@
+use crate::{
+    Config, FileId,
+    parse::{Ast, Expr, ParseVisitor, Stmt, Tag},
+};
+use codespan_reporting::diagnostic::Diagnostic;
+use hashbrown::HashSet;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A diagnostic that was suppressed by an `allow` tag, along with the tag that suppressed it.
+#[derive(Debug, Clone)]
+pub struct Suppression {
+    diagnostic: Diagnostic<FileId>,
+    tag: Tag,
+}
+impl Suppression {
+    /// Creates a new suppression.
+    pub fn new(diagnostic: Diagnostic<FileId>, tag: Tag) -> Self {
+        Self { diagnostic, tag }
+    }
+
+    /// Returns the diagnostic that was suppressed.
+    pub fn diagnostic(&self) -> &Diagnostic<FileId> {
+        &self.diagnostic
+    }
+
+    /// Returns the tag that suppressed the diagnostic.
+    pub fn tag(&self) -> &Tag {
+        &self.tag
+    }
+}
+
+/// The reasons an `allow` tag can fail to suppress a lint.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum SuppressionIssue {
+    /// The date in the tag's `until` attribute has passed.
+    Expired(String),
+    /// The tag's `until` attribute is not a real date written as `YYYY-MM-DD`.
+    InvalidDate(String),
+    /// The user's config requires a reason for the lint, but the tag did not give one.
+    MissingReason,
+}
+
+/// A calendar date, used to check the `until` attribute of tags.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub(crate) struct Date {
+    year: i64,
+    month: u32,
+    day: u32,
+}
+impl Date {
+    /// Parses a date written as `YYYY-MM-DD`, returning None if the day doesn't exist in its month
+    /// (such as `2026-02-29` or `2026-04-31`).
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut parts = text.split('-');
+        let (year, month, day) = (parts.next()?, parts.next()?, parts.next()?);
+        if parts.next().is_some() || year.len() != 4 || month.len() != 2 || day.len() != 2 {
+            return None;
+        }
+        let date = Self {
+            year: year.parse().ok()?,
+            month: month.parse().ok()?,
+            day: day.parse().ok()?,
+        };
+        ((1..=12).contains(&date.month) && (1..=date.days_in_month()).contains(&date.day)).then_some(date)
+    }
+
+    /// Returns the number of days in the date's month.
+    fn days_in_month(&self) -> u32 {
+        match self.month {
+            2 if self.year % 4 == 0 && (self.year % 100 != 0 || self.year % 400 == 0) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    /// Returns the current date in UTC.
+    pub fn today() -> Self {
+        let days = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs() / 86400) as i64;
+        Self::from_days_since_epoch(days)
+    }
+
+    /// Converts a number of days since 1970-01-01 into a date, using the algorithm described in
+    /// http://howardhinnant.github.io/date_algorithms.html#civil_from_days.
+    pub fn from_days_since_epoch(days: i64) -> Self {
+        let days = days + 719468;
+        let era = days.div_euclid(146097);
+        let day_of_era = days - era * 146097;
+        let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        } as u32;
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+        Self { year, month, day }
+    }
+}
+
+/// Visits every tag in the Ast once, along with the config that is in effect where the tag was
+/// written. Tags in regions are attached to every statement within them, so this is the preferred
+/// way for lints to inspect tags.
+pub(crate) fn visit_tags(ast: &Ast, config: &Config, mut visitor: impl FnMut(&Tag, &Config)) {
+    let mut visited = HashSet::new();
+    let mut visit = |tags: &[Tag], config: &Config| {
+        for tag in tags {
+            if visited.insert(tag.location()) {
+                visitor(tag, config);
+            }
+        }
+    };
+    visit(ast.tags(), config);
+    for stmt in ast.stmts() {
+        visit_stmt_tags(stmt, config, &mut visit);
+    }
+}
+
+fn visit_stmt_tags(stmt: &Stmt, config: &Config, visit: &mut impl FnMut(&[Tag], &Config)) {
+    let config = &*config.with_tags(stmt.tags());
+    visit(stmt.tags(), config);
+    stmt.visit_child_stmts(|stmt| visit_stmt_tags(stmt, config, visit));
+    stmt.visit_child_exprs(|expr| visit_expr_tags(expr, config, visit));
+}
+
+fn visit_expr_tags(expr: &Expr, config: &Config, visit: &mut impl FnMut(&[Tag], &Config)) {
+    let config = &*config.with_tags(expr.tags());
+    visit(expr.tags(), config);
+    expr.visit_child_stmts(|stmt| visit_stmt_tags(stmt, config, visit));
+    expr.visit_child_exprs(|expr| visit_expr_tags(expr, config, visit));
+}
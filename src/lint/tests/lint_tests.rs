@@ -1,30 +1,74 @@
 use crate::{
-    Config, GmlLibrary, driver,
-    lint::{Lint, LintLevel, collection::*},
+    BannedCallRule, Config, GmlLibrary, PatternRule,
+    analyze::Project,
+    driver,
+    lint::{Lint, LintLevel, LintRegistry, collection::*},
     parse::*,
 };
 use codespan_reporting::term::termcolor::{ColorChoice, StandardStream};
 use pretty_assertions::assert_eq;
+use std::path::Path;
 
-fn config_for_lint<T: Lint>() -> Config {
+pub(super) fn config_for_lint<T: Lint + Default>() -> Config {
     let mut config = Config::full();
     config.lint_levels.iter_mut().for_each(|(_, v)| *v = LintLevel::Allow);
-    config.lint_levels.insert(T::tag().into(), LintLevel::Deny);
+    config.lint_levels.insert(T::default().tag().into(), LintLevel::Deny);
     config
 }
 
-pub(super) fn harness_lint<T: Lint>(source: &'static str, expected_number: usize) {
-    let config = config_for_lint::<T>();
+pub(super) fn harness_lint<T: Lint + Default>(source: &'static str, expected_number: usize) {
+    harness_lint_with_config::<T>(config_for_lint::<T>(), source, expected_number);
+}
+
+pub(super) fn harness_lint_with_config<T: Lint + Default>(
+    config: Config,
+    source: &'static str,
+    expected_number: usize,
+) {
+    harness_lint_with_registry::<T>(config, &LintRegistry::default(), source, expected_number);
+}
+
+pub(super) fn harness_lint_with_registry<T: Lint + Default>(
+    config: Config,
+    lints: &LintRegistry,
+    source: &'static str,
+    expected_number: usize,
+) {
+    harness_files::<T>(config, lints, &[("scripts/test/test.gml", source)], expected_number);
+}
+
+/// Runs the lint over several files at once, each given as a path and its source, so that lints
+/// that look across the whole project can be tested.
+pub(super) fn harness_project_lint<T: Lint + Default>(files: &[(&str, &'static str)], expected_number: usize) {
+    harness_project_lint_with_config::<T>(config_for_lint::<T>(), files, expected_number);
+}
+
+pub(super) fn harness_project_lint_with_config<T: Lint + Default>(
+    config: Config,
+    files: &[(&str, &'static str)],
+    expected_number: usize,
+) {
+    harness_files::<T>(config, &LintRegistry::default(), files, expected_number);
+}
+
+fn harness_files<T: Lint + Default>(
+    config: Config,
+    lints: &LintRegistry,
+    files: &[(&str, &'static str)],
+    expected_number: usize,
+) {
     let mut library = GmlLibrary::new();
-    let file_id = library.add("test.gml".into(), source);
-    let mut ast = Parser::new_with_default_ids(source, file_id).into_ast().unwrap();
-    let mut reports = vec![];
-    driver::process_ast(&ast, &mut reports, &config);
-    for stmt in ast.stmts_mut() {
-        driver::process_stmt_early(stmt, &mut reports, &config);
+    let mut project = Project::new();
+    let mut asts = vec![];
+    for (path, source) in files {
+        let file_id = library.add(path.to_string(), source);
+        let ast = Parser::new_with_default_ids(source, file_id).into_ast().unwrap();
+        project.add(&ast, Path::new(path));
+        asts.push(ast);
     }
-    for stmt in ast.stmts() {
-        driver::process_stmt_late(stmt, &mut reports, &config);
+    let mut reports = vec![];
+    for ast in asts {
+        reports.append(&mut driver::process_file(&ast, &config, lints, &project).diagnostics);
     }
     let writer = StandardStream::stdout(ColorChoice::Always);
     let config = codespan_reporting::term::Config::default();
@@ -36,8 +80,8 @@ pub(super) fn harness_lint<T: Lint>(source: &'static str, expected_number: usize
             reports.len(),
             expected_number,
             "{} got the wrong number of lints on `{}`!",
-            T::tag(),
-            source
+            T::default().tag(),
+            files.iter().map(|(_, source)| *source).collect::<Vec<_>>().join("\n")
         );
     }
 }
@@ -77,6 +121,119 @@ fn anonymous_constructor() {
     );
 }
 
+fn config_with_banned_calls(rules: Vec<BannedCallRule>) -> Config {
+    let mut config = config_for_lint::<BannedCall>();
+    config.banned_calls = rules;
+    config
+}
+
+fn draw_text_rule() -> BannedCallRule {
+    BannedCallRule {
+        patterns: vec!["draw_text*".into()],
+        replacement: Some("ui_draw_text".into()),
+        allowed_paths: vec!["scripts/ui_*/*".into()],
+        allowed_events: vec!["Draw_64".into()],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn banned_call() {
+    harness_lint_with_config::<BannedCall>(
+        config_with_banned_calls(vec![
+            draw_text_rule(),
+            BannedCallRule {
+                patterns: vec!["room_goto".into(), "game_end".into()],
+                ..Default::default()
+            },
+        ]),
+        "
+            draw_text(foo, x, y);
+            draw_text_ext(foo, x, y);
+            room_goto(foo);
+            room_goto_next();
+            game_end();
+            foo.draw_text();
+        ",
+        4,
+    );
+}
+
+#[test]
+fn banned_call_without_rules() {
+    harness_lint::<BannedCall>("draw_text(foo, x, y);", 0);
+}
+
+#[test]
+fn banned_call_rule_level() {
+    harness_lint_with_config::<BannedCall>(
+        config_with_banned_calls(vec![BannedCallRule {
+            patterns: vec!["draw_text".into()],
+            level: Some(LintLevel::Allow),
+            ..Default::default()
+        }]),
+        "draw_text(foo, x, y);",
+        0,
+    );
+}
+
+#[test]
+fn banned_call_most_specific_rule() {
+    let rules = vec![
+        BannedCallRule {
+            patterns: vec!["draw_*".into()],
+            level: Some(LintLevel::Allow),
+            ..Default::default()
+        },
+        BannedCallRule {
+            patterns: vec!["draw_text*".into()],
+            ..Default::default()
+        },
+        BannedCallRule {
+            patterns: vec!["draw_text_ext".into()],
+            level: Some(LintLevel::Allow),
+            ..Default::default()
+        },
+    ];
+    harness_lint_with_config::<BannedCall>(
+        config_with_banned_calls(rules),
+        "
+            draw_sprite(foo, 0, x, y);
+            draw_text(foo, x, y);
+            draw_text_color(foo, x, y);
+            draw_text_ext(foo, x, y);
+        ",
+        2,
+    );
+}
+
+#[test]
+fn banned_call_allowed_path() {
+    let config = config_with_banned_calls(vec![draw_text_rule()]);
+    let config = config.for_file(Path::new("/project/scripts/ui_text/ui_text.gml"));
+    harness_lint_with_config::<BannedCall>(config.into_owned(), "draw_text(foo, x, y);", 0);
+}
+
+#[test]
+fn banned_call_allowed_event() {
+    let config = config_with_banned_calls(vec![draw_text_rule()]);
+    let config = config.for_file(Path::new("/project/objects/obj_hud/Draw_64.gml"));
+    harness_lint_with_config::<BannedCall>(config.into_owned(), "draw_text(foo, x, y);", 0);
+}
+
+#[test]
+fn banned_call_outside_allowed_files() {
+    for path in [
+        "/project/scripts/gameplay/gameplay.gml",
+        "/project/objects/obj_hud/Draw_0.gml",
+        "/project/scripts/ui_text.gml",
+    ] {
+        let config = config_with_banned_calls(vec![draw_text_rule()]);
+        let config = config.for_file(Path::new(path));
+        harness_lint_with_config::<BannedCall>(config.into_owned(), "draw_text(foo, x, y);", 1);
+    }
+}
+
 #[test]
 fn bool_equality() {
     harness_lint::<BoolEquality>(
@@ -195,6 +352,48 @@ fn condition_wrapper() {
     );
 }
 
+#[test]
+fn constant_condition() {
+    harness_lint::<ConstantCondition>(
+        r#"
+            if (false) {}
+            if (1) {} else {}
+            if (x == x) {}
+            if (a.b[0] != a.b[0]) {}
+            if (a || true) {}
+            if ("a" == "b") {}
+            while (0) {}
+            for (var i = 0; 2 < 1; i++) {}
+            do {} until (true);
+            var a = 1 > 0 ? b : c;
+        "#,
+        10,
+    );
+    harness_lint::<ConstantCondition>(
+        "
+            if (foo) {}
+            if (x == y) {}
+            if (foo() == foo()) {}
+            if (a && true) {}
+            if (DEBUG) {}
+            while (true) { if foo break; }
+            while (1) { return; }
+            do { exit; } until (false);
+            var a = b ? c : d;
+        ",
+        0,
+    );
+    harness_lint::<ConstantCondition>(
+        "
+            while (true) { foo(); }
+            while (1) { while (true) { break; } }
+            for (var i = 0; true; i++) { continue; }
+            do { foo(); } until (false);
+        ",
+        4,
+    );
+}
+
 #[test]
 fn deprecated() {
     harness_lint::<Deprecated>(
@@ -229,6 +428,76 @@ fn draw_text() {
     );
 }
 
+#[test]
+fn duplicate_case() {
+    harness_project_lint::<DuplicateCase>(
+        &[
+            (
+                "scripts/a/a.gml",
+                "
+                    #macro ONE 1
+                    #macro ALSO_ONE ONE
+                    #macro GREETING \"hi\"
+                    enum Dir { Left, Right = 5, Up }
+                ",
+            ),
+            (
+                "objects/obj/Step_0.gml",
+                r#"
+                    switch foo {
+                        case 1: break;
+                        case ONE: break;
+                        case ALSO_ONE: break;
+                        case 0x1: break;
+                        case "hi": break;
+                        case GREETING: break;
+                        case Dir.Up: break;
+                        case 6: break;
+                        case Dir.Right: break;
+                        case -5: break;
+                        case bar: break;
+                        case bar: break;
+                    }
+                "#,
+            ),
+        ],
+        5,
+    );
+}
+
+#[test]
+fn duplicate_declaration() {
+    harness_project_lint::<DuplicateDeclaration>(
+        &[
+            (
+                "scripts/a/a.gml",
+                "function foo() {} function Bar() constructor {} enum Baz { A }",
+            ),
+            ("scripts/b/b.gml", "function foo() {} function Bar() {} enum Baz { B }"),
+            ("scripts/c/c.gml", "function foo() {}"),
+            ("objects/obj/Create_0.gml", "function foo() {}"),
+        ],
+        4,
+    );
+    harness_project_lint::<DuplicateDeclaration>(
+        &[
+            ("scripts/a/a.gml", "#macro FOO 0"),
+            ("scripts/b/b.gml", "#macro Debug:FOO 1"),
+            ("scripts/c/c.gml", "#macro Release:FOO 2"),
+        ],
+        0,
+    );
+    harness_project_lint::<DuplicateDeclaration>(
+        &[
+            ("scripts/a/a.gml", "#macro Debug:FOO 0"),
+            ("scripts/b/b.gml", "#macro Debug:FOO 1"),
+            ("scripts/c/c.gml", "#macro FOO 1"),
+            ("scripts/d/d.gml", "#macro FOO 1"),
+        ],
+        2,
+    );
+}
+
 #[test]
 fn english_flavor_violation() {
     harness_lint::<EnglishFlavorViolation>(
@@ -261,6 +530,141 @@ fn global() {
     );
 }
 
+#[test]
+fn inconsistent_return() {
+    harness_lint::<InconsistentReturn>(
+        "
+            function a() {
+                if foo { return 1; }
+            }
+            function b() {
+                if foo { return 1; }
+                return;
+            }
+            function c() {
+                if foo { exit; }
+                return 1;
+            }
+            function d() constructor {
+                return 1;
+            }
+        ",
+        4,
+    );
+    harness_lint::<InconsistentReturn>(
+        "
+            function a() {
+                if foo { return 1; }
+                return 2;
+            }
+            function b() {
+                if foo { return; }
+                bar();
+            }
+            function c() {
+                while true {
+                    if foo { return 1; }
+                }
+            }
+            function d() {
+                switch foo {
+                    case 0: return 1;
+                    default: throw bar;
+                }
+            }
+            function e() constructor {
+                if foo { return; }
+            }
+            if foo { return 1; }
+        ",
+        0,
+    );
+}
+
+#[test]
+fn invalid_allow() {
+    harness_lint::<InvalidAllow>(
+        "
+            // #[allow(draw_text, until = \"2000-01-01\")]
+            draw_text(foo, x, y);
+            // #[allow(draw_text, until = \"next week\")]
+            draw_text(foo, x, y);
+        ",
+        2,
+    );
+    harness_lint::<InvalidAllow>(
+        "
+            // #[allow(draw_text, until = \"2999-12-31\")]
+            draw_text(foo, x, y);
+        ",
+        0,
+    );
+}
+
+#[test]
+fn invalid_enum_member() {
+    harness_project_lint::<InvalidEnumMember>(
+        &[
+            (
+                "scripts/a/a.gml",
+                "
+                    #macro BASE 10
+                    enum Dir { Left = 1, Right, Up = 2, Down, Default = Dir.Left, Len }
+                    enum Flags { A = 1 << 0, B = 1 << 1, C = Dir.Right * 2, D = BASE, E = BASE - 6 }
+                    enum Bad { A = foo(), B = x, Len, C }
+                ",
+            ),
+            ("scripts/b/b.gml", "enum Other { A = Dir.Down, B = Flags.A + 1 }"),
+        ],
+        6,
+    );
+}
+
+#[test]
+fn line_too_long() {
+    let mut config = config_for_lint::<LineTooLong>();
+    config.max_line_length = 20;
+    harness_lint_with_config::<LineTooLong>(
+        config,
+        "var short = 0;\nvar much_too_long = \"........\";\r\nvar o = \"é.é......\";\n",
+        1,
+    );
+}
+
+#[test]
+fn magic_number() {
+    harness_lint::<MagicNumber>(
+        "
+            enum Hp { Max = 37, Min = -3 }
+            #macro SPEED 12
+            if (hp < 37) {}
+            alarm[3] = 143;
+            array[0] = array[1];
+            x = -1 + 2 * 0 + 0.5;
+            y = -5;
+            col = $ff00ff;
+            grid[# 4, 0] = true;
+            done = true;
+        ",
+        7,
+    );
+}
+
+#[test]
+fn magic_number_allowed_values() {
+    let mut config = config_for_lint::<MagicNumber>();
+    config.allowed_magic_numbers = vec![37.0];
+    harness_lint_with_config::<MagicNumber>(
+        config,
+        "
+            if (hp < 37) {}
+            array[0] = array[1] + 1;
+            x = 0;
+        ",
+        2,
+    );
+}
+
 #[test]
 fn missing_case_member() {
     harness_lint::<MissingCaseMember>(
@@ -389,6 +793,54 @@ fn or_preference() {
     );
 }
 
+fn config_with_pattern_rules(rules: Vec<PatternRule>) -> Config {
+    let mut config = config_for_lint::<PatternViolation>();
+    config.pattern_rules = rules;
+    config
+}
+
+#[test]
+fn pattern_violation() {
+    harness_lint_with_config::<PatternViolation>(
+        config_with_pattern_rules(vec![
+            PatternRule::new(
+                "instance_create_layer($x, $y, \"Instances\", $obj)",
+                "use `spawn` instead",
+            ),
+            PatternRule::new("if ($a == true) $body", "comparisons to `true` are redundant"),
+        ]),
+        "
+            instance_create_layer(x, y, \"Instances\", obj_bullet);
+            var bullet = instance_create_layer(x, y, \"Instances\", obj_bullet);
+            instance_create_layer(x, y, \"Effects\", obj_smoke);
+            if foo == true {
+                if (bar == true) exit;
+            }
+            if foo == false {}
+        ",
+        4,
+    );
+}
+
+#[test]
+fn pattern_violation_rule_level() {
+    let mut rule = PatternRule::new("foo($a)", "don't call foo");
+    rule.level = Some(LintLevel::Allow);
+    harness_lint_with_config::<PatternViolation>(config_with_pattern_rules(vec![rule]), "foo(1);", 0);
+}
+
+#[test]
+fn pattern_violation_invalid_pattern() {
+    harness_lint_with_config::<PatternViolation>(
+        config_with_pattern_rules(vec![
+            PatternRule::new("foo(", "this pattern is broken"),
+            PatternRule::new("foo($a)", "don't call foo"),
+        ]),
+        "foo(1);",
+        1,
+    );
+}
+
 #[test]
 fn room_goto() {
     harness_lint::<RoomGoto>(
@@ -400,6 +852,55 @@ fn room_goto() {
     );
 }
 
+const SHADOWING_FILES: &[(&str, &str)] = &[
+    (
+        "scripts/a/a.gml",
+        "
+            #macro SIZE 16
+            function helper(a, SIZE) {
+                var a = 0;
+                for (var i = 0; i < 1; i++) {
+                    for (var i = 0; i < 1; i++) {}
+                }
+                for (var i = 0; i < 1; i++) {}
+                try {} catch (i) {}
+                var helper = 1;
+                var x = 0;
+                var room = 0;
+                var fn = function(b) { var b2 = b; var a = 1; };
+            }
+        ",
+    ),
+    (
+        "objects/obj_a/Create_0.gml",
+        "
+            var x = 0;
+            var depth;
+            var _speed = 1;
+            if (foo) { var x = 1; }
+            function update(image_index) {}
+            function draw(mouse_x) {}
+        ",
+    ),
+];
+
+#[test]
+fn shadowing() {
+    harness_project_lint::<Shadowing>(SHADOWING_FILES, 10);
+}
+
+#[test]
+fn shadowing_rules() {
+    let mut config = config_for_lint::<Shadowing>();
+    config.shadowing_rules.check_parameters = false;
+    config.shadowing_rules.check_locals = false;
+    harness_project_lint_with_config::<Shadowing>(config.clone(), SHADOWING_FILES, 8);
+    config.shadowing_rules.check_instance_variables = false;
+    harness_project_lint_with_config::<Shadowing>(config.clone(), SHADOWING_FILES, 4);
+    config.shadowing_rules.check_globals = false;
+    harness_project_lint_with_config::<Shadowing>(config, SHADOWING_FILES, 0);
+}
+
 #[test]
 fn show_debug_message() {
     harness_lint::<ShowDebugMessage>(
@@ -454,6 +955,46 @@ fn suspicious_constant_usage() {
     );
 }
 
+#[test]
+fn switch_fallthrough() {
+    harness_lint::<SwitchFallthrough>(
+        "
+            switch foo {
+                case 0:
+                case 1:
+                    bar();
+                case 2:
+                    bar();
+                    // fallthrough
+                case 3:
+                    if bar { return; } else { exit; }
+                case 4:
+                    while true { break; }
+                default:
+                    bar();
+            }
+            switch foo {
+                case 0:
+                    bar();
+                    break;
+                case 1:
+                    bar();
+            }
+            while true {
+                switch foo {
+                    case 0:
+                        continue;
+                    case 1:
+                        throw bar;
+                    case 2:
+                        bar();
+                }
+            }
+        ",
+        2,
+    );
+}
+
 #[test]
 fn todo() {
     harness_lint::<Todo>(
@@ -480,6 +1021,71 @@ fn too_many_arguments() {
     );
 }
 
+#[test]
+fn too_complex() {
+    let mut config = config_for_lint::<TooComplex>();
+    config.max_complexity = 3;
+    harness_lint_with_config::<TooComplex>(
+        config,
+        "
+            function complex(a, b) {
+                if a && b { return 0; }
+                while a { a--; }
+                return a ? 1 : 2;
+            }
+            function simple(a) {
+                if a { return 0; }
+            }
+            if a {} else if b {} else if c {}
+        ",
+        2,
+    );
+}
+
+#[test]
+fn too_deeply_nested() {
+    let mut config = config_for_lint::<TooDeeplyNested>();
+    config.max_nesting_depth = 2;
+    harness_lint_with_config::<TooDeeplyNested>(
+        config,
+        "
+            function nested() {
+                if a {
+                    while b {
+                        for (var i = 0; i < c; i++) { d(); }
+                    }
+                }
+            }
+            function chained() {
+                if a { if b { c(); } } else if d { e(); } else if f { g(); }
+            }
+        ",
+        1,
+    );
+}
+
+#[test]
+fn too_many_statements() {
+    let mut config = config_for_lint::<TooManyStatements>();
+    config.max_statements = 3;
+    harness_lint_with_config::<TooManyStatements>(
+        config,
+        "
+            function long() {
+                var a = 0, b = 1;
+                a++;
+                b++;
+                return a + b;
+            }
+            function short() {
+                { { a = 0; } }
+                return a;
+            }
+        ",
+        1,
+    );
+}
+
 #[test]
 fn try_catch() {
     harness_lint::<crate::lint::collection::TryCatch>(
@@ -506,6 +1112,86 @@ fn unassigned_constructor() {
     );
 }
 
+#[test]
+fn uninitialized_local_variable() {
+    harness_lint::<UninitializedLocalVariable>(
+        "
+            var _a;
+            show_debug_message(_a);
+            var _b;
+            if foo { _b = 1; }
+            show_debug_message(_b);
+            var _c;
+            while foo { _c = 1; }
+            _c += 1;
+            var _d, _e = _d;
+            var _f;
+            try { _f = foo(); } catch (e) {}
+            show_debug_message(_f);
+        ",
+        5,
+    );
+    harness_lint::<UninitializedLocalVariable>(
+        "
+            var _a;
+            _a = 0;
+            show_debug_message(_a);
+            var _b;
+            if foo { _b = 1; } else { _b = 2; }
+            show_debug_message(_b);
+            var _c;
+            if foo { _c = 1; } else { exit; }
+            show_debug_message(_c);
+            var _d;
+            _d[0] = 1;
+            show_debug_message(_d);
+            var _e;
+            do { _e = foo(); } until _e;
+            var _f;
+            function bar() { return _f; }
+            var _g;
+            switch foo {
+                case 0: _g = 0; break;
+                default: _g = 1; break;
+            }
+            show_debug_message(_g);
+        ",
+        0,
+    );
+    harness_lint::<UninitializedLocalVariable>(
+        "
+            // Once read, the variable must have been assigned (or the game would have crashed)
+            var _a;
+            show_debug_message(_a);
+            show_debug_message(_a);
+            function foo() {
+                var _b;
+                return _b;
+            }
+        ",
+        2,
+    );
+}
+
+#[test]
+fn unknown_lint() {
+    harness_lint::<UnknownLint>(
+        "
+            // #[allow(draw_txt)]
+            draw_text(foo, x, y);
+        ",
+        1,
+    );
+    harness_lint::<UnknownLint>(
+        "
+            // #[allow(draw_text)]
+            // #[some_other_tag(draw_txt)]
+            draw_text(foo, x, y);
+        ",
+        0,
+    );
+}
+
 #[test]
 fn unnecessary_grouping() {
     harness_lint::<UnnecessaryGrouping>(
@@ -544,6 +1230,187 @@ fn unnecessary_grouping() {
     );
 }
 
+#[test]
+fn unreachable_code() {
+    harness_lint::<UnreachableCode>(
+        "
+            function foo() {
+                return 0;
+                bar();
+                buzz();
+            }
+            while foo {
+                break;
+                bar();
+            }
+            while foo {
+                continue;
+                bar();
+            }
+            if foo { exit; } else { throw bar; }
+            bar();
+        ",
+        4,
+    );
+    harness_lint::<UnreachableCode>(
+        "
+            if false { foo(); }
+            if true { foo(); } else { bar(); }
+            while (false) { foo(); }
+            function hoisted() {
+                exit;
+                #macro FOO 1
+                enum E { A }
+                function f() {}
+            }
+            function mixed() {
+                exit;
+                #macro BAR 1
+                baz();
+            }
+        ",
+        4,
+    );
+    harness_lint::<UnreachableCode>(
+        "
+            if foo { return; }
+            bar();
+            switch foo {
+                case 0: return 1; break;
+                case 1: bar(); break;
+            }
+            while true { if foo { break; } }
+            bar();
+            try { throw foo; } catch (e) {}
+            bar();
+            function buzz() { return 0; }
+            bar();
+        ",
+        0,
+    );
+}
+
+#[test]
+fn unreachable_code_constant_condition() {
+    let mut config = config_for_lint::<UnreachableCode>();
+    config
+        .lint_levels
+        .insert(ConstantCondition.tag().into(), LintLevel::Deny);
+    harness_lint_with_config::<UnreachableCode>(
+        config,
+        "
+            if (false) { foo(); }
+            if (1) { foo(); } else { bar(); }
+            while (0) { foo(); }
+            if (true) { foo(); }
+            if (x != x) { foo(); }
+        ",
+        5,
+    );
+}
+
+#[test]
+fn unused_enum() {
+    harness_project_lint::<UnusedEnum>(
+        &[
+            ("scripts/a/a.gml", "enum Foo { A } enum Bar { B } enum Baz { C }"),
+            ("objects/obj/Create_0.gml", "x = Foo.A; show_debug_message(Bar);"),
+        ],
+        1,
+    );
+}
+
+#[test]
+fn unused_enum_member() {
+    harness_project_lint::<UnusedEnumMember>(
+        &[
+            ("scripts/a/a.gml", "enum Foo { A, B, C, Len } enum Bar { A }"),
+            ("scripts/b/b.gml", "#macro FOO_C Foo.C"),
+            ("objects/obj/Create_0.gml", "x = Foo.A;"),
+        ],
+        1,
+    );
+}
+
+#[test]
+fn unused_function() {
+    harness_project_lint::<UnusedFunction>(
+        &[
+            (
+                "scripts/a/a.gml",
+                "function used() {} function unused() {} function Used() constructor {} function Unused() constructor {}",
+            ),
+            ("scripts/b/b.gml", "function caller() : Used() constructor { used(); }"),
+            (
+                "objects/obj/Create_0.gml",
+                "function method_variable() {} var b = new caller();",
+            ),
+        ],
+        2,
+    );
+    harness_project_lint::<UnusedFunction>(
+        &[(
+            "scripts/a/a.gml",
+            "function recursive(n) { return recursive(n - 1); } function by_name() {} script_execute(asset_get_index(\"by_name\"));",
+        )],
+        1,
+    );
+    harness_project_lint::<UnusedFunction>(
+        &[(
+            "scripts/a/a.gml",
+            "
+                // #[allow(unused_function)]
+                function foo() {}
+            ",
+        )],
+        0,
+    );
+}
+
+#[test]
+fn unused_function_exemptions() {
+    let mut config = config_for_lint::<UnusedFunction>();
+    config.public_api_paths.push("scripts/api_*/*".into());
+    config.used_symbols.push("on_*".into());
+    harness_project_lint_with_config::<UnusedFunction>(
+        config,
+        &[
+            ("scripts/api_math/api_math.gml", "function api_lerp() {}"),
+            ("scripts/events/events.gml", "function on_load() {} function load() {}"),
+        ],
+        1,
+    );
+}
+
+#[test]
+fn unused_globalvar() {
+    harness_project_lint::<UnusedGlobalvar>(
+        &[
+            ("scripts/a/a.gml", "globalvar foo; globalvar bar; globalvar baz;"),
+            ("objects/obj/Create_0.gml", "foo = 0; global.bar = 1;"),
+        ],
+        1,
+    );
+}
+
+#[test]
+fn unused_macro() {
+    harness_project_lint::<UnusedMacro>(
+        &[
+            (
+                "scripts/a/a.gml",
+                "
+                    #macro FOO 0
+                    #macro BAR FOO + 1
+                    #macro BAZ 2
+                ",
+            ),
+            ("objects/obj/Create_0.gml", "x = BAR;"),
+        ],
+        1,
+    );
+}
+
 #[test]
 fn unused_parameter() {
     harness_lint::<UnusedParameter>(
@@ -631,6 +1498,247 @@ fn with_loop() {
     );
 }
 
+#[test]
+fn wrong_argument_count() {
+    harness_project_lint::<WrongArgumentCount>(
+        &[
+            (
+                "scripts/a/a.gml",
+                "
+                    function scr_damage(target, amount, source) {}
+                    function scr_heal(target, amount = 1) {}
+                    function scr_log() { show_debug_message(argument[0]); }
+                    function scr_format(text) { for (var i = 1; i < argument_count; i++) {} }
+                    function Vec2(x, y) constructor {}
+                ",
+            ),
+            (
+                "objects/obj/Step_0.gml",
+                "
+                    scr_damage(target);
+                    scr_damage(target, 10, id);
+                    scr_damage(target, 10, id, 0);
+                    scr_heal(target);
+                    scr_heal();
+                    scr_log(1, 2, 3);
+                    scr_format();
+                    var v = new Vec2(1);
+                    unknown_function(1, 2, 3);
+                ",
+            ),
+        ],
+        4,
+    );
+    harness_project_lint::<WrongArgumentCount>(
+        &[
+            ("scripts/a/a.gml", "function foo(a) {}"),
+            ("scripts/b/b.gml", "function foo() {}"),
+            ("objects/obj/Step_0.gml", "foo(0, 1, 2);"),
+        ],
+        0,
+    );
+}
+
+#[test]
+fn wrong_argument_count_shadowed() {
+    harness_project_lint::<WrongArgumentCount>(
+        &[
+            ("scripts/a/a.gml", "function foo(a, b, c) {}"),
+            (
+                "scripts/b/b.gml",
+                "
+                    function bar(foo) {
+                        foo(1);
+                    }
+                    function baz() {
+                        var foo = function(a) {};
+                        foo(1);
+                    }
+                    function qux() {
+                        foo(1);
+                    }
+                ",
+            ),
+        ],
+        1,
+    );
+}
+
+#[test]
+fn wrong_argument_count_builtin() {
+    harness_project_lint::<WrongArgumentCount>(
+        &[(
+            "objects/obj/Draw_0.gml",
+            "
+                draw_sprite(spr_player, 0, x);
+                draw_sprite(spr_player, 0, x, y);
+                array_create();
+                array_create(4, 0);
+                array_create(4, 0, 0);
+                show_debug_message(1, 2, 3);
+                buffer_surface_copy(1, 2, 3);
+            ",
+        )],
+        3,
+    );
+}
+
+#[test]
+fn constructor_misuse() {
+    harness_project_lint::<ConstructorMisuse>(
+        &[
+            (
+                "scripts/a/a.gml",
+                "
+                    function Vec2(x, y) constructor {}
+                    function Vec3(x, y, z) : Vec2(x, y) constructor {}
+                    function Enemy() : Actor() constructor {}
+                    function Boss() : scr_spawn() constructor {}
+                    function scr_spawn() {}
+                ",
+            ),
+            (
+                "objects/obj/Create_0.gml",
+                "
+                    a = new Vec2(0, 0);
+                    b = Vec2(0, 0);
+                    c = new Vec3(0, 0, 0);
+                    d = new scr_spawn();
+                    e = new Enemy();
+                    f = new Boss();
+                    g = new array_create(4);
+                    h = function() { return Vec2(1, 1); }
+                    i = new Foo();
+                ",
+            ),
+        ],
+        6,
+    );
+}
+
+#[test]
+fn constructor_misuse_shadowed() {
+    harness_project_lint::<ConstructorMisuse>(
+        &[
+            ("scripts/a/a.gml", "function Foo() constructor {}"),
+            (
+                "scripts/b/b.gml",
+                "
+                    function bar(Foo) {
+                        Foo();
+                    }
+                    function baz() {
+                        var Foo = function() {};
+                        Foo();
+                    }
+                    function qux() {
+                        Foo();
+                    }
+                ",
+            ),
+        ],
+        1,
+    );
+}
+
+#[test]
+fn wrong_argument_type() {
+    harness_lint::<WrongArgumentType>(
+        r#"
+            draw_sprite(spr_player, 0, "x", y);
+            draw_rectangle(0, 0, 10, 10, "false");
+            array_length("foo");
+            array_length([0, 1]);
+            string_length(0);
+            string_upper([]);
+            array_foreach(items, function(item) {});
+            draw_text(x, y, 0);
+            draw_sprite(spr_player, 0, foo, bar);
+        "#,
+        4,
+    );
+}
+
+#[test]
+fn wrong_argument_type_shadowed() {
+    harness_project_lint::<WrongArgumentType>(
+        &[(
+            "scripts/a/a.gml",
+            "
+                function foo(string_upper) {
+                    string_upper([]);
+                }
+                function bar() {
+                    var array_length = function(a) {};
+                    array_length(\"foo\");
+                }
+                function baz() {
+                    string_upper([]);
+                }
+            ",
+        )],
+        1,
+    );
+}
+
+#[test]
+fn unknown_function() {
+    harness_project_lint::<UnknownFunction>(
+        &[
+            ("scripts/a/a.gml", "function draw_sprit_shadow() {}"),
+            (
+                "objects/obj/Draw_0.gml",
+                "
+                    draw_sprit(spr_player, 0, x, y);
+                    array_lenght(items);
+                    draw_sprite(spr_player, 0, x, y);
+                    draw_sprit_shadow();
+                    scr_player_update();
+                    foo();
+                ",
+            ),
+        ],
+        2,
+    );
+}
+
+#[test]
+fn unknown_function_methods() {
+    harness_project_lint::<UnknownFunction>(
+        &[
+            (
+                "scripts/a/a.gml",
+                "
+                    var draw_txt = function() {};
+                    draw_txt();
+                    function scr_draw(draw_txt) {
+                        draw_txt();
+                    }
+                    function scr_draw_all() {
+                        draw_txt();
+                    }
+                ",
+            ),
+            ("objects/obj/Create_0.gml", "draw_txt = function() {};"),
+            ("objects/obj/Draw_0.gml", "draw_txt(); self.array_lenght();"),
+            ("objects/obj_other/Create_0.gml", "self.array_lenght = function() {};"),
+            ("objects/obj_other/Draw_0.gml", "draw_txt(); array_lenght();"),
+        ],
+        2,
+    );
+}
+
+#[test]
+fn unused_allow() {
+    harness_lint::<UnusedAllow>(
+        "
+            // #[allow(draw_text)]
+            draw_sprite(foo, x, y);
+        ",
+        1,
+    );
+}
+
 #[test]
 fn unused_local_variable() {
     harness_lint::<crate::lint::collection::UnusedLocalVariable>(
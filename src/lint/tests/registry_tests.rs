@@ -0,0 +1,118 @@
+use crate::{
+    Config, Duck, FileId,
+    lint::{
+        Lint, LintLevel, LintRegistry,
+        collection::*,
+        tests::lint_tests::{config_for_lint, harness_lint_with_registry},
+    },
+    parse::{Call, Expr, ExprKind},
+};
+use codespan_reporting::diagnostic::{Diagnostic, Label};
+
+#[derive(Debug, PartialEq, Default)]
+struct NoFooCalls;
+impl Lint for NoFooCalls {
+    fn explanation(&self) -> &'static str {
+        "Calls to `foo` are not allowed."
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warn
+    }
+
+    fn tag(&self) -> &'static str {
+        "no_foo_calls"
+    }
+
+    fn visit_expr_early(&self, expr: &Expr, config: &Config, reports: &mut Vec<Diagnostic<FileId>>) {
+        if let ExprKind::Call(Call { left, .. }) = expr.kind() {
+            if left
+                .kind()
+                .as_identifier()
+                .is_some_and(|identifier| identifier.lexeme == "foo")
+            {
+                reports.push(
+                    self.diagnostic(config)
+                        .with_message("Call to `foo`")
+                        .with_labels(vec![Label::primary(left.file_id(), left.span())]),
+                );
+            }
+        }
+    }
+}
+
+fn registry_with_custom_lint() -> LintRegistry {
+    let mut lints = LintRegistry::default();
+    lints.register(NoFooCalls);
+    lints
+}
+
+#[test]
+fn builtin_lints_registered() {
+    let lints = LintRegistry::builtin();
+    assert!(lints.contains(DrawText.tag()));
+    assert!(!lints.contains(NoFooCalls.tag()));
+}
+
+#[test]
+fn custom_lint_runs() {
+    harness_lint_with_registry::<NoFooCalls>(
+        config_for_lint::<NoFooCalls>(),
+        &registry_with_custom_lint(),
+        "foo(); bar(); foo(1);",
+        2,
+    );
+}
+
+#[test]
+fn custom_lint_can_be_allowed() {
+    harness_lint_with_registry::<NoFooCalls>(
+        config_for_lint::<NoFooCalls>(),
+        &registry_with_custom_lint(),
+        "
+            // #[allow(no_foo_calls)]
+            foo();
+            foo();
+        ",
+        1,
+    );
+}
+
+#[test]
+fn custom_lint_is_not_unknown() {
+    harness_lint_with_registry::<UnknownLint>(
+        config_for_lint::<UnknownLint>(),
+        &registry_with_custom_lint(),
+        "
+            // #[allow(no_foo_calls, no_bar_calls)]
+            foo();
+        ",
+        1,
+    );
+}
+
+#[test]
+fn unregistered_lints_do_not_run() {
+    let mut config = config_for_lint::<DrawText>();
+    config.lint_levels.insert(NoFooCalls.tag().into(), LintLevel::Deny);
+    harness_lint_with_registry::<NoFooCalls>(config, &LintRegistry::new(), "foo(); draw_text(0, 0, foo);", 0);
+}
+
+#[test]
+fn register_replaces_lint_with_same_tag() {
+    let mut lints = LintRegistry::new();
+    lints.register(NoFooCalls);
+    lints.register(NoFooCalls);
+    assert_eq!(lints.iter().count(), 1);
+}
+
+#[test]
+fn duck_explains_custom_lints() {
+    let mut duck = Duck::default();
+    assert!(duck.lints().get(NoFooCalls.tag()).is_none());
+    duck.lints_mut().register(NoFooCalls);
+    assert_eq!(
+        duck.lints().get(NoFooCalls.tag()).map(|lint| lint.explanation()),
+        Some(NoFooCalls.explanation())
+    );
+}
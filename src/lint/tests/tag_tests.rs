@@ -0,0 +1,610 @@
+use crate::{
+    Config, GmlLibrary, RunSummary,
+    analyze::Project,
+    driver,
+    lint::{
+        Date, Lint, LintLevel, LintRegistry, Suppression, SuppressionIssue,
+        collection::*,
+        tests::lint_tests::{config_for_lint, harness_lint, harness_lint_with_config},
+    },
+    parse::*,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn allow_covers_statement() {
+    harness_lint::<DrawText>(
+        "
+            // #[allow(draw_text)]
+            draw_text(foo, x, y);
+            draw_text(foo, x, y);
+        ",
+        1,
+    );
+}
+
+#[test]
+fn allow_covers_function_body() {
+    harness_lint::<DrawText>(
+        "
+            // #[allow(draw_text)]
+            function foo() {
+                draw_text(foo, x, y);
+                if bar {
+                    draw_text(foo, x, y);
+                }
+            }
+            draw_text(foo, x, y);
+        ",
+        1,
+    );
+}
+
+#[test]
+fn allow_covers_block() {
+    harness_lint::<DrawText>(
+        "
+            if foo {
+                // #[allow(draw_text)]
+                {
+                    draw_text(foo, x, y);
+                    draw_text(foo, x, y);
+                }
+                draw_text(foo, x, y);
+            }
+        ",
+        1,
+    );
+}
+
+#[test]
+fn allow_covers_region() {
+    harness_lint::<DrawText>(
+        "
+            // #[allow(draw_text)]
+            #region Drawing
+            draw_text(foo, x, y);
+            draw_text(foo, x, y);
+            #endregion
+            draw_text(foo, x, y);
+        ",
+        1,
+    );
+}
+
+#[test]
+fn allow_covers_file() {
+    harness_lint::<DrawText>(
+        "
+            // #![allow(draw_text)]
+            draw_text(foo, x, y);
+            function foo() {
+                draw_text(foo, x, y);
+            }
+        ",
+        0,
+    );
+}
+
+#[test]
+fn allow_multiple_lints() {
+    harness_lint::<DrawText>(
+        "
+            // #[allow(draw_sprite, draw_text)]
+            draw_text(foo, x, y);
+        ",
+        0,
+    );
+}
+
+#[test]
+fn stacked_tags() {
+    harness_lint::<DrawText>(
+        "
+            // #[allow(draw_text)]
+            // #[allow(draw_sprite)]
+            draw_text(foo, x, y);
+        ",
+        0,
+    );
+}
+
+#[test]
+fn allow_other_lint() {
+    harness_lint::<DrawText>(
+        "
+            // #[allow(draw_sprite)]
+            draw_text(foo, x, y);
+        ",
+        1,
+    );
+}
+
+#[test]
+fn warn_raises_level() {
+    let mut config = config_for_lint::<DrawText>();
+    config.lint_levels.insert(DrawText.tag().into(), LintLevel::Allow);
+    harness_lint_with_config::<DrawText>(
+        config,
+        "
+            draw_text(foo, x, y);
+            // #[warn(draw_text)]
+            function foo() {
+                draw_text(foo, x, y);
+            }
+        ",
+        1,
+    );
+}
+
+#[test]
+fn deny_raises_level_for_file() {
+    let mut config = config_for_lint::<DrawText>();
+    config.lint_levels.insert(DrawText.tag().into(), LintLevel::Allow);
+    harness_lint_with_config::<DrawText>(
+        config,
+        "
+            // #![deny(draw_text)]
+            draw_text(foo, x, y);
+            draw_text(foo, x, y);
+        ",
+        2,
+    );
+}
+
+#[test]
+fn nested_allow_overrides_file_deny() {
+    harness_lint::<DrawText>(
+        "
+            // #![deny(draw_text)]
+            draw_text(foo, x, y);
+            // #[allow(draw_text)]
+            draw_text(foo, x, y);
+        ",
+        1,
+    );
+}
+
+#[test]
+fn tags_attach_to_compound_statements() {
+    let ast = Parser::new_with_default_ids("// #[allow(foo)]\nif a { b(); }", 0)
+        .into_ast()
+        .unwrap();
+    assert!(ast.stmts()[0].tags() == [("allow", Some("foo"))]);
+}
+
+#[test]
+fn region_tags_attach_to_each_statement() {
+    let ast = Parser::new_with_default_ids("// #[allow(foo)]\n#region\na();\n{ b(); }\n#endregion\nc();", 0)
+        .into_ast()
+        .unwrap();
+    assert!(ast.stmts()[0].tags() == [("allow", Some("foo"))]);
+    assert_eq!(ast.stmts()[0].tags(), ast.stmts()[1].tags());
+    assert!(ast.stmts()[1].kind().as_block().unwrap().body[0].tags().is_empty());
+    assert!(ast.stmts()[2].tags().is_empty());
+}
+
+#[test]
+fn inner_tags_attach_to_ast() {
+    let ast = Parser::new_with_default_ids("// #![allow(global)]\nglobal.a = 0;", 0)
+        .into_ast()
+        .unwrap();
+    assert!(ast.tags() == [("allow", Some("global"))]);
+    assert!(ast.stmts()[0].tags().is_empty());
+}
+
+#[test]
+fn tags_keep_their_location() {
+    let ast = Parser::new_with_default_ids("a();\n// #[allow(foo)]\nb();", 0)
+        .into_ast()
+        .unwrap();
+    let Location(_, span) = ast.stmts()[1].tags()[0].location();
+    assert_eq!((span.start(), span.end()), (5, 21));
+}
+
+#[test]
+fn unknown_lint_in_region_reported_once() {
+    harness_lint::<UnknownLint>(
+        "
+            // #[allow(draw_txt)]
+            #region
+            draw_text(foo, x, y);
+            draw_text(foo, x, y);
+            #endregion
+        ",
+        1,
+    );
+}
+
+#[test]
+fn unknown_lint_reports_each_name() {
+    harness_lint::<UnknownLint>(
+        "
+            // #![deny(not_a_lint)]
+            // #[allow(draw_txt, draw_sprit, draw_text)]
+            draw_text(foo, x, y);
+        ",
+        3,
+    );
+}
+
+#[test]
+fn unknown_lint_can_be_allowed() {
+    harness_lint::<UnknownLint>(
+        "
+            // #[allow(unknown_lint)]
+            function foo() {
+                // #[allow(draw_txt)]
+                draw_text(foo, x, y);
+            }
+        ",
+        0,
+    );
+}
+
+fn config_for_unused_allow() -> Config {
+    let mut config = config_for_lint::<UnusedAllow>();
+    config.lint_levels.insert(DrawText.tag().into(), LintLevel::Warn);
+    config.lint_levels.insert(DrawSprite.tag().into(), LintLevel::Warn);
+    config
+}
+
+#[test]
+fn used_allow() {
+    harness_lint_with_config::<UnusedAllow>(
+        config_for_unused_allow(),
+        "
+            // #[allow(draw_text)]
+            draw_text(foo, x, y);
+        ",
+        0,
+    );
+}
+
+#[test]
+fn allow_for_lint_disabled_by_config() {
+    harness_lint::<UnusedAllow>(
+        "
+            // #[allow(draw_text)]
+            draw_text(foo, x, y);
+        ",
+        1,
+    );
+}
+
+#[test]
+fn used_allow_on_nested_code() {
+    harness_lint_with_config::<UnusedAllow>(
+        config_for_unused_allow(),
+        "
+            // #[allow(draw_text)]
+            function foo() {
+                if bar {
+                    baz(function() {
+                        draw_text(foo, x, y);
+                    });
+                }
+            }
+        ",
+        0,
+    );
+}
+
+#[test]
+fn used_allow_for_project_lint() {
+    let mut config = config_for_lint::<UnusedAllow>();
+    config.lint_levels.insert(UnusedFunction.tag().into(), LintLevel::Warn);
+    harness_lint_with_config::<UnusedAllow>(
+        config,
+        "
+            // #[allow(unused_function)]
+            function foo() {}
+        ",
+        0,
+    );
+}
+
+#[test]
+fn partially_used_allow() {
+    harness_lint_with_config::<UnusedAllow>(
+        config_for_unused_allow(),
+        "
+            // #[allow(draw_text, draw_sprite)]
+            draw_text(foo, x, y);
+        ",
+        1,
+    );
+}
+
+#[test]
+fn used_region_allow() {
+    harness_lint_with_config::<UnusedAllow>(
+        config_for_unused_allow(),
+        "
+            // #[allow(draw_text)]
+            #region
+            foo();
+            draw_text(foo, x, y);
+            bar();
+            #endregion
+        ",
+        0,
+    );
+}
+
+#[test]
+fn unused_region_allow_reported_once() {
+    harness_lint_with_config::<UnusedAllow>(
+        config_for_unused_allow(),
+        "
+            // #[allow(draw_text)]
+            #region
+            foo();
+            bar();
+            #endregion
+        ",
+        1,
+    );
+}
+
+#[test]
+fn used_file_allow() {
+    harness_lint_with_config::<UnusedAllow>(
+        config_for_unused_allow(),
+        "
+            // #![allow(draw_text)]
+            function foo() {
+                draw_text(foo, x, y);
+            }
+        ",
+        0,
+    );
+}
+
+#[test]
+fn outer_allow_shadowed_by_inner_allow() {
+    harness_lint_with_config::<UnusedAllow>(
+        config_for_unused_allow(),
+        "
+            // #[allow(draw_text)]
+            function foo() {
+                // #[allow(draw_text)]
+                draw_text(foo, x, y);
+            }
+        ",
+        1,
+    );
+}
+
+#[test]
+fn allow_overridden_by_warn() {
+    harness_lint_with_config::<UnusedAllow>(
+        config_for_unused_allow(),
+        "
+            // #[allow(draw_text)]
+            function foo() {
+                // #[warn(draw_text)]
+                draw_text(foo, x, y);
+            }
+        ",
+        2,
+    );
+}
+
+#[test]
+fn unused_allow_can_be_allowed() {
+    harness_lint_with_config::<UnusedAllow>(
+        config_for_unused_allow(),
+        "
+            // #[allow(unused_allow, draw_sprite)]
+            draw_text(foo, x, y);
+        ",
+        1,
+    );
+}
+
+fn tag_from(source: &'static str) -> Tag {
+    let ast = Parser::new_with_default_ids(source, 0).into_ast().unwrap();
+    ast.stmts()[0].tags()[0].clone()
+}
+
+#[test]
+fn tag_attributes() {
+    let tag =
+        tag_from("// #[allow(draw_text, reason = \"menus (for now), then later\", until = \"2026-12-31\")]\nfoo();");
+    assert_eq!(tag.parameters().collect::<Vec<_>>(), vec!["draw_text"]);
+    assert_eq!(tag.reason(), Some("menus (for now), then later"));
+    assert_eq!(tag.until(), Some("2026-12-31"));
+    assert_eq!(tag.attribute("something_else"), None);
+}
+
+#[test]
+fn dates() {
+    assert_eq!(Date::parse("1970-01-01"), Some(Date::from_days_since_epoch(0)));
+    assert_eq!(Date::parse("1969-12-31"), Some(Date::from_days_since_epoch(-1)));
+    assert_eq!(Date::parse("2000-02-29"), Some(Date::from_days_since_epoch(11016)));
+    assert_eq!(Date::parse("2024-01-01"), Some(Date::from_days_since_epoch(19723)));
+    assert!(Date::parse("2024-01-01") < Date::parse("2024-01-02"));
+    assert_eq!(Date::parse("2024-1-01"), None);
+    assert_eq!(Date::parse("2024-13-01"), None);
+    assert_eq!(Date::parse("tomorrow"), None);
+}
+
+#[test]
+fn impossible_dates() {
+    assert_eq!(Date::parse("2026-02-31"), None);
+    assert_eq!(Date::parse("2026-04-31"), None);
+    assert_eq!(Date::parse("2026-02-29"), None);
+    assert_eq!(Date::parse("1900-02-29"), None);
+    assert_eq!(Date::parse("2024-02-29"), Some(Date::from_days_since_epoch(19782)));
+    assert_eq!(Date::parse("2026-12-31"), Some(Date::from_days_since_epoch(20818)));
+    assert_eq!(Date::parse("2026-06-00"), None);
+}
+
+#[test]
+fn allow_issues() {
+    let mut config = Config {
+        require_allow_reasons: vec!["global".into()],
+        ..Default::default()
+    };
+    let tag = tag_from("// #[allow(global, draw_text)]\nfoo();");
+    assert_eq!(
+        config.allow_issue(&tag, "global"),
+        Some(SuppressionIssue::MissingReason)
+    );
+    assert_eq!(config.allow_issue(&tag, "draw_text"), None);
+    let tag = tag_from("// #[allow(global, reason = \"legacy\", until = \"2000-01-01\")]\nfoo();");
+    assert_eq!(
+        config.allow_issue(&tag, "global"),
+        Some(SuppressionIssue::Expired("2000-01-01".into()))
+    );
+    config.expire_allows = false;
+    assert_eq!(config.allow_issue(&tag, "global"), None);
+}
+
+#[test]
+fn required_reason_missing() {
+    let mut config = config_for_lint::<DrawText>();
+    config.require_allow_reasons = vec![DrawText.tag().into()];
+    harness_lint_with_config::<DrawText>(
+        config,
+        "
+            // #[allow(draw_text)]
+            draw_text(foo, x, y);
+        ",
+        1,
+    );
+}
+
+#[test]
+fn required_reason_given() {
+    let mut config = config_for_lint::<DrawText>();
+    config.require_allow_reasons = vec!["*".into()];
+    harness_lint_with_config::<DrawText>(
+        config,
+        "
+            // #[allow(draw_text, reason = \"debug overlay\")]
+            draw_text(foo, x, y);
+        ",
+        0,
+    );
+}
+
+#[test]
+fn expired_allow() {
+    harness_lint::<DrawText>(
+        "
+            // #[allow(draw_text, until = \"2000-01-01\")]
+            draw_text(foo, x, y);
+        ",
+        1,
+    );
+}
+
+#[test]
+fn unexpired_allow() {
+    harness_lint::<DrawText>(
+        "
+            // #[allow(draw_text, until = \"2999-12-31\")]
+            draw_text(foo, x, y);
+        ",
+        0,
+    );
+}
+
+#[test]
+fn expiry_disabled() {
+    let mut config = config_for_lint::<DrawText>();
+    config.expire_allows = false;
+    harness_lint_with_config::<DrawText>(
+        config,
+        "
+            // #[allow(draw_text, until = \"2000-01-01\")]
+            draw_text(foo, x, y);
+        ",
+        0,
+    );
+}
+
+#[test]
+fn missing_reason_reported() {
+    let mut config = config_for_lint::<InvalidAllow>();
+    config.require_allow_reasons = vec![DrawText.tag().into()];
+    harness_lint_with_config::<InvalidAllow>(
+        config,
+        "
+            // #[allow(draw_text)]
+            draw_text(foo, x, y);
+            // #[allow(draw_text, reason = \"debug overlay\")]
+            draw_text(foo, x, y);
+            // #[allow(draw_sprite)]
+            draw_sprite(foo, x, y);
+        ",
+        1,
+    );
+}
+
+#[test]
+fn impossible_date_reported() {
+    harness_lint::<InvalidAllow>(
+        "
+            // #[allow(draw_text, until = \"2026-02-31\")]
+            draw_text(foo, x, y);
+            // #[allow(draw_text, until = \"2999-04-31\")]
+            draw_text(foo, x, y);
+            // #[allow(draw_text, until = \"2999-04-30\")]
+            draw_text(foo, x, y);
+        ",
+        2,
+    );
+}
+
+fn suppressions_of(source: &'static str) -> (GmlLibrary, Vec<Suppression>) {
+    let mut library = GmlLibrary::new();
+    let file_id = library.add("test.gml".into(), source);
+    let ast = Parser::new_with_default_ids(source, file_id).into_ast().unwrap();
+    let mut config = config_for_lint::<DrawText>();
+    config.lint_levels.insert(DrawSprite.tag().into(), LintLevel::Deny);
+    let reports = driver::process_file(&ast, &config, &LintRegistry::default(), &Project::new());
+    (library, reports.suppressions)
+}
+
+#[test]
+fn suppressions_collected() {
+    let (_, suppressions) = suppressions_of(
+        "
+            // #[allow(draw_text, reason = \"debug overlay\")]
+            function foo() {
+                draw_text(foo, x, y);
+                // #[allow(draw_sprite)]
+                draw_sprite(foo, x, y);
+            }
+            draw_text(foo, x, y);
+        ",
+    );
+    assert_eq!(suppressions.len(), 2);
+    assert_eq!(suppressions[0].diagnostic().code.as_deref(), Some("draw_text"));
+    assert_eq!(suppressions[0].tag().reason(), Some("debug overlay"));
+    assert_eq!(suppressions[1].diagnostic().code.as_deref(), Some("draw_sprite"));
+    assert_eq!(suppressions[1].tag().reason(), None);
+}
+
+#[test]
+fn suppression_reasons_in_output() {
+    let (library, suppressions) = suppressions_of(
+        "
+            // #[allow(draw_text, reason = \"debug overlay\", until = \"2999-12-31\")]
+            draw_text(foo, x, y);
+        ",
+    );
+    let summary = RunSummary::new(library, vec![], suppressions, vec![], 0);
+    let json: serde_json::Value = serde_json::from_str(&summary.to_json()).unwrap();
+    assert_eq!(json["suppressed"][0]["lint"], "draw_text");
+    assert_eq!(json["suppressed"][0]["reason"], "debug overlay");
+    assert_eq!(json["suppressed"][0]["until"], "2999-12-31");
+    let sarif: serde_json::Value = serde_json::from_str(&summary.to_sarif()).unwrap();
+    let result = &sarif["runs"][0]["results"][0];
+    assert_eq!(result["ruleId"], "draw_text");
+    assert_eq!(result["suppressions"][0]["kind"], "inSource");
+    assert_eq!(result["suppressions"][0]["justification"], "debug overlay");
+}
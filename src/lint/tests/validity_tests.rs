@@ -12,7 +12,7 @@ fn harness_valid(source: &'static str) {
     assert!(parser.is_ok_and(|v| v.stmts().len() == 1), "`{}` was invalid!", source)
 }
 
-fn harness_valid_but_linted<T: Lint>(source: &'static str) {
+fn harness_valid_but_linted<T: Lint + Default>(source: &'static str) {
     let parser = Parser::new_with_default_ids(source, 0).into_ast();
     assert!(parser.is_ok_and(|v| v.stmts().len() == 1), "`{}` was invalid!", source);
     harness_lint::<T>(source, 1);
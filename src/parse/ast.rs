@@ -1,17 +1,40 @@
 use crate::parse::ParseVisitor;
 
-use super::{Expr, Stmt};
+use super::{Expr, Location, Stmt};
 
 /// A collection of statements.
 #[derive(Debug, Clone, serde::Serialize)]
 #[serde(rename_all = "snake_case")]
 pub struct Ast {
     stmts: Vec<Stmt>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<Tag>,
+    #[serde(skip)]
+    source: &'static str,
 }
 impl Ast {
     /// Creates a new Ast with the given statements.
     pub fn new(stmts: Vec<Stmt>) -> Self {
-        Self { stmts }
+        Self {
+            stmts,
+            tags: vec![],
+            source: "",
+        }
+    }
+
+    /// Creates a new Ast with the given statements and file-level tags.
+    pub fn new_with_tags(stmts: Vec<Stmt>, tags: Vec<Tag>) -> Self {
+        Self {
+            stmts,
+            tags,
+            source: "",
+        }
+    }
+
+    /// Returns the Ast with the source code it was parsed from attached.
+    pub fn with_source(mut self, source: &'static str) -> Self {
+        self.source = source;
+        self
     }
 
     /// Consumes the Ast into its inner collection of statements.
@@ -29,14 +52,24 @@ impl Ast {
         &mut self.stmts
     }
 
+    /// Returns the source code the Ast was parsed from, or an empty string if it was built by hand.
+    pub fn source(&self) -> &'static str {
+        self.source
+    }
+
+    /// Returns the file-level tags of the ast, written as inner tags (ie: `// #![allow(global)]`).
+    /// These apply to every statement in the file.
+    pub fn tags(&self) -> &[Tag] {
+        &self.tags
+    }
+
     /// Returns all nodes in the ast that match the provided tag, or have any tag at all if None is
     /// provided.
     pub fn tagged_nodes(&self, tag: Option<&Tag>) -> Vec<Node> {
         fn collect_tagged_exprs(expr: &Expr, nodes: &mut Vec<Node>, tag: Option<&Tag>) {
-            let matches = match (tag, expr.tag()) {
-                (None, Some(_)) => true,
-                (Some(t1), Some(t2)) if t1 == t2 => true,
-                _ => false,
+            let matches = match tag {
+                None => !expr.tags().is_empty(),
+                Some(tag) => expr.tags().contains(tag),
             };
             if matches {
                 nodes.push(Node::Expr(expr.clone()));
@@ -45,10 +78,9 @@ impl Ast {
             expr.visit_child_exprs(|expr| collect_tagged_exprs(expr, nodes, tag));
         }
         fn collect_tagged_stmts(stmt: &Stmt, nodes: &mut Vec<Node>, tag: Option<&Tag>) {
-            let matches = match (tag, stmt.tag()) {
-                (None, Some(_)) => true,
-                (Some(t1), Some(t2)) if t1 == t2 => true,
-                _ => false,
+            let matches = match tag {
+                None => !stmt.tags().is_empty(),
+                Some(tag) => stmt.tags().contains(tag),
             };
             if matches {
                 nodes.push(Node::Stmt(stmt.clone()));
@@ -68,9 +100,64 @@ impl Ast {
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, Default, serde::Serialize)]
 pub struct AstId(u64);
 
-/// The data from a user-written tag (ie: #[allow(draw_text)])
+/// The data from a user-written tag (ie: #[allow(draw_text)]), along with the location of the
+/// comment it was written in.
 #[derive(Debug, Clone, PartialEq, serde::Serialize)]
-pub struct Tag(pub String, pub Option<String>);
+pub struct Tag(pub String, pub Option<String>, pub Location);
+impl Tag {
+    /// Returns the tag's label (ie: `allow` in `#[allow(draw_text)]`).
+    pub fn label(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns an iterator over the comma separated entries in the tag's parameter (ie: `draw_text`
+    /// and `draw_sprite` in `#[allow(draw_text, draw_sprite)]`), excluding any attributes.
+    pub fn parameters(&self) -> impl Iterator<Item = &str> {
+        self.entries().filter(|entry| !entry.contains('='))
+    }
+
+    /// Returns the value of the attribute with the given key in the tag's parameter, with its
+    /// quotes removed (ie: `legacy code` for the key `reason` in `#[allow(global, reason =
+    /// "legacy code")]`).
+    pub fn attribute(&self, key: &str) -> Option<&str> {
+        self.entries().find_map(|entry| {
+            let (entry_key, value) = entry.split_once('=')?;
+            (entry_key.trim() == key).then(|| value.trim().trim_matches('"'))
+        })
+    }
+
+    /// Returns the reason given for this tag, if any (ie: `#[allow(global, reason = "...")]`).
+    pub fn reason(&self) -> Option<&str> {
+        self.attribute("reason")
+    }
+
+    /// Returns the date this tag is valid until, if any (ie: `#[allow(global, until =
+    /// "2026-12-31")]`).
+    pub fn until(&self) -> Option<&str> {
+        self.attribute("until")
+    }
+
+    /// Returns an iterator over every comma separated entry in the tag's parameter, ignoring commas
+    /// within quotes.
+    fn entries(&self) -> impl Iterator<Item = &str> {
+        let parameter = self.1.as_deref().unwrap_or_default();
+        let mut in_quotes = false;
+        parameter
+            .split(move |chr| {
+                if chr == '"' {
+                    in_quotes = !in_quotes;
+                }
+                chr == ',' && !in_quotes
+            })
+            .map(|entry| entry.trim())
+            .filter(|entry| !entry.is_empty())
+    }
+
+    /// Returns the location of the comment the tag was written in.
+    pub fn location(&self) -> Location {
+        self.2
+    }
+}
 impl PartialEq<(&str, Option<&str>)> for Tag {
     fn eq(&self, other: &(&str, Option<&str>)) -> bool {
         self.0 == other.0 && self.1.as_deref() == other.1
@@ -87,11 +174,11 @@ pub enum Node {
     Expr(Expr),
 }
 impl Node {
-    /// Returns the tag the node contains.
-    pub fn tag(&self) -> Option<&Tag> {
+    /// Returns the tags the node contains.
+    pub fn tags(&self) -> &[Tag] {
         match self {
-            Node::Stmt(stmt) => stmt.tag(),
-            Node::Expr(expr) => expr.tag(),
+            Node::Stmt(stmt) => stmt.tags(),
+            Node::Expr(expr) => expr.tags(),
         }
     }
 }
@@ -124,8 +124,8 @@ pub struct Expr {
     id: ExprId,
     #[serde(skip)]
     location: Location,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    tag: Option<Tag>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<Tag>,
 }
 impl Expr {
     /// Get a reference to the inner ExprKind.
@@ -150,9 +150,13 @@ impl Expr {
     pub fn file_id(&self) -> FileId {
         self.location().0
     }
-    /// Returns the tag attached to this statement, if any.
-    pub fn tag(&self) -> Option<&Tag> {
-        self.tag.as_ref()
+    /// Returns the tags attached to this expression.
+    pub fn tags(&self) -> &[Tag] {
+        &self.tags
+    }
+    /// Returns a mutable reference to the tags attached to this expression.
+    pub fn tags_mut(&mut self) -> &mut Vec<Tag> {
+        &mut self.tags
     }
 
     /// Get the expr's id.
@@ -353,12 +357,12 @@ impl std::fmt::Display for Expr {
 /// `into_expr` method, and a `into_expr_lazy` for tests.
 pub trait IntoExpr: Sized + Into<ExprKind> {
     /// Converts self into an Expr.
-    fn into_expr(self, id: ExprId, span: Span, file_id: FileId, tag: Option<Tag>) -> Expr {
+    fn into_expr(self, id: ExprId, span: Span, file_id: FileId, tags: Vec<Tag>) -> Expr {
         Expr {
             expr_type: Box::new(self.into()),
             id,
             location: Location(file_id, span),
-            tag,
+            tags,
         }
     }
 
@@ -367,7 +371,7 @@ pub trait IntoExpr: Sized + Into<ExprKind> {
     where
         Self: Sized,
     {
-        self.into_expr(ExprId::default(), Default::default(), 0, None)
+        self.into_expr(ExprId::default(), Default::default(), 0, vec![])
     }
 }
 
@@ -30,6 +30,20 @@ pub enum Literal {
     Misc(String),
 }
 
+impl Literal {
+    /// Returns the numerical value of the literal, if it is a number. Hex numbers and booleans
+    /// (which are just `1` and `0` in gml) count as numbers.
+    pub fn as_number(&self) -> Option<f64> {
+        match self {
+            Literal::Real(value) => Some(*value),
+            Literal::Hex(lexeme) => u64::from_str_radix(lexeme, 16).ok().map(|value| value as f64),
+            Literal::True => Some(1.0),
+            Literal::False => Some(0.0),
+            _ => None,
+        }
+    }
+}
+
 type StructField = (Identifier, Expr);
 #[derive(serde::Serialize)]
 struct SerializedStructField {
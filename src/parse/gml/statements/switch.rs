@@ -114,11 +114,24 @@ impl ParseVisitor for Switch {
 pub struct SwitchCase {
     identity: Expr,
     body: Vec<Stmt>,
+    #[serde(skip)]
+    fallthrough_comment: bool,
 }
 impl SwitchCase {
     /// Creates a new GmlSwitchCase with the given identity and body.
     pub fn new(identity: Expr, body: Vec<Stmt>) -> Self {
-        Self { identity, body }
+        Self {
+            identity,
+            body,
+            fallthrough_comment: false,
+        }
+    }
+
+    /// Marks whether the case's body ends with a `// fallthrough` comment, declaring that it is
+    /// intended to fall through into the next case.
+    pub fn with_fallthrough_comment(mut self, fallthrough_comment: bool) -> Self {
+        self.fallthrough_comment = fallthrough_comment;
+        self
     }
 
     /// Returns a reference to the case's identity.
@@ -130,4 +143,14 @@ impl SwitchCase {
     pub fn iter_body_statements(&self) -> impl Iterator<Item = &Stmt> {
         self.body.iter()
     }
+
+    /// Returns the statements in the body of the case.
+    pub fn body(&self) -> &[Stmt] {
+        &self.body
+    }
+
+    /// Returns whether the case's body ends with a `// fallthrough` comment.
+    pub fn has_fallthrough_comment(&self) -> bool {
+        self.fallthrough_comment
+    }
 }
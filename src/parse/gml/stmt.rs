@@ -200,8 +200,8 @@ pub struct Stmt {
     id: StmtId,
     #[serde(skip)]
     location: Location,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    tag: Option<Tag>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<Tag>,
 }
 impl Stmt {
     /// Returns a reference to the inner StmtKind.
@@ -224,9 +224,13 @@ impl Stmt {
     pub fn file_id(&self) -> FileId {
         self.location().0
     }
-    /// Returns the tag attached to this statement, if any.
-    pub fn tag(&self) -> Option<&Tag> {
-        self.tag.as_ref()
+    /// Returns the tags attached to this statement.
+    pub fn tags(&self) -> &[Tag] {
+        &self.tags
+    }
+    /// Returns a mutable reference to the tags attached to this statement.
+    pub fn tags_mut(&mut self) -> &mut Vec<Tag> {
+        &mut self.tags
     }
 
     /// Get the stmt's id.
@@ -319,12 +323,12 @@ impl std::fmt::Display for Stmt {
 /// `into_stmt` method, and a `into_stmt_lazy` for tests.
 pub trait IntoStmt: Sized + Into<StmtKind> {
     /// Converts self into an statement box.
-    fn into_stmt(self, id: StmtId, span: Span, file_id: FileId, tag: Option<Tag>) -> Stmt {
+    fn into_stmt(self, id: StmtId, span: Span, file_id: FileId, tags: Vec<Tag>) -> Stmt {
         Stmt {
             stmt_type: Box::new(self.into()),
             id,
             location: Location(file_id, span),
-            tag,
+            tags,
         }
     }
 
@@ -334,7 +338,7 @@ pub trait IntoStmt: Sized + Into<StmtKind> {
     where
         Self: Sized,
     {
-        self.into_stmt(Default::default(), Default::default(), 0, None)
+        self.into_stmt(Default::default(), Default::default(), 0, vec![])
     }
 }
 
@@ -232,6 +232,9 @@ pub enum TokenKind {
     Real(f64),
     StringLiteral(&'static str),
     Tag(&'static str, Option<&'static str>),
+    InnerTag(&'static str, Option<&'static str>),
+    Region(&'static str),
+    EndRegion,
     Hex(&'static str),
     MiscConstant(&'static str),
     Invalid(&'static str),
@@ -351,6 +354,15 @@ impl Display for TokenKind {
                     return f.pad(&format!("// #[{label}]"));
                 }
             }
+            TokenKind::InnerTag(label, param) => {
+                if let Some(param) = param {
+                    return f.pad(&format!("// #![{label}({param})]"));
+                } else {
+                    return f.pad(&format!("// #![{label}]"));
+                }
+            }
+            TokenKind::Region(name) => return f.pad(&format!("#region {name}")),
+            TokenKind::EndRegion => "#endregion",
             TokenKind::Hex(hex) => hex,
             TokenKind::MiscConstant(con) => con,
             TokenKind::Invalid(_) => "INVALID_TOKEN",
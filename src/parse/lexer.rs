@@ -1,7 +1,7 @@
 use super::{Span, Token, TokenKind};
 use hashbrown::HashSet;
 use once_cell::sync::Lazy;
-use std::iter::Peekable;
+use std::{iter::Peekable, ops::Range};
 use unicode_segmentation::{GraphemeIndices, UnicodeSegmentation};
 
 /// Takes gml and converts it into tokens as an iterator.
@@ -9,6 +9,7 @@ pub struct Lexer {
     source: &'static str,
     input_characters: Peekable<GraphemeIndices<'static>>,
     next_char_boundary: usize,
+    token_start: usize,
 }
 impl Lexer {
     /// Creates a new Lexer, taking a string of gml source.
@@ -17,12 +18,21 @@ impl Lexer {
             source,
             input_characters: source.grapheme_indices(true).peekable(),
             next_char_boundary: 1,
+            token_start: 0,
         }
     }
 
+    /// Converts the Lexer into an iterator that also yields the exact byte range each token
+    /// occupies in the source. Anything between two ranges (whitespace, regions, malformed tags)
+    /// was skipped over by the lexer.
+    pub fn with_offsets(self) -> LexerWithOffsets {
+        LexerWithOffsets(self)
+    }
+
     /// Consumes the Lexer's source code until it identifies the next Token.
     fn lex(&mut self) -> Token {
         if let Some((start_index, chr)) = self.take() {
+            self.token_start = start_index;
             let token_type = match chr {
                 id if id.is_whitespace() => return self.lex(),
                 '.' => {
@@ -248,11 +258,16 @@ impl Lexer {
                             TokenKind::Macro(name, config, body),
                             Span::new(start_index, self.next_char_boundary),
                         )
-                    } else if self.match_take_str("#region", start_index)
-                        || self.match_take_str("#endregion", start_index)
-                    {
+                    } else if self.match_take_str("#region", start_index) {
+                        self.consume_whitespace_on_line(self.next_char_boundary);
+                        let name = self.consume_rest_of_line(self.next_char_boundary);
+                        Token::new(
+                            TokenKind::Region(name.trim_end()),
+                            Span::new(start_index, self.next_char_boundary),
+                        )
+                    } else if self.match_take_str("#endregion", start_index) {
                         self.discard_rest_of_line();
-                        self.lex()
+                        Token::new(TokenKind::EndRegion, Span::new(start_index, self.next_char_boundary))
                     } else {
                         Token::new(TokenKind::Hash, Span::new(start_index, self.next_char_boundary))
                     };
@@ -273,11 +288,13 @@ impl Lexer {
                         // Eat up the whitespace first...
                         self.consume_whitespace_on_line(start_index);
 
-                        // See if this is an lint tag...
-                        if self.match_take('#') && self.match_take('[') {
+                        // See if this is an lint tag (or an inner tag, which applies to the whole file)...
+                        let is_tag = self.match_take('#');
+                        let is_inner = is_tag && self.match_take('!');
+                        if is_tag && self.match_take('[') {
                             let tag = self.construct_word(self.next_char_boundary);
                             let parameter = if self.match_take('(') {
-                                let parameter = self.consume_until(self.next_char_boundary, ')');
+                                let parameter = self.consume_tag_parameter(self.next_char_boundary);
                                 self.match_take(')');
                                 Some(parameter)
                             } else {
@@ -285,7 +302,11 @@ impl Lexer {
                             };
                             if self.match_take(']') {
                                 self.consume_rest_of_line(self.next_char_boundary);
-                                Some(TokenKind::Tag(tag, parameter))
+                                if is_inner {
+                                    Some(TokenKind::InnerTag(tag, parameter))
+                                } else {
+                                    Some(TokenKind::Tag(tag, parameter))
+                                }
                             } else {
                                 return self.lex();
                             }
@@ -397,10 +418,18 @@ impl Lexer {
         &self.source[start_pos..self.next_char_boundary]
     }
 
-    /// Consumes until reaching the given character, pausing before taking it.
-    fn consume_until(&mut self, start_pos: usize, c: char) -> &'static str {
-        while self.peek().is_some_and(|chr| chr != c) {
-            self.take().unwrap();
+    /// Consumes the parameter of a tag up until its closing parenthesis, skipping over any found
+    /// within quotes (ie: `reason = "see (this)"`). Stops at the end of the line.
+    fn consume_tag_parameter(&mut self, start_pos: usize) -> &'static str {
+        let mut in_quotes = false;
+        while let Some(chr) = self.peek() {
+            if chr == '\r' || chr == '\n' || (chr == ')' && !in_quotes) {
+                break;
+            }
+            if chr == '"' {
+                in_quotes = !in_quotes;
+            }
+            self.take();
         }
         &self.source[start_pos..self.next_char_boundary]
     }
@@ -552,10 +581,26 @@ impl Iterator for Lexer {
     }
 }
 
+/// A [Lexer] that yields each token alongside the byte range it was lexed from. Unlike a token's
+/// [Span], these ranges always reflect the real source, which allows the skipped text between
+/// tokens to be recovered.
+pub struct LexerWithOffsets(Lexer);
+impl Iterator for LexerWithOffsets {
+    type Item = (Token, Range<usize>);
+    fn next(&mut self) -> Option<Self::Item> {
+        let token = self.0.next()?;
+        Some((token, self.0.token_start..self.0.next_char_boundary))
+    }
+}
+
 /// Various constants in gml that are not specificlly tracked in duck.
 pub static MISC_GML_CONSTANTS: Lazy<HashSet<&'static str>> =
     Lazy::new(|| serde_json::from_str(include_str!("../../assets/misc_gml_constants.json")).unwrap());
 
-/// Various built-in variables in gml that are not specificlly tracked in duck.
-pub static MISC_GML_VARIABLES: Lazy<HashSet<&'static str>> =
-    Lazy::new(|| serde_json::from_str(include_str!("../../assets/misc_gml_variables.json")).unwrap());
+/// Built-in variables that every instance has its own copy of, such as `x` or `image_index`.
+pub static MISC_GML_INSTANCE_VARIABLES: Lazy<HashSet<&'static str>> =
+    Lazy::new(|| serde_json::from_str(include_str!("../../assets/misc_gml_instance_variables.json")).unwrap());
+
+/// Built-in variables shared by the whole game, such as `room` or `delta_time`.
+pub static MISC_GML_GLOBAL_VARIABLES: Lazy<HashSet<&'static str>> =
+    Lazy::new(|| serde_json::from_str(include_str!("../../assets/misc_gml_global_variables.json")).unwrap());
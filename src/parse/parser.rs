@@ -8,13 +8,29 @@ use std::{iter::Peekable, ops::Range};
 /// Recursively decsends Gml source, incremently returning various statements
 /// and expressions.
 pub struct Parser {
-    lexer: Peekable<Lexer>,
+    lexer: Peekable<LexerWithOffsets>,
+    source: &'static str,
     cursor: usize,
     file_id: FileId,
-    comments: Vec<Token>,
+    /// Whether every token and the whitespace between them is being recorded for a [SyntaxTree].
+    lossless: bool,
+    syntax_tokens: Vec<SyntaxToken>,
+    /// The trivia waiting to be attached to the next token. Unless the parser is lossless, this
+    /// only holds comments, tags and regions.
+    trivia: Vec<Trivia>,
+    trivia_cursor: usize,
     use_default_ids: bool,
-    tag_queue: Option<Tag>,
-    active_tag: Option<Tag>,
+    tag_queue: Vec<Tag>,
+    file_tags: Vec<Tag>,
+    regions: Vec<OpenRegion>,
+    depth: usize,
+}
+
+/// A `#region` that has not yet been closed, along with the tags that were written above it.
+struct OpenRegion {
+    tags: Vec<Tag>,
+    /// The statement depth the region was opened at. Unknown until the next statement begins.
+    depth: Option<usize>,
 }
 
 // Basic features
@@ -22,13 +38,19 @@ impl Parser {
     /// Creates a new parser.
     pub fn new(source_code: &'static str, file_id: FileId) -> Self {
         Self {
-            lexer: Lexer::new(source_code).peekable(),
+            lexer: Lexer::new(source_code).with_offsets().peekable(),
+            source: source_code,
             cursor: 0,
             file_id,
-            comments: vec![],
+            lossless: false,
+            syntax_tokens: vec![],
+            trivia: vec![],
+            trivia_cursor: 0,
             use_default_ids: false,
-            tag_queue: None,
-            active_tag: None,
+            tag_queue: vec![],
+            file_tags: vec![],
+            regions: vec![],
+            depth: 0,
         }
     }
 
@@ -51,7 +73,44 @@ impl Parser {
         while self.soft_peek().is_some() {
             statements.push(self.stmt()?);
         }
-        Ok(Ast::new(statements))
+        Ok(Ast::new_with_tags(statements, self.file_tags).with_source(self.source))
+    }
+
+    /// Parses the entire source as a single expression.
+    ///
+    /// ### Errors
+    ///
+    /// Returns a [ParseError] if the source was not a valid expression, or if anything came after
+    /// it.
+    pub fn into_expr(mut self) -> Result<Expr, Diagnostic<FileId>> {
+        let expr = self.expr()?;
+        match self.soft_peek().copied() {
+            Some(token) => Err(Diagnostic::error().with_message("Unexpected token").with_labels(vec![
+                Label::primary(self.file_id, token.span).with_message("expected the expression to end here"),
+            ])),
+            None => Ok(expr),
+        }
+    }
+
+    /// Runs the parser through the entire source, collecting everything into a lossless
+    /// [SyntaxTree] that retains all of the whitespace and comments alongside the Ast. Only this
+    /// records the tokens and trivia, so parsing with [Parser::into_ast] doesn't pay for them.
+    ///
+    /// ### Errors
+    ///
+    /// Returns a [ParseError] if any of the source code caused an error.
+    pub fn into_syntax_tree(mut self) -> Result<SyntaxTree, Diagnostic<FileId>> {
+        self.lossless = true;
+        let mut statements = vec![];
+        while self.soft_peek().is_some() {
+            statements.push(self.stmt()?);
+        }
+        self.collect_skipped_trivia(self.source.len());
+        Ok(SyntaxTree::new(
+            Ast::new_with_tags(statements, self.file_tags).with_source(self.source),
+            self.syntax_tokens,
+            self.trivia,
+        ))
     }
 
     /// Creates a new expression.
@@ -64,7 +123,18 @@ impl Parser {
             },
             span,
             self.file_id,
-            self.tag_queue.as_ref().cloned(),
+            self.tag_queue.clone(),
+        )
+    }
+
+    /// Creates a new tag from the label and parameter of a tag token found in the given range.
+    /// Unlike the rest of the Ast, tags always keep their true offsets (even in tests), as they are
+    /// told apart by their location when tracking which tags have been used.
+    fn new_tag(&self, label: &str, parameter: Option<&str>, range: Range<usize>) -> Tag {
+        Tag(
+            label.to_string(),
+            parameter.map(|v| v.to_string()),
+            Location(self.file_id, Span(range.start, range.end)),
         )
     }
 
@@ -78,10 +148,22 @@ impl Parser {
             },
             self.span(start_position),
             self.file_id,
-            self.active_tag.as_ref().cloned(),
+            vec![],
         )
     }
 
+    /// Returns the tags of every open region that was opened at the current statement depth,
+    /// assigning any region opened since the last statement to this depth.
+    fn region_tags(&mut self) -> Vec<Tag> {
+        let depth = self.depth;
+        self.regions
+            .iter_mut()
+            .filter_map(|region| (*region.depth.get_or_insert(depth) == depth).then_some(&region.tags))
+            .flatten()
+            .cloned()
+            .collect()
+    }
+
     /// Creates a [Span] from the given position up until the pilot's current
     /// position.
     fn span(&self, start: usize) -> Span {
@@ -98,7 +180,11 @@ impl Parser {
     /// Returns a [ParseError] if any of the source code caused an error.
     pub fn stmt(&mut self) -> Result<Stmt, Diagnostic<FileId>> {
         self.collect_upcoming_comments(); // seeks out tags
-        self.active_tag = self.tag_queue.take();
+        self.depth += 1;
+        // Tags on regions cover every statement within them, while other tags only cover the
+        // statement that follows them (and everything inside it).
+        let mut tags = self.region_tags();
+        tags.append(&mut self.tag_queue);
         let result = match self.peek()?.token_type {
             TokenKind::Macro(name, config, body) => self.macro_declaration(name, config, body),
             TokenKind::Enum => self.enum_declaration(),
@@ -121,8 +207,11 @@ impl Parser {
             TokenKind::Var => self.local_variable_series(),
             _ => self.assignment(),
         };
-        self.active_tag.take();
-        result
+        self.depth -= 1;
+        result.map(|mut stmt| {
+            stmt.tags_mut().extend(tags);
+            stmt
+        })
     }
 
     fn macro_declaration(&mut self, name: &str, config: Option<&str>, body: &str) -> Result<Stmt, Diagnostic<FileId>> {
@@ -281,7 +370,13 @@ impl Parser {
                     let identity = self.expr()?;
                     self.require(TokenKind::Colon)?;
                     let body = case_body(self)?;
-                    members.push(SwitchCase::new(identity, body))
+                    // Any comments between the body and the next case are still waiting to be
+                    // attached to the next token
+                    let fallthrough_comment = self
+                        .trivia
+                        .iter()
+                        .any(|trivia| trivia.kind == TriviaKind::Comment && is_fallthrough_comment(trivia.text));
+                    members.push(SwitchCase::new(identity, body).with_fallthrough_comment(fallthrough_comment))
                 }
                 TokenKind::Default => {
                     self.take()?;
@@ -447,7 +542,7 @@ impl Parser {
     }
 
     fn expr_stmt(&mut self, expr: Expr) -> Result<Stmt, Diagnostic<FileId>> {
-        let start = self.next_token_boundary();
+        let start = expr.span().start();
         match expr.kind() {
             ExprKind::Function(..)
             | ExprKind::Postfix(..)
@@ -517,34 +612,32 @@ impl Parser {
 
     fn logical(&mut self) -> Result<Expr, Diagnostic<FileId>> {
         let start = self.next_token_boundary();
-        let expr = self.equality()?;
-        if let Some(operator) = self.soft_peek().and_then(|token| token.as_logical_op()) {
+        let mut expr = self.equality()?;
+        while let Some(operator) = self.soft_peek().and_then(|token| token.as_logical_op()) {
             self.take()?;
-            let right = self.logical()?;
+            let right = self.equality()?;
             let end = right.span().end();
-            Ok(self.new_expr(Logical::new(expr, operator, right), Span::new(start, end)))
-        } else {
-            Ok(expr)
+            expr = self.new_expr(Logical::new(expr, operator, right), Span::new(start, end));
         }
+        Ok(expr)
     }
 
     fn equality(&mut self) -> Result<Expr, Diagnostic<FileId>> {
         let start = self.next_token_boundary();
-        let expr = self.binary()?;
-        if let Some(operator) = self.soft_peek().and_then(|token| token.as_equality_op()) {
+        let mut expr = self.binary()?;
+        while let Some(operator) = self.soft_peek().and_then(|token| token.as_equality_op()) {
             self.take()?;
-            let right = self.equality()?;
+            let right = self.binary()?;
             let end = right.span().end();
-            Ok(self.new_expr(Equality::new(expr, operator, right), Span::new(start, end)))
-        } else {
-            Ok(expr)
+            expr = self.new_expr(Equality::new(expr, operator, right), Span::new(start, end));
         }
+        Ok(expr)
     }
 
     fn binary(&mut self) -> Result<Expr, Diagnostic<FileId>> {
         let start = self.next_token_boundary();
-        let expr = self.bitshift()?;
-        if let Some(operator) = self
+        let mut expr = self.bitshift()?;
+        while let Some(operator) = self
             .soft_peek()
             .map(|token| token.as_evaluation_op())
             .filter(|operator| {
@@ -556,18 +649,17 @@ impl Parser {
             .flatten()
         {
             self.take()?;
-            let right = self.binary()?;
+            let right = self.bitshift()?;
             let end = right.span().end();
-            Ok(self.new_expr(Evaluation::new(expr, operator, right), Span::new(start, end)))
-        } else {
-            Ok(expr)
+            expr = self.new_expr(Evaluation::new(expr, operator, right), Span::new(start, end));
         }
+        Ok(expr)
     }
 
     fn bitshift(&mut self) -> Result<Expr, Diagnostic<FileId>> {
         let start = self.next_token_boundary();
-        let expr = self.addition()?;
-        if let Some(operator) = self
+        let mut expr = self.addition()?;
+        while let Some(operator) = self
             .soft_peek()
             .map(|token| token.as_evaluation_op())
             .filter(|operator| {
@@ -579,18 +671,17 @@ impl Parser {
             .flatten()
         {
             self.take()?;
-            let right = self.bitshift()?;
+            let right = self.addition()?;
             let end = right.span().end();
-            Ok(self.new_expr(Evaluation::new(expr, operator, right), Span::new(start, end)))
-        } else {
-            Ok(expr)
+            expr = self.new_expr(Evaluation::new(expr, operator, right), Span::new(start, end));
         }
+        Ok(expr)
     }
 
     fn addition(&mut self) -> Result<Expr, Diagnostic<FileId>> {
         let start = self.next_token_boundary();
-        let expr = self.multiplication()?;
-        if let Some(operator) = self
+        let mut expr = self.multiplication()?;
+        while let Some(operator) = self
             .soft_peek()
             .map(|token| token.as_evaluation_op())
             .filter(|operator| {
@@ -608,18 +699,17 @@ impl Parser {
             .flatten()
         {
             self.take()?;
-            let right = self.addition()?;
+            let right = self.multiplication()?;
             let end = right.span().end();
-            Ok(self.new_expr(Evaluation::new(expr, operator, right), Span::new(start, end)))
-        } else {
-            Ok(expr)
+            expr = self.new_expr(Evaluation::new(expr, operator, right), Span::new(start, end));
         }
+        Ok(expr)
     }
 
     fn multiplication(&mut self) -> Result<Expr, Diagnostic<FileId>> {
         let start = self.next_token_boundary();
-        let expr = self.unary()?;
-        if let Some(operator) = self
+        let mut expr = self.unary()?;
+        while let Some(operator) = self
             .soft_peek()
             .map(|token| token.as_evaluation_op())
             .filter(|operator| {
@@ -634,12 +724,11 @@ impl Parser {
             .flatten()
         {
             self.take()?;
-            let right = self.multiplication()?;
+            let right = self.unary()?;
             let end = right.span().end();
-            Ok(self.new_expr(Evaluation::new(expr, operator, right), Span::new(start, end)))
-        } else {
-            Ok(expr)
+            expr = self.new_expr(Evaluation::new(expr, operator, right), Span::new(start, end));
         }
+        Ok(expr)
     }
 
     fn unary(&mut self) -> Result<Expr, Diagnostic<FileId>> {
@@ -932,7 +1021,7 @@ impl Parser {
             ) {
                 return Ok(left);
             }
-            (self.next_token_boundary(), left)
+            (left.span().0, left)
         };
         self.require(TokenKind::LeftSquareBracket)?;
         let access = match self.peek()?.token_type {
@@ -1035,11 +1124,7 @@ impl Parser {
 
     /// Consumes and returns the next token if it is within the array of types.
     fn match_take_possibilities(&mut self, token_types: &[TokenKind]) -> Option<Token> {
-        if self
-            .peek()
-            .map(|token| token_types.contains(&token.token_type))
-            .unwrap_or(false)
-        {
+        if self.peek().is_ok_and(|token| token_types.contains(&token.token_type)) {
             Some(self.take().unwrap())
         } else {
             None
@@ -1126,14 +1211,14 @@ impl Parser {
     /// Get the gml tokens's cursor.
     fn next_token_boundary(&mut self) -> usize {
         self.collect_upcoming_comments();
-        self.lexer.peek().map_or(self.cursor, |token| token.span.0)
+        self.lexer.peek().map_or(self.cursor, |(token, _)| token.span.0)
     }
 
     /// Returns the type of the next Token if there is one. Used for situations
     /// where no tokens remaining would be valid.
     fn soft_peek(&mut self) -> Option<&Token> {
         self.collect_upcoming_comments();
-        if let Some(token) = self.lexer.peek() {
+        if let Some((token, _)) = self.lexer.peek() {
             Some(token)
         } else {
             None
@@ -1146,7 +1231,7 @@ impl Parser {
         self.collect_upcoming_comments();
         let start = self.next_token_boundary();
         let next = self.lexer.peek();
-        if let Some(next) = next {
+        if let Some((next, _)) = next {
             Ok(next)
         } else {
             Err(Diagnostic::error()
@@ -1161,8 +1246,20 @@ impl Parser {
     fn take(&mut self) -> Result<Token, Diagnostic<FileId>> {
         self.collect_upcoming_comments();
         let start = self.next_token_boundary();
-        if let Some(token) = self.lexer.next() {
+        if let Some((token, range)) = self.lexer.next() {
             self.cursor = token.span.0;
+            if self.lossless {
+                self.collect_skipped_trivia(range.start);
+                self.syntax_tokens.push(SyntaxToken {
+                    token,
+                    text: &self.source[range.clone()],
+                    offset: range.start,
+                    leading_trivia: std::mem::take(&mut self.trivia),
+                });
+                self.trivia_cursor = range.end;
+            } else {
+                self.trivia.clear();
+            }
             Ok(token)
         } else {
             Err(Diagnostic::error()
@@ -1173,30 +1270,106 @@ impl Parser {
         }
     }
 
-    /// Looks ahead at the next token and collects it if it is a comment (including lint tags).
+    /// Looks ahead at the next token and collects it as trivia if it is a comment (including lint
+    /// tags).
     fn collect_upcoming_comments(&mut self) {
         loop {
-            match self.lexer.peek() {
-                Some(Token {
-                    token_type: TokenKind::Comment(_),
-                    ..
-                }) => self.comments.push(self.lexer.next().unwrap()),
-                Some(Token {
-                    token_type: TokenKind::Tag(label, parameter),
-                    ..
-                }) => {
-                    self.tag_queue = Some(Tag(label.to_string(), parameter.map(|v| v.to_string())));
-                    self.lexer.next().unwrap();
+            let kind = match self.lexer.peek() {
+                Some((
+                    Token {
+                        token_type: TokenKind::Comment(_),
+                        ..
+                    },
+                    _,
+                )) => TriviaKind::Comment,
+                Some((
+                    Token {
+                        token_type: TokenKind::Tag(label, parameter),
+                        ..
+                    },
+                    range,
+                )) => {
+                    let (label, parameter, range) = (*label, *parameter, range.clone());
+                    let tag = self.new_tag(label, parameter, range);
+                    self.tag_queue.push(tag);
+                    TriviaKind::Tag
+                }
+                Some((
+                    Token {
+                        token_type: TokenKind::InnerTag(label, parameter),
+                        ..
+                    },
+                    range,
+                )) => {
+                    let (label, parameter, range) = (*label, *parameter, range.clone());
+                    let tag = self.new_tag(label, parameter, range);
+                    self.file_tags.push(tag);
+                    TriviaKind::Tag
+                }
+                Some((
+                    Token {
+                        token_type: TokenKind::Region(_),
+                        ..
+                    },
+                    _,
+                )) => {
+                    self.regions.push(OpenRegion {
+                        tags: std::mem::take(&mut self.tag_queue),
+                        depth: None,
+                    });
+                    TriviaKind::Region
+                }
+                Some((
+                    Token {
+                        token_type: TokenKind::EndRegion,
+                        ..
+                    },
+                    _,
+                )) => {
+                    self.regions.pop();
+                    TriviaKind::Region
                 }
                 _ => break,
-            }
+            };
+            let (_, range) = self.lexer.next().unwrap();
+            self.collect_skipped_trivia(range.start);
+            self.trivia.push(Trivia {
+                kind,
+                text: &self.source[range.clone()],
+                offset: range.start,
+            });
+            self.trivia_cursor = range.end;
         }
     }
+
+    /// Collects any source the lexer skipped over (whitespace, regions, etc) up until the given
+    /// position as trivia, if the parser is lossless.
+    fn collect_skipped_trivia(&mut self, end: usize) {
+        if self.lossless && end > self.trivia_cursor {
+            split_trivia(
+                &self.source[self.trivia_cursor..end],
+                self.trivia_cursor,
+                &mut self.trivia,
+            );
+            self.trivia_cursor = end;
+        }
+    }
+}
+
+/// Returns whether the text of a comment marks a switch case as intentionally falling through
+/// (ie: `// fallthrough` or `/* falls through */`).
+fn is_fallthrough_comment(text: &str) -> bool {
+    let words: String = text
+        .chars()
+        .filter(char::is_ascii_alphabetic)
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    words.starts_with("fallthrough") || words.starts_with("fallsthrough")
 }
 
 /// A start and end cursor measured in characters, used for expressing small sections of source
 /// code.
-#[derive(Debug, PartialEq, Default, Copy, Clone, serde::Serialize)]
+#[derive(Debug, PartialEq, Eq, Hash, Default, Copy, Clone, serde::Serialize)]
 pub struct Span(usize, usize);
 impl Span {
     /// Creates a new span.
@@ -1228,5 +1401,5 @@ impl From<Span> for Range<usize> {
 }
 
 /// A location for something in gml, combining a span and a file id.
-#[derive(Debug, PartialEq, Default, Copy, Clone, serde::Serialize)]
+#[derive(Debug, PartialEq, Eq, Hash, Default, Copy, Clone, serde::Serialize)]
 pub struct Location(pub FileId, pub Span);
@@ -0,0 +1,611 @@
+use crate::{
+    FileId,
+    parse::{
+        printer::{Precedence, operand_precedences},
+        *,
+    },
+};
+use codespan_reporting::diagnostic::Diagnostic;
+use hashbrown::{HashMap, HashSet};
+use std::mem::discriminant;
+
+/// The prefix metavariables are renamed to before a pattern is parsed. `$` can't be used directly,
+/// as the lexer would read most metavariables (such as `$a`) as hex literals.
+const METAVARIABLE_PREFIX: &str = "__duck_metavariable_";
+
+/// A structural pattern written in gml, such as `instance_create_layer($x, $y, "Instances", $obj)`
+/// or `if ($a == true) $body`.
+///
+/// Patterns match against the [Expr]s and [Stmt]s of an [Ast] by their structure rather than their
+/// text, so whitespace, comments and parenthesis are ignored. Any identifier starting with a `$` is
+/// a metavariable, which will match any expression (or statement, if written where a statement is
+/// expected) and capture it in the resulting [PatternMatch]. If the same metavariable is used more
+/// than once, each use must match the same code. `$_` matches anything without capturing it.
+///
+/// ```
+/// use duck::parse::{Parser, Pattern};
+/// let pattern = Pattern::new("array_length($a) == 0").unwrap();
+/// let ast = Parser::new("if array_length(foo) == 0 { bar(); }", 0)
+///     .into_ast()
+///     .unwrap();
+/// let matches = pattern.find(&ast);
+/// assert_eq!(matches.len(), 1);
+/// assert_eq!(matches[0].rewrite("array_empty($a)"), "array_empty(foo)");
+/// ```
+///
+/// Metavariable names must start with a letter or an underscore. To avoid confusion with them, hex
+/// literals in patterns should be written with `0x` rather than `$`.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Pattern {
+    /// A pattern that matches expressions.
+    Expr(Expr),
+    /// A pattern that matches statements.
+    Stmt(Stmt),
+}
+impl Pattern {
+    /// Parses the pattern from its source.
+    ///
+    /// ### Errors
+    ///
+    /// Returns a diagnostic if the pattern is not valid gml, contains more than one statement, or
+    /// is nothing but a metavariable (which would match everything).
+    pub fn new(source: &str) -> Result<Self, Diagnostic<FileId>> {
+        let pattern = Self::parse(source)?;
+        if pattern
+            .as_expr()
+            .is_some_and(|expr| ungroup(expr).kind().as_identifier().and_then(metavariable).is_some())
+        {
+            return Err(Diagnostic::error().with_message("Patterns must contain more than a lone metavariable"));
+        }
+        Ok(pattern)
+    }
+
+    /// Parses gml containing metavariables, which may be nothing but a lone metavariable.
+    fn parse(source: &str) -> Result<Self, Diagnostic<FileId>> {
+        let source: &'static str = Box::leak(replace_metavariables(source).into_boxed_str());
+        // Most patterns are statements, but expressions that can't stand on their own as a
+        // statement (such as `$a + $b`) are also accepted
+        let pattern = match Parser::new(source, 0).into_ast().map(Ast::unpack) {
+            Ok(stmts) if stmts.len() == 1 => match stmts[0].kind() {
+                StmtKind::Expr(expr) => Self::Expr(expr.clone()),
+                _ => Self::Stmt(stmts[0].clone()),
+            },
+            Ok(_) => Self::Expr(Parser::new(source, 0).into_expr().map_err(|_| {
+                Diagnostic::error().with_message("Patterns must contain exactly one expression or statement")
+            })?),
+            Err(error) => Self::Expr(Parser::new(source, 0).into_expr().map_err(|_| error)?),
+        };
+        Ok(pattern)
+    }
+
+    /// Returns the expression this pattern matches against, if it is an expression pattern.
+    pub fn as_expr(&self) -> Option<&Expr> {
+        match self {
+            Pattern::Expr(expr) => Some(expr),
+            Pattern::Stmt(_) => None,
+        }
+    }
+
+    /// Returns the statement this pattern matches against, if it is a statement pattern.
+    pub fn as_stmt(&self) -> Option<&Stmt> {
+        match self {
+            Pattern::Expr(_) => None,
+            Pattern::Stmt(stmt) => Some(stmt),
+        }
+    }
+
+    /// Matches the pattern against the expression itself (but not its children). Groupings never
+    /// match, as the expression inside of them will match instead.
+    pub fn match_expr(&self, expr: &Expr) -> Option<PatternMatch> {
+        self.match_expr_in_slot(expr, Slot::FREE)
+    }
+
+    fn match_expr_in_slot(&self, expr: &Expr, slot: Slot) -> Option<PatternMatch> {
+        if expr.kind().as_grouping().is_some() {
+            return None;
+        }
+        let mut matcher = Matcher::default();
+        matcher.expr(self.as_expr()?, expr).then(|| PatternMatch {
+            location: expr.location(),
+            bindings: matcher.bindings,
+            slot,
+        })
+    }
+
+    /// Matches the pattern against the statement itself (but not its children).
+    pub fn match_stmt(&self, stmt: &Stmt) -> Option<PatternMatch> {
+        let mut matcher = Matcher::default();
+        matcher.stmt(self.as_stmt()?, stmt).then(|| PatternMatch {
+            location: stmt.location(),
+            bindings: matcher.bindings,
+            slot: Slot::FREE,
+        })
+    }
+
+    /// Returns every match of the pattern in the Ast, in the order they appear.
+    pub fn find(&self, ast: &Ast) -> Vec<PatternMatch> {
+        let mut matches = vec![];
+        for stmt in ast.stmts() {
+            self.find_in_stmt(stmt, &mut matches);
+        }
+        matches.sort_by_key(|pattern_match| pattern_match.location.1.start());
+        matches
+    }
+
+    fn find_in_stmt(&self, stmt: &Stmt, matches: &mut Vec<PatternMatch>) {
+        matches.extend(self.match_stmt(stmt));
+        stmt.visit_child_stmts(|stmt| self.find_in_stmt(stmt, matches));
+        stmt.visit_child_exprs(|expr| self.find_in_expr(expr, Slot::FREE, matches));
+    }
+
+    fn find_in_expr(&self, expr: &Expr, slot: Slot, matches: &mut Vec<PatternMatch>) {
+        matches.extend(self.match_expr_in_slot(expr, slot));
+        expr.visit_child_stmts(|stmt| self.find_in_stmt(stmt, matches));
+        expr.visit_child_exprs(|child| self.find_in_expr(child, Slot::of(expr, child), matches));
+    }
+}
+
+/// A successful match of a [Pattern], along with everything its metavariables captured.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PatternMatch {
+    location: Location,
+    bindings: HashMap<String, Binding>,
+    /// The position the matched code sits in.
+    slot: Slot,
+}
+impl PatternMatch {
+    /// Returns the location of the code that was matched.
+    pub fn location(&self) -> Location {
+        self.location
+    }
+
+    /// Returns the code captured by the metavariable with the given name (without its `$`).
+    pub fn binding(&self, name: &str) -> Option<&Binding> {
+        self.bindings.get(name)
+    }
+
+    /// Returns an iterator over every metavariable's name (without its `$`) and the code it
+    /// captured.
+    pub fn bindings(&self) -> impl Iterator<Item = (&str, &Binding)> {
+        self.bindings.iter().map(|(name, binding)| (name.as_str(), binding))
+    }
+
+    /// Fills in the template with the code captured by each metavariable, printed with the
+    /// [Printer]. Metavariables that captured nothing are left as they are. This is useful when the
+    /// source the match came from is not available; otherwise, prefer [PatternMatch::rewrite_in],
+    /// which keeps the captured code as it was written.
+    ///
+    /// Parenthesis are added around captured code (and around the whole rewrite) wherever they are
+    /// needed to keep it from being split apart by the operators around it.
+    pub fn rewrite(&self, template: &str) -> String {
+        let printer = Printer::default();
+        self.fill(template, |binding| binding.print(&printer))
+    }
+
+    /// Fills in the template with the code captured by each metavariable, taken from the source
+    /// the match was found in so that its formatting and comments are kept. Otherwise identical to
+    /// [PatternMatch::rewrite].
+    pub fn rewrite_in(&self, source: &str, template: &str) -> String {
+        self.fill(template, |binding| binding.text(source))
+    }
+
+    fn fill(&self, template: &str, text: impl Fn(&Binding) -> String) -> String {
+        let (wrapped, wrap_output) = self.parenthesized(template);
+        let mut output = String::new();
+        let mut remaining = template;
+        while let Some(index) = remaining.find('$') {
+            output.push_str(&remaining[..index]);
+            let name_length = metavariable_name_length(&remaining[index + 1..]);
+            let name = &remaining[index + 1..index + 1 + name_length];
+            match self.bindings.get(name) {
+                Some(binding) if wrapped.contains(name) => output.push_str(&format!("({})", text(binding))),
+                Some(binding) => output.push_str(&text(binding)),
+                None => output.push_str(&remaining[index..index + 1 + name_length]),
+            }
+            remaining = &remaining[index + 1 + name_length..];
+        }
+        output.push_str(remaining);
+        if wrap_output { format!("({output})") } else { output }
+    }
+
+    /// Returns the names of the metavariables whose code must be wrapped in parenthesis where they
+    /// are used in the template, and whether the whole rewrite must be wrapped to fit where the
+    /// match was found. If the template can't be parsed, everything that might need parenthesis is
+    /// given them.
+    fn parenthesized(&self, template: &str) -> (HashSet<String>, bool) {
+        let mut wrapped = HashSet::new();
+        match Pattern::parse(template) {
+            Ok(Pattern::Expr(expr)) => {
+                let lone_metavariable = expr.kind().as_identifier().and_then(metavariable).is_some();
+                self.parenthesized_expr(&expr, self.slot, &mut wrapped);
+                (wrapped, !lone_metavariable && self.slot.requires_parenthesis(&expr))
+            }
+            Ok(Pattern::Stmt(stmt)) => {
+                self.parenthesized_stmt(&stmt, &mut wrapped);
+                (wrapped, false)
+            }
+            Err(_) => {
+                for (name, binding) in self.bindings.iter() {
+                    if let Binding::Expr(expr) = binding {
+                        if Precedence::of(expr) < Precedence::Primary {
+                            wrapped.insert(name.clone());
+                        }
+                    }
+                }
+                (wrapped, self.slot != Slot::FREE)
+            }
+        }
+    }
+
+    fn parenthesized_expr(&self, expr: &Expr, slot: Slot, wrapped: &mut HashSet<String>) {
+        if let Some(name) = expr.kind().as_identifier().and_then(metavariable) {
+            if let Some(Binding::Expr(binding)) = self.bindings.get(name) {
+                if slot.requires_parenthesis(binding) {
+                    wrapped.insert(name.to_string());
+                }
+            }
+            return;
+        }
+        expr.visit_child_stmts(|stmt| self.parenthesized_stmt(stmt, wrapped));
+        expr.visit_child_exprs(|child| self.parenthesized_expr(child, Slot::of(expr, child), wrapped));
+    }
+
+    fn parenthesized_stmt(&self, stmt: &Stmt, wrapped: &mut HashSet<String>) {
+        stmt.visit_child_stmts(|stmt| self.parenthesized_stmt(stmt, wrapped));
+        stmt.visit_child_exprs(|expr| self.parenthesized_expr(expr, Slot::FREE, wrapped));
+    }
+}
+
+/// Replaces the code of each match in the source with the template, filled in by
+/// [PatternMatch::rewrite_in]. The matches must all come from the same file. If matches overlap
+/// (such as a match nested inside of another), only the one that starts first is replaced.
+///
+/// ```
+/// use duck::parse::{Parser, Pattern, rewrite_source};
+/// let source = "if array_length(foo[ 0 ]) == 0 { bar(); } x = double(a + 1) - 1;";
+/// let ast = Parser::new(source, 0).into_ast().unwrap();
+/// let matches = Pattern::new("array_length($a) == 0").unwrap().find(&ast);
+/// let source = rewrite_source(source, &matches, "array_empty($a)");
+/// assert_eq!(
+///     source,
+///     "if array_empty(foo[ 0 ]) { bar(); } x = double(a + 1) - 1;"
+/// );
+/// let ast = Parser::new(Box::leak(source.into_boxed_str()), 0)
+///     .into_ast()
+///     .unwrap();
+/// let matches = Pattern::new("double($v)").unwrap().find(&ast);
+/// assert_eq!(
+///     rewrite_source(ast.source(), &matches, "$v * 2"),
+///     "if array_empty(foo[ 0 ]) { bar(); } x = (a + 1) * 2 - 1;"
+/// );
+/// ```
+pub fn rewrite_source(source: &str, matches: &[PatternMatch], template: &str) -> String {
+    let mut matches: Vec<&PatternMatch> = matches.iter().collect();
+    matches.sort_by_key(|pattern_match| pattern_match.location.1.start());
+    let mut output = String::with_capacity(source.len());
+    let mut cursor = 0;
+    for pattern_match in matches {
+        let span = pattern_match.location.1;
+        if span.start() < cursor {
+            continue;
+        }
+        output.push_str(&source[cursor..span.start()]);
+        output.push_str(&pattern_match.rewrite_in(source, template));
+        cursor = span.end();
+    }
+    output.push_str(&source[cursor..]);
+    output
+}
+
+/// The code captured by a metavariable in a [Pattern].
+#[derive(Debug, PartialEq, Clone)]
+pub enum Binding {
+    /// An expression.
+    Expr(Expr),
+    /// A statement.
+    Stmt(Stmt),
+    /// An identifier in a position that does not hold an expression, such as the name of a
+    /// function or the field in dot access.
+    Identifier(Identifier),
+}
+impl Binding {
+    /// Prints the captured code back into gml.
+    pub fn print(&self, printer: &Printer) -> String {
+        match self {
+            Binding::Expr(expr) => printer.expr(expr),
+            Binding::Stmt(stmt) => printer.stmt(stmt),
+            Binding::Identifier(identifier) => identifier.lexeme.clone(),
+        }
+    }
+
+    /// Returns the captured code as it was written in the source it was matched in. If the source
+    /// does not contain it (such as for code that was built by hand), it is printed instead.
+    pub fn text(&self, source: &str) -> String {
+        let span = match self {
+            Binding::Expr(expr) => expr.span(),
+            Binding::Stmt(stmt) => stmt.span(),
+            Binding::Identifier(identifier) => identifier.span,
+        };
+        match source.get(span.start()..span.end()) {
+            Some(text) if !text.is_empty() => text.to_string(),
+            _ => self.print(&Printer::default()),
+        }
+    }
+}
+
+/// The position an expression sits in, which decides whether code put in its place needs to be
+/// wrapped in parenthesis to keep the same meaning.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+struct Slot {
+    /// The loosest precedence that can sit in the position without parenthesis.
+    minimum: Precedence,
+}
+impl Slot {
+    /// A position that holds any expression, such as an argument or the value of an assignment.
+    const FREE: Self = Self {
+        minimum: Precedence::NullCoalecence,
+    };
+
+    /// Returns the position the child sits in within the parent.
+    fn of(parent: &Expr, child: &Expr) -> Self {
+        let minimum = |minimum| Self { minimum };
+        match parent.kind() {
+            ExprKind::Logical(Logical { left, .. })
+            | ExprKind::Equality(Equality { left, .. })
+            | ExprKind::Evaluation(Evaluation { left, .. })
+            | ExprKind::NullCoalecence(NullCoalecence { left, .. }) => {
+                let (left_minimum, right_minimum) = operand_precedences(parent);
+                minimum(if std::ptr::eq(child, left) {
+                    left_minimum
+                } else {
+                    right_minimum
+                })
+            }
+            ExprKind::Unary(_) => minimum(Precedence::Unary),
+            ExprKind::Postfix(_) => minimum(Precedence::Primary),
+            ExprKind::Ternary(Ternary { condition, .. }) if std::ptr::eq(child, condition) => {
+                minimum(Precedence::Logical)
+            }
+            ExprKind::Call(Call { left, .. })
+            | ExprKind::Access(
+                Access::Dot { left, .. }
+                | Access::Array { left, .. }
+                | Access::Map { left, .. }
+                | Access::Grid { left, .. }
+                | Access::List { left, .. }
+                | Access::Struct { left, .. },
+            ) if std::ptr::eq(child, left) => minimum(Precedence::Primary),
+            _ => Self::FREE,
+        }
+    }
+
+    /// Returns whether the expression would need parenthesis to sit in this position.
+    fn requires_parenthesis(&self, expr: &Expr) -> bool {
+        Precedence::of(expr) < self.minimum
+    }
+}
+
+/// Renames every metavariable in the source (outside of strings) to an identifier the parser
+/// accepts.
+fn replace_metavariables(source: &str) -> String {
+    let mut output = String::with_capacity(source.len());
+    let mut in_string = false;
+    let mut chars = source.char_indices().peekable();
+    while let Some((index, chr)) = chars.next() {
+        match chr {
+            '"' => in_string = !in_string,
+            '\\' if in_string => {
+                output.push(chr);
+                if let Some((_, escaped)) = chars.next() {
+                    output.push(escaped);
+                }
+                continue;
+            }
+            '$' if !in_string && metavariable_name_length(&source[index + 1..]) > 0 => {
+                output.push_str(METAVARIABLE_PREFIX);
+                continue;
+            }
+            _ => {}
+        }
+        output.push(chr);
+    }
+    output
+}
+
+/// Returns the length of the metavariable name at the start of the text, which is zero if it does
+/// not start with one.
+fn metavariable_name_length(text: &str) -> usize {
+    if !text.starts_with(|chr: char| chr.is_ascii_alphabetic() || chr == '_') {
+        return 0;
+    }
+    text.find(|chr: char| !chr.is_ascii_alphanumeric() && chr != '_')
+        .unwrap_or(text.len())
+}
+
+/// Returns the name of the metavariable if the identifier is one.
+fn metavariable(identifier: &Identifier) -> Option<&str> {
+    identifier.lexeme.strip_prefix(METAVARIABLE_PREFIX)
+}
+
+/// Removes any groupings around the expression, as they have no effect on its structure.
+fn ungroup(mut expr: &Expr) -> &Expr {
+    while let Some(grouping) = expr.kind().as_grouping() {
+        expr = &grouping.inner;
+    }
+    expr
+}
+
+/// Compares patterns to code, collecting whatever the metavariables capture along the way.
+#[derive(Default)]
+struct Matcher {
+    bindings: HashMap<String, Binding>,
+}
+impl Matcher {
+    fn expr(&mut self, pattern: &Expr, target: &Expr) -> bool {
+        let pattern = ungroup(pattern);
+        if let Some(name) = pattern.kind().as_identifier().and_then(metavariable) {
+            return self.bind(name, Binding::Expr(target.clone()));
+        }
+        let target = ungroup(target);
+        self.expr_kind(pattern.kind(), target.kind()) && self.children(pattern, target)
+    }
+
+    fn stmt(&mut self, pattern: &Stmt, target: &Stmt) -> bool {
+        if let Some(name) = pattern
+            .kind()
+            .as_expr_stmt()
+            .and_then(|expr| ungroup(expr).kind().as_identifier())
+            .and_then(metavariable)
+        {
+            return self.bind(name, Binding::Stmt(target.clone()));
+        }
+        self.stmt_kind(pattern.kind(), target.kind()) && self.children(pattern, target)
+    }
+
+    fn identifier(&mut self, pattern: &Identifier, target: &Identifier) -> bool {
+        match metavariable(pattern) {
+            Some(name) => self.bind(name, Binding::Identifier(target.clone())),
+            None => pattern.lexeme == target.lexeme,
+        }
+    }
+
+    /// Captures the code in the metavariable, or if it has already captured something, ensures
+    /// that it is the same as the new code.
+    fn bind(&mut self, name: &str, binding: Binding) -> bool {
+        if name == "_" {
+            return true;
+        }
+        let Some(existing) = self.bindings.get(name) else {
+            self.bindings.insert(name.to_string(), binding);
+            return true;
+        };
+        let mut matcher = Matcher::default();
+        match (existing, &binding) {
+            (Binding::Expr(existing), Binding::Expr(expr)) => matcher.expr(existing, expr),
+            (Binding::Stmt(existing), Binding::Stmt(stmt)) => matcher.stmt(existing, stmt),
+            (Binding::Identifier(existing), Binding::Identifier(identifier)) => existing.lexeme == identifier.lexeme,
+            _ => false,
+        }
+    }
+
+    /// Matches each child of the pattern against the corresponding child of the target, requiring
+    /// both to have the same number of children.
+    fn children(&mut self, pattern: &impl ParseVisitor, target: &impl ParseVisitor) -> bool {
+        let mut pattern_exprs = vec![];
+        pattern.visit_child_exprs(|expr| pattern_exprs.push(expr.clone()));
+        let mut pattern_stmts = vec![];
+        pattern.visit_child_stmts(|stmt| pattern_stmts.push(stmt.clone()));
+
+        let mut matches = true;
+        let mut index = 0;
+        target.visit_child_exprs(|expr| {
+            matches = matches && pattern_exprs.get(index).is_some_and(|pattern| self.expr(pattern, expr));
+            index += 1;
+        });
+        matches = matches && index == pattern_exprs.len();
+        index = 0;
+        target.visit_child_stmts(|stmt| {
+            matches = matches && pattern_stmts.get(index).is_some_and(|pattern| self.stmt(pattern, stmt));
+            index += 1;
+        });
+        matches && index == pattern_stmts.len()
+    }
+
+    /// Compares everything about the two expressions aside from their children.
+    fn expr_kind(&mut self, pattern: &ExprKind, target: &ExprKind) -> bool {
+        match (pattern, target) {
+            (ExprKind::Function(pattern), ExprKind::Function(target)) => {
+                match (&pattern.name, &target.name) {
+                    (Some(pattern), Some(target)) if !self.identifier(pattern, target) => return false,
+                    (Some(_), None) | (None, Some(_)) => return false,
+                    _ => {}
+                }
+                fields(&pattern.parameters, &target.parameters)
+                    && pattern.constructor.is_some() == target.constructor.is_some()
+            }
+            (ExprKind::Logical(pattern), ExprKind::Logical(target)) => {
+                discriminant(&pattern.op) == discriminant(&target.op)
+            }
+            (ExprKind::Equality(pattern), ExprKind::Equality(target)) => {
+                discriminant(&pattern.op) == discriminant(&target.op)
+            }
+            (ExprKind::Evaluation(pattern), ExprKind::Evaluation(target)) => {
+                discriminant(&pattern.op) == discriminant(&target.op)
+            }
+            (ExprKind::Unary(pattern), ExprKind::Unary(target)) => {
+                discriminant(&pattern.op) == discriminant(&target.op)
+            }
+            (ExprKind::Postfix(pattern), ExprKind::Postfix(target)) => {
+                discriminant(&pattern.op) == discriminant(&target.op)
+            }
+            (ExprKind::Access(pattern), ExprKind::Access(target)) => match (pattern, target) {
+                (Access::Global { right: pattern }, Access::Global { right: target })
+                | (Access::Identity { right: pattern }, Access::Identity { right: target })
+                | (Access::Other { right: pattern }, Access::Other { right: target })
+                | (Access::Dot { right: pattern, .. }, Access::Dot { right: target, .. }) => {
+                    self.identifier(pattern, target)
+                }
+                (
+                    Access::Array {
+                        using_accessor: pattern,
+                        ..
+                    },
+                    Access::Array {
+                        using_accessor: target, ..
+                    },
+                ) => pattern == target,
+                (pattern, target) => discriminant(pattern) == discriminant(target),
+            },
+            (ExprKind::Call(pattern), ExprKind::Call(target)) => pattern.uses_new == target.uses_new,
+            (ExprKind::Literal(Literal::Array(_)), ExprKind::Literal(Literal::Array(_))) => true,
+            (ExprKind::Literal(Literal::Struct(pattern)), ExprKind::Literal(Literal::Struct(target))) => {
+                pattern.len() == target.len()
+                    && pattern
+                        .iter()
+                        .zip(target.iter())
+                        .all(|((pattern, _), (target, _))| self.identifier(pattern, target))
+            }
+            (ExprKind::Literal(pattern), ExprKind::Literal(target)) => pattern == target,
+            (ExprKind::Identifier(pattern), ExprKind::Identifier(target)) => self.identifier(pattern, target),
+            (pattern, target) => discriminant(pattern) == discriminant(target),
+        }
+    }
+
+    /// Compares everything about the two statements aside from their children.
+    fn stmt_kind(&mut self, pattern: &StmtKind, target: &StmtKind) -> bool {
+        match (pattern, target) {
+            (StmtKind::Enum(pattern), StmtKind::Enum(target)) => {
+                self.identifier(&pattern.name, &target.name) && fields(&pattern.members, &target.members)
+            }
+            (StmtKind::Macro(pattern), StmtKind::Macro(target)) => {
+                self.identifier(&pattern.name, &target.name)
+                    && pattern.config == target.config
+                    && pattern.body == target.body
+            }
+            (StmtKind::Globalvar(pattern), StmtKind::Globalvar(target)) => self.identifier(&pattern.name, &target.name),
+            (StmtKind::LocalVariables(pattern), StmtKind::LocalVariables(target)) => {
+                fields(&pattern.declarations, &target.declarations)
+            }
+            (StmtKind::Switch(pattern), StmtKind::Switch(target)) => {
+                pattern.cases.len() == target.cases.len()
+                    && pattern.cases.iter().zip(target.cases.iter()).all(|(pattern, target)| {
+                        pattern.iter_body_statements().count() == target.iter_body_statements().count()
+                    })
+                    && pattern.default_case.as_ref().map(Vec::len) == target.default_case.as_ref().map(Vec::len)
+            }
+            (StmtKind::Assignment(pattern), StmtKind::Assignment(target)) => {
+                discriminant(&pattern.op) == discriminant(&target.op)
+            }
+            (pattern, target) => discriminant(pattern) == discriminant(target),
+        }
+    }
+}
+
+/// Returns whether the two lists of fields are initialized in the same places.
+fn fields(pattern: &[Field], target: &[Field]) -> bool {
+    pattern.len() == target.len()
+        && pattern
+            .iter()
+            .zip(target.iter())
+            .all(|(pattern, target)| discriminant(pattern) == discriminant(target))
+}
@@ -0,0 +1,641 @@
+use super::*;
+
+/// Converts the Ast (or any individual [Stmt] or [Expr]) back into valid gml.
+///
+/// The output is normalized (consistent whitespace, one statement per line, semicolons where
+/// gml accepts them), and parenthesis are only inserted where they are required to preserve the
+/// structure of the tree. Any [Grouping] in the tree is always preserved, so printing code that
+/// came from the parser and parsing it again will produce the same tree.
+///
+/// ```
+/// use duck::parse::{Parser, Printer};
+/// let ast = Parser::new("foo=1+2;", 0).into_ast().unwrap();
+/// assert_eq!(Printer::default().ast(&ast), "foo = 1 + 2;\n");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Printer {
+    indentation: String,
+}
+impl Default for Printer {
+    fn default() -> Self {
+        Self::new("    ")
+    }
+}
+impl Printer {
+    /// Creates a new printer that will use the provided string for each level of indentation.
+    pub fn new(indentation: impl Into<String>) -> Self {
+        Self {
+            indentation: indentation.into(),
+        }
+    }
+
+    /// Prints every statement in the Ast, each ending with a new line.
+    pub fn ast(&self, ast: &Ast) -> String {
+        let mut output = Output::new(self);
+        for Tag(label, parameter, _) in ast.tags() {
+            match parameter {
+                Some(parameter) => output.push_str(&format!("// #![{label}({parameter})]\n")),
+                None => output.push_str(&format!("// #![{label}]\n")),
+            }
+        }
+        for stmt in ast.stmts() {
+            output.stmt(stmt);
+            output.push('\n');
+        }
+        output.buffer
+    }
+
+    /// Prints a single statement, including any tags attached to it.
+    pub fn stmt(&self, stmt: &Stmt) -> String {
+        let mut output = Output::new(self);
+        output.stmt(stmt);
+        output.buffer
+    }
+
+    /// Prints a single expression.
+    pub fn expr(&self, expr: &Expr) -> String {
+        let mut output = Output::new(self);
+        output.expr(expr, Precedence::NullCoalecence, false);
+        output.buffer
+    }
+}
+
+/// How tightly an expression binds, mirroring the descent order of the [Parser].
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub(crate) enum Precedence {
+    NullCoalecence,
+    Ternary,
+    Logical,
+    Equality,
+    Bitwise,
+    BitShift,
+    Addition,
+    Multiplication,
+    Unary,
+    Postfix,
+    Primary,
+}
+impl Precedence {
+    pub(crate) fn of(expr: &Expr) -> Self {
+        match expr.kind() {
+            ExprKind::NullCoalecence(_) => Self::NullCoalecence,
+            ExprKind::Ternary(_) => Self::Ternary,
+            ExprKind::Logical(_) => Self::Logical,
+            ExprKind::Equality(_) => Self::Equality,
+            ExprKind::Evaluation(Evaluation { op, .. }) => match op {
+                EvaluationOp::And(_) | EvaluationOp::Or(_) | EvaluationOp::Xor(_) => Self::Bitwise,
+                EvaluationOp::BitShiftLeft(_) | EvaluationOp::BitShiftRight(_) => Self::BitShift,
+                EvaluationOp::Plus(_) | EvaluationOp::Minus(_) => Self::Addition,
+                EvaluationOp::Star(_) | EvaluationOp::Slash(_) | EvaluationOp::Div(_) | EvaluationOp::Modulo(_) => {
+                    Self::Multiplication
+                }
+            },
+            ExprKind::Unary(_) => Self::Unary,
+            ExprKind::Postfix(_) => Self::Postfix,
+            ExprKind::Function(_)
+            | ExprKind::Access(_)
+            | ExprKind::Call(_)
+            | ExprKind::Grouping(_)
+            | ExprKind::Literal(_)
+            | ExprKind::Identifier(_) => Self::Primary,
+        }
+    }
+
+    /// The precedence one step tighter than this one.
+    fn tighter(self) -> Self {
+        match self {
+            Self::NullCoalecence => Self::Ternary,
+            Self::Ternary => Self::Logical,
+            Self::Logical => Self::Equality,
+            Self::Equality => Self::Bitwise,
+            Self::Bitwise => Self::BitShift,
+            Self::BitShift => Self::Addition,
+            Self::Addition => Self::Multiplication,
+            Self::Multiplication => Self::Unary,
+            Self::Unary => Self::Postfix,
+            Self::Postfix | Self::Primary => Self::Primary,
+        }
+    }
+}
+
+/// Returns the loosest precedences the left and right operands of a binary operation can have
+/// without parenthesis. GameMaker groups chains of operators at the same precedence from the left,
+/// except for `??`, which is grouped from the right.
+pub(crate) fn operand_precedences(expr: &Expr) -> (Precedence, Precedence) {
+    let precedence = Precedence::of(expr);
+    match expr.kind() {
+        ExprKind::NullCoalecence(_) => (precedence.tighter(), precedence),
+        _ => (precedence, precedence.tighter()),
+    }
+}
+
+/// The buffer a [Printer] writes into.
+struct Output<'a> {
+    printer: &'a Printer,
+    buffer: String,
+    depth: usize,
+}
+impl<'a> Output<'a> {
+    fn new(printer: &'a Printer) -> Self {
+        Self {
+            printer,
+            buffer: String::new(),
+            depth: 0,
+        }
+    }
+
+    fn push(&mut self, chr: char) {
+        self.buffer.push(chr);
+    }
+
+    fn push_str(&mut self, string: &str) {
+        self.buffer.push_str(string);
+    }
+
+    fn new_line(&mut self) {
+        self.push('\n');
+        for _ in 0..self.depth {
+            self.buffer.push_str(&self.printer.indentation);
+        }
+    }
+
+    fn stmt(&mut self, stmt: &Stmt) {
+        for Tag(label, parameter, _) in stmt.tags() {
+            match parameter {
+                Some(parameter) => self.push_str(&format!("// #[{label}({parameter})]")),
+                None => self.push_str(&format!("// #[{label}]")),
+            }
+            self.new_line();
+        }
+        self.stmt_kind(stmt);
+        if Self::requires_semicolon(stmt) {
+            self.push(';');
+        }
+    }
+
+    /// Whether the statement should be terminated with a semicolon when it stands on its own.
+    fn requires_semicolon(stmt: &Stmt) -> bool {
+        match stmt.kind() {
+            StmtKind::Expr(expr) => !matches!(expr.kind(), ExprKind::Function(_)),
+            StmtKind::Globalvar(_)
+            | StmtKind::LocalVariables(_)
+            | StmtKind::DoUntil(_)
+            | StmtKind::Return(_)
+            | StmtKind::Throw(_)
+            | StmtKind::Delete(_)
+            | StmtKind::Break
+            | StmtKind::Continue
+            | StmtKind::Exit
+            | StmtKind::Assignment(_) => true,
+            StmtKind::Enum(_)
+            | StmtKind::Macro(_)
+            | StmtKind::TryCatch(_)
+            | StmtKind::For(_)
+            | StmtKind::With(_)
+            | StmtKind::Repeat(_)
+            | StmtKind::While(_)
+            | StmtKind::If(_)
+            | StmtKind::Switch(_)
+            | StmtKind::Block(_) => false,
+        }
+    }
+
+    /// Prints the statement without its tag or trailing semicolon.
+    fn stmt_kind(&mut self, stmt: &Stmt) {
+        match stmt.kind() {
+            StmtKind::Enum(Enum { name, members }) => {
+                self.push_str(&format!("enum {name} {{"));
+                self.depth += 1;
+                for member in members {
+                    self.new_line();
+                    self.field(member);
+                    self.push(',');
+                }
+                self.depth -= 1;
+                self.new_line();
+                self.push('}');
+            }
+            StmtKind::Macro(Macro { name, config, body }) => {
+                self.push_str("#macro ");
+                if let Some(config) = config {
+                    self.push_str(&format!("{config}:"));
+                }
+                self.push_str(&format!("{name} {body}"));
+            }
+            StmtKind::Globalvar(Globalvar { name }) => self.push_str(&format!("globalvar {name}")),
+            StmtKind::LocalVariables(LocalVariables { declarations }) => {
+                self.push_str("var ");
+                for (i, declaration) in declarations.iter().enumerate() {
+                    if i != 0 {
+                        self.push_str(", ");
+                    }
+                    self.field(declaration);
+                }
+            }
+            StmtKind::TryCatch(TryCatch {
+                try_body,
+                catch_expr,
+                catch_body,
+                finally_body,
+            }) => {
+                self.push_str("try ");
+                self.stmt(try_body);
+                self.continuation(try_body, "catch ");
+                self.expr(catch_expr, Precedence::NullCoalecence, true);
+                self.push(' ');
+                self.stmt(catch_body);
+                if let Some(finally_body) = finally_body {
+                    self.continuation(catch_body, "finally ");
+                    self.stmt(finally_body);
+                }
+            }
+            StmtKind::For(For {
+                initializer,
+                condition,
+                iterator,
+                body,
+            }) => {
+                self.push_str("for (");
+                self.stmt_kind(initializer);
+                self.push_str("; ");
+                self.expr(condition, Precedence::NullCoalecence, false);
+                self.push_str("; ");
+                self.stmt_kind(iterator);
+                self.push_str(") ");
+                self.stmt(body);
+            }
+            StmtKind::With(With { identity, body }) => self.control("with", identity, body),
+            StmtKind::Repeat(Repeat { tick_counts, body }) => self.control("repeat", tick_counts, body),
+            StmtKind::While(While { condition, body }) => self.control("while", condition, body),
+            StmtKind::DoUntil(DoUntil { body, condition }) => {
+                self.push_str("do ");
+                self.stmt(body);
+                self.continuation(body, "until ");
+                self.expr(condition, Precedence::NullCoalecence, false);
+            }
+            StmtKind::If(If {
+                condition,
+                body,
+                else_stmt,
+                uses_then_keyword,
+            }) => {
+                self.push_str("if ");
+                self.expr(condition, Precedence::NullCoalecence, true);
+                if *uses_then_keyword {
+                    self.push_str(" then");
+                }
+                self.push(' ');
+                self.stmt(body);
+                if let Some(else_stmt) = else_stmt {
+                    self.continuation(body, "else ");
+                    self.stmt(else_stmt);
+                }
+            }
+            StmtKind::Switch(Switch {
+                identity,
+                cases,
+                default_case,
+            }) => {
+                self.push_str("switch ");
+                self.expr(identity, Precedence::NullCoalecence, true);
+                self.push_str(" {");
+                self.depth += 1;
+                for case in cases {
+                    self.new_line();
+                    self.push_str("case ");
+                    self.expr(case.identity(), Precedence::NullCoalecence, false);
+                    self.push(':');
+                    self.case_body(case.iter_body_statements());
+                }
+                if let Some(default_case) = default_case {
+                    self.new_line();
+                    self.push_str("default:");
+                    self.case_body(default_case.iter());
+                }
+                self.depth -= 1;
+                self.new_line();
+                self.push('}');
+            }
+            StmtKind::Block(Block { body, delimiters }) => {
+                let (open, close) = delimiters.map_or(("{".into(), "}".into()), |(open, close)| {
+                    (open.to_string(), close.to_string())
+                });
+                self.push_str(&open);
+                if !body.is_empty() {
+                    self.depth += 1;
+                    for stmt in body {
+                        self.new_line();
+                        self.stmt(stmt);
+                    }
+                    self.depth -= 1;
+                    self.new_line();
+                }
+                self.push_str(&close);
+            }
+            StmtKind::Return(Return { value }) => {
+                self.push_str("return");
+                if let Some(value) = value {
+                    self.push(' ');
+                    self.expr(value, Precedence::NullCoalecence, false);
+                }
+            }
+            StmtKind::Throw(Throw { value }) => {
+                self.push_str("throw ");
+                self.expr(value, Precedence::NullCoalecence, false);
+            }
+            StmtKind::Delete(Delete { value }) => {
+                self.push_str("delete ");
+                self.expr(value, Precedence::NullCoalecence, false);
+            }
+            StmtKind::Break => self.push_str("break"),
+            StmtKind::Continue => self.push_str("continue"),
+            StmtKind::Exit => self.push_str("exit"),
+            StmtKind::Assignment(Assignment { left, op, right }) => {
+                self.expr(left, Precedence::Unary, true);
+                self.push_str(&format!(" {} ", op.token()));
+                self.expr(right, Precedence::NullCoalecence, false);
+            }
+            StmtKind::Expr(expr) => self.expr(expr, Precedence::NullCoalecence, false),
+        }
+    }
+
+    /// Prints the keyword that continues a statement after a body (such as `else`), placing it on
+    /// the same line as a closing brace, or on a new line otherwise.
+    fn continuation(&mut self, previous_body: &Stmt, keyword: &str) {
+        if matches!(previous_body.kind(), StmtKind::Block(_)) {
+            self.push(' ');
+        } else {
+            self.new_line();
+        }
+        self.push_str(keyword);
+    }
+
+    fn control(&mut self, keyword: &str, expr: &Expr, body: &Stmt) {
+        self.push_str(keyword);
+        self.push(' ');
+        self.expr(expr, Precedence::NullCoalecence, true);
+        self.push(' ');
+        self.stmt(body);
+    }
+
+    fn case_body<'s>(&mut self, body: impl Iterator<Item = &'s Stmt>) {
+        self.depth += 1;
+        for stmt in body {
+            self.new_line();
+            self.stmt(stmt);
+        }
+        self.depth -= 1;
+    }
+
+    fn field(&mut self, field: &Field) {
+        match field {
+            Field::Uninitialized(expr) => self.expr(expr, Precedence::Primary, false),
+            Field::Initialized(stmt) => self.stmt_kind(stmt),
+        }
+    }
+
+    /// Prints an expression that sits in a position which requires at least the given
+    /// precedence. `followed` marks that more of the surrounding expression will be printed
+    /// after it, in which case expressions that greedily parse everything to their right (such as
+    /// unary operations and ternaries) must be wrapped in parenthesis.
+    fn expr(&mut self, expr: &Expr, minimum: Precedence, followed: bool) {
+        let open_ended = matches!(
+            expr.kind(),
+            ExprKind::Unary(_) | ExprKind::Ternary(_) | ExprKind::NullCoalecence(_)
+        );
+        if Precedence::of(expr) < minimum || (followed && open_ended) {
+            self.push('(');
+            self.expr_kind(expr, false);
+            self.push(')');
+        } else {
+            self.expr_kind(expr, followed);
+        }
+    }
+
+    /// Prints an expression that is the target of a call or an accessor.
+    fn callee(&mut self, expr: &Expr) {
+        if matches!(
+            expr.kind(),
+            ExprKind::Identifier(_) | ExprKind::Grouping(_) | ExprKind::Call(_) | ExprKind::Access(_)
+        ) {
+            self.expr_kind(expr, true);
+        } else {
+            self.push('(');
+            self.expr_kind(expr, false);
+            self.push(')');
+        }
+    }
+
+    /// Prints a binary operation, wrapping an operand in parenthesis if it would otherwise be
+    /// grouped differently when parsed again. See [operand_precedences].
+    fn binary(&mut self, expr: &Expr, left: &Expr, op: &dyn std::fmt::Display, right: &Expr, followed: bool) {
+        let (left_minimum, right_minimum) = operand_precedences(expr);
+        self.expr(left, left_minimum, true);
+        self.push_str(&format!(" {op} "));
+        self.expr(right, right_minimum, followed);
+    }
+
+    fn expr_kind(&mut self, expr: &Expr, followed: bool) {
+        match expr.kind() {
+            ExprKind::Function(Function {
+                name,
+                parameters,
+                constructor,
+                body,
+            }) => {
+                self.push_str("function");
+                if let Some(name) = name {
+                    self.push_str(&format!(" {name}"));
+                }
+                self.push('(');
+                for (i, parameter) in parameters.iter().enumerate() {
+                    if i != 0 {
+                        self.push_str(", ");
+                    }
+                    self.field(parameter);
+                }
+                self.push_str(") ");
+                if let Some(Constructor { inheritance }) = constructor {
+                    if let Some(inheritance) = inheritance {
+                        self.push_str(": ");
+                        self.expr(inheritance, Precedence::Primary, false);
+                        self.push(' ');
+                    }
+                    self.push_str("constructor ");
+                }
+                self.stmt(body);
+            }
+            ExprKind::Logical(Logical { left, op, right }) => self.binary(expr, left, op, right, followed),
+            ExprKind::Equality(Equality { left, op, right }) => self.binary(expr, left, op, right, followed),
+            ExprKind::Evaluation(Evaluation { left, op, right }) => self.binary(expr, left, op, right, followed),
+            ExprKind::NullCoalecence(NullCoalecence { left, right }) => self.binary(expr, left, &"??", right, followed),
+            ExprKind::Ternary(Ternary {
+                condition,
+                true_value,
+                false_value,
+            }) => {
+                self.expr(condition, Precedence::Logical, true);
+                self.push_str(" ? ");
+                self.expr(true_value, Precedence::NullCoalecence, false);
+                self.push_str(" : ");
+                self.expr(false_value, Precedence::NullCoalecence, followed);
+            }
+            ExprKind::Unary(Unary { op, right }) => {
+                let op = op.to_string();
+                self.push_str(&op);
+                let operand_start = self.buffer.len();
+                self.expr(right, Precedence::NullCoalecence, followed);
+                // Avoid gluing operators together (ie: `- -a` becoming `--a`), and keep keywords apart.
+                let operand = &self.buffer[operand_start..];
+                if op.ends_with(char::is_alphabetic) || (op.ends_with(['+', '-']) && operand.starts_with(['+', '-'])) {
+                    self.buffer.insert(operand_start, ' ');
+                }
+            }
+            ExprKind::Postfix(Postfix { left, op }) => {
+                self.expr(left, Precedence::Primary, true);
+                self.push_str(&op.to_string());
+            }
+            ExprKind::Access(access) => match access {
+                Access::Global { right } => self.push_str(&format!("global.{right}")),
+                Access::Identity { right } => self.push_str(&format!("self.{right}")),
+                Access::Other { right } => self.push_str(&format!("other.{right}")),
+                Access::Dot { left, right } => {
+                    self.callee(left);
+                    self.push_str(&format!(".{right}"));
+                }
+                Access::Array {
+                    left,
+                    index_one,
+                    index_two,
+                    using_accessor,
+                } => {
+                    self.callee(left);
+                    self.push_str(if *using_accessor { "[@ " } else { "[" });
+                    self.expr(index_one, Precedence::NullCoalecence, false);
+                    if let Some(index_two) = index_two {
+                        self.push_str(", ");
+                        self.expr(index_two, Precedence::NullCoalecence, false);
+                    }
+                    self.push(']');
+                }
+                Access::Map { left, key } => self.accessor(left, "[? ", key),
+                Access::List { left, index } => self.accessor(left, "[| ", index),
+                Access::Struct { left, key } => self.accessor(left, "[$ ", key),
+                Access::Grid {
+                    left,
+                    index_one,
+                    index_two,
+                } => {
+                    self.callee(left);
+                    self.push_str("[# ");
+                    self.expr(index_one, Precedence::NullCoalecence, false);
+                    self.push_str(", ");
+                    self.expr(index_two, Precedence::NullCoalecence, false);
+                    self.push(']');
+                }
+            },
+            ExprKind::Call(Call {
+                left,
+                arguments,
+                uses_new,
+            }) => {
+                if *uses_new {
+                    self.push_str("new ");
+                    // `new` always binds to the first call in a chain, so a call being called must be wrapped.
+                    if matches!(left.kind(), ExprKind::Call(_)) {
+                        self.push('(');
+                        self.expr_kind(left, false);
+                        self.push(')');
+                    } else {
+                        self.callee(left);
+                    }
+                } else {
+                    self.callee(left);
+                }
+                self.push('(');
+                self.list(arguments);
+                self.push(')');
+            }
+            ExprKind::Grouping(Grouping { inner, .. }) => {
+                self.push('(');
+                self.expr(inner, Precedence::NullCoalecence, false);
+                self.push(')');
+            }
+            ExprKind::Literal(literal) => self.literal(literal),
+            ExprKind::Identifier(identifier) => self.push_str(&identifier.lexeme),
+        }
+    }
+
+    fn accessor(&mut self, left: &Expr, opening: &str, key: &Expr) {
+        self.callee(left);
+        self.push_str(opening);
+        self.expr(key, Precedence::NullCoalecence, false);
+        self.push(']');
+    }
+
+    fn list(&mut self, exprs: &[Expr]) {
+        for (i, expr) in exprs.iter().enumerate() {
+            if i != 0 {
+                self.push_str(", ");
+            }
+            self.expr(expr, Precedence::NullCoalecence, false);
+        }
+    }
+
+    fn literal(&mut self, literal: &Literal) {
+        match literal {
+            Literal::True => self.push_str("true"),
+            Literal::False => self.push_str("false"),
+            Literal::Undefined => self.push_str("undefined"),
+            Literal::Noone => self.push_str("noone"),
+            Literal::String(lexeme) => {
+                // Strings keep their original (escaped) contents, so we only need to pick
+                // delimiters that can contain them.
+                let unescaped = |quote: char| {
+                    let mut in_escape = false;
+                    lexeme.chars().any(|chr| match chr {
+                        _ if in_escape => {
+                            in_escape = false;
+                            false
+                        }
+                        '\\' => {
+                            in_escape = true;
+                            false
+                        }
+                        chr => chr == quote,
+                    })
+                };
+                if !lexeme.contains(['\n', '\r']) && !unescaped('"') {
+                    self.push_str(&format!("\"{lexeme}\""));
+                } else if !unescaped('\'') {
+                    self.push_str(&format!("@'{lexeme}'"));
+                } else {
+                    self.push_str(&format!("@\"{lexeme}\""));
+                }
+            }
+            Literal::Real(real) => self.push_str(&real.to_string()),
+            Literal::Hex(hex) => self.push_str(&format!("0x{hex}")),
+            Literal::Array(elements) => {
+                self.push('[');
+                self.list(elements);
+                self.push(']');
+            }
+            Literal::Struct(fields) => {
+                if fields.is_empty() {
+                    self.push_str("{}");
+                } else {
+                    self.push_str("{ ");
+                    for (i, (name, value)) in fields.iter().enumerate() {
+                        if i != 0 {
+                            self.push_str(", ");
+                        }
+                        self.push_str(&format!("{name}: "));
+                        self.expr(value, Precedence::NullCoalecence, false);
+                    }
+                    self.push_str(" }");
+                }
+            }
+            Literal::Misc(lexeme) => self.push_str(lexeme),
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use super::{Ast, Token};
+
+/// A lossless view of a gml file, containing every token the parser consumed along with all of
+/// the whitespace, comments, tags and regions that surrounded them. Printing a SyntaxTree
+/// reproduces the original source exactly.
+///
+/// The typed [Ast] remains the primary way to inspect code -- the SyntaxTree simply keeps
+/// everything the Ast throws away, so that tooling like formatters and fixers can work against
+/// the exact source.
+#[derive(Debug, Clone)]
+pub struct SyntaxTree {
+    ast: Ast,
+    tokens: Vec<SyntaxToken>,
+    trailing_trivia: Vec<Trivia>,
+}
+impl SyntaxTree {
+    /// Creates a new syntax tree.
+    pub fn new(ast: Ast, tokens: Vec<SyntaxToken>, trailing_trivia: Vec<Trivia>) -> Self {
+        Self {
+            ast,
+            tokens,
+            trailing_trivia,
+        }
+    }
+
+    /// Returns a reference to the typed Ast.
+    pub fn ast(&self) -> &Ast {
+        &self.ast
+    }
+
+    /// Consumes the syntax tree, returning its typed Ast.
+    pub fn into_ast(self) -> Ast {
+        self.ast
+    }
+
+    /// Returns every significant token in the file, in order.
+    pub fn tokens(&self) -> &[SyntaxToken] {
+        &self.tokens
+    }
+
+    /// Returns the trivia found after the final token of the file.
+    pub fn trailing_trivia(&self) -> &[Trivia] {
+        &self.trailing_trivia
+    }
+
+    /// Returns an iterator over all of the trivia in the file, in order.
+    pub fn trivia(&self) -> impl Iterator<Item = &Trivia> {
+        self.tokens
+            .iter()
+            .flat_map(|token| token.leading_trivia.iter())
+            .chain(self.trailing_trivia.iter())
+    }
+
+    /// Returns an iterator over all of the comments in the file (including lint tags).
+    pub fn comments(&self) -> impl Iterator<Item = &Trivia> {
+        self.trivia().filter(|trivia| trivia.is_comment())
+    }
+
+    /// Returns the trivia leading up to the token that begins at the given byte offset, if there
+    /// is such a token.
+    pub fn leading_trivia_at(&self, offset: usize) -> Option<&[Trivia]> {
+        self.tokens
+            .binary_search_by_key(&offset, |token| token.offset)
+            .ok()
+            .map(|index| self.tokens[index].leading_trivia.as_slice())
+    }
+}
+impl std::fmt::Display for SyntaxTree {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for token in self.tokens.iter() {
+            for trivia in token.leading_trivia.iter() {
+                f.write_str(trivia.text)?;
+            }
+            f.write_str(token.text)?;
+        }
+        for trivia in self.trailing_trivia.iter() {
+            f.write_str(trivia.text)?;
+        }
+        Ok(())
+    }
+}
+
+/// A significant token within a [SyntaxTree], along with the trivia that preceded it.
+#[derive(Debug, PartialEq, Clone)]
+pub struct SyntaxToken {
+    /// The token itself.
+    pub token: Token,
+    /// The exact source text of the token.
+    pub text: &'static str,
+    /// The byte offset the token starts at in the source.
+    pub offset: usize,
+    /// All of the trivia found between the previous token and this one.
+    pub leading_trivia: Vec<Trivia>,
+}
+
+/// A piece of source code that has no meaning to the Ast, such as whitespace or a comment.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Trivia {
+    /// The kind of trivia this is.
+    pub kind: TriviaKind,
+    /// The exact source text of the trivia.
+    pub text: &'static str,
+    /// The byte offset the trivia starts at in the source.
+    pub offset: usize,
+}
+impl Trivia {
+    /// Returns whether this trivia is a comment or a lint tag.
+    pub fn is_comment(&self) -> bool {
+        matches!(self.kind, TriviaKind::Comment | TriviaKind::Tag)
+    }
+}
+
+/// The various kinds of [Trivia].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TriviaKind {
+    /// Spaces, tabs and newlines.
+    Whitespace,
+    /// A line or block comment.
+    Comment,
+    /// A lint tag, such as `// #[allow(draw_text)]`.
+    Tag,
+    /// A `#region` or `#endregion` line.
+    Region,
+    /// Anything else the lexer passed over.
+    Skipped,
+}
+
+/// Splits text the lexer skipped over into trivia, starting at the given offset.
+pub(super) fn split_trivia(text: &'static str, offset: usize, trivia: &mut Vec<Trivia>) {
+    let mut remaining = text;
+    while !remaining.is_empty() {
+        let start = offset + text.len() - remaining.len();
+        let (kind, length) = if remaining.starts_with(char::is_whitespace) {
+            let length = remaining.find(|c: char| !c.is_whitespace()).unwrap_or(remaining.len());
+            (TriviaKind::Whitespace, length)
+        } else {
+            let length = remaining.find(['\r', '\n']).unwrap_or(remaining.len());
+            let kind = if remaining.starts_with("//") {
+                TriviaKind::Comment
+            } else {
+                TriviaKind::Skipped
+            };
+            (kind, length)
+        };
+        trivia.push(Trivia {
+            kind,
+            text: &remaining[..length],
+            offset: start,
+        });
+        remaining = &remaining[length..];
+    }
+}
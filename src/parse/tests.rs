@@ -1,3 +1,6 @@
 mod expr_tests;
+mod pattern_tests;
+mod printer_tests;
 mod stmt_tests;
+mod syntax_tests;
 mod token_parsing;
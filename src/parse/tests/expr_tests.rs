@@ -243,14 +243,29 @@ expr_test!(
     bitwise_chain,
     "1 | 1 | 1",
     Evaluation::new(
-        Literal::Real(1.0).into_expr_lazy(),
-        EvaluationOp::Or(Token::lazy(TokenKind::Pipe)),
         Evaluation::new(
             Literal::Real(1.0).into_expr_lazy(),
             EvaluationOp::Or(Token::lazy(TokenKind::Pipe)),
             Literal::Real(1.0).into_expr_lazy(),
         )
         .into_expr_lazy(),
+        EvaluationOp::Or(Token::lazy(TokenKind::Pipe)),
+        Literal::Real(1.0).into_expr_lazy(),
+    )
+);
+
+expr_test!(
+    subtraction_chain,
+    "1 - 2 - 3",
+    Evaluation::new(
+        Evaluation::new(
+            Literal::Real(1.0).into_expr_lazy(),
+            EvaluationOp::Minus(Token::lazy(TokenKind::Minus)),
+            Literal::Real(2.0).into_expr_lazy(),
+        )
+        .into_expr_lazy(),
+        EvaluationOp::Minus(Token::lazy(TokenKind::Minus)),
+        Literal::Real(3.0).into_expr_lazy(),
     )
 );
 
@@ -957,13 +972,6 @@ expr_test!(
     logically_joined_expressions,
     "foo == 1 && foo == 1 && foo == 1",
     Logical::new(
-        Equality::new(
-            Identifier::lazy("foo").into_expr_lazy(),
-            EqualityOp::Equal(Token::lazy(TokenKind::DoubleEqual)),
-            Literal::Real(1.0).into_expr_lazy(),
-        )
-        .into_expr_lazy(),
-        LogicalOp::And(Token::lazy(TokenKind::DoubleAmpersand)),
         Logical::new(
             Equality::new(
                 Identifier::lazy("foo").into_expr_lazy(),
@@ -980,6 +988,13 @@ expr_test!(
             .into_expr_lazy(),
         )
         .into_expr_lazy(),
+        LogicalOp::And(Token::lazy(TokenKind::DoubleAmpersand)),
+        Equality::new(
+            Identifier::lazy("foo").into_expr_lazy(),
+            EqualityOp::Equal(Token::lazy(TokenKind::DoubleEqual)),
+            Literal::Real(1.0).into_expr_lazy(),
+        )
+        .into_expr_lazy(),
     )
 );
 
@@ -0,0 +1,116 @@
+use crate::parse::*;
+use pretty_assertions::assert_eq;
+
+/// Finds every match of the pattern in the source, returning each match's rewrite.
+fn matches(pattern: &str, rewrite: &str, source: &'static str) -> Vec<String> {
+    let pattern = Pattern::new(pattern).unwrap_or_else(|e| panic!("failed to parse pattern: {}", e.message));
+    let ast = Parser::new_with_default_ids(source, 0).into_ast().unwrap();
+    pattern
+        .find(&ast)
+        .iter()
+        .map(|pattern_match| pattern_match.rewrite(rewrite))
+        .collect()
+}
+
+/// Asserts the pattern matches the source the given number of times.
+macro_rules! pattern_test {
+    ($name:ident: $pattern:expr, $src:expr => $count:expr) => {
+        #[cfg(test)]
+        #[test]
+        fn $name() {
+            assert_eq!(matches($pattern, "", $src).len(), $count);
+        }
+    };
+}
+
+pattern_test!(exact_call: "foo(1, 2)", "foo(1, 2); foo(1, 3); foo(1, 2, 3); bar(1, 2);" => 1);
+pattern_test!(metavariable_call: "foo($a, $b)", "foo(1, 2); foo(x, y + 1); foo(1); foo(1, 2, 3);" => 2);
+pattern_test!(nested_matches: "foo($a)", "foo(foo(1));" => 2);
+pattern_test!(repeated_metavariable: "$a == $a", "a = x == x; a = x == y; a = foo.bar == foo.bar;" => 2);
+pattern_test!(wildcard: "$_ == $_", "a = x == x; a = x == y;" => 2);
+pattern_test!(groupings_ignored: "$a + $b", "a = (x + y); a = (x) + (y); a = x + y;" => 3);
+pattern_test!(precedence_respected: "$a * $b", "a = x + y * z; a = (x + y) * z;" => 2);
+pattern_test!(operators_compared: "$a == 0", "a = x == 0; a = x != 0; a = x >= 0;" => 1);
+pattern_test!(literals_compared: "$a == \"foo\"", "a = x == \"foo\"; a = x == \"bar\"; a = x == foo;" => 1);
+pattern_test!(identifiers_compared: "array_length($a)", "array_length(x); array_length_1d(x);" => 1);
+pattern_test!(dot_access_metavariable: "$a.$field = 0", "foo.bar = 0; foo.baz = 0; foo = 0;" => 2);
+pattern_test!(new_compared: "new $a()", "new Foo(); Foo();" => 1);
+pattern_test!(struct_literal: "scr_save($a, { $key: $value })", "scr_save(x, { a: 1 }); scr_save(x, { a: 1, b: 2 }); scr_save(x, y);" => 1);
+pattern_test!(with_all: "with (all) $body", "with (all) { foo(); } with all x = 1; with (obj_player) {}" => 2);
+pattern_test!(if_metavariable_body: "if ($a == true) $body", "if foo == true { bar(); } if (foo == true) bar(); if foo {}" => 2);
+pattern_test!(if_requires_else: "if $a $b else $c", "if foo {} if foo {} else {}" => 1);
+pattern_test!(statement_bodies_compared: "while ($a) { exit; }", "while (x) { exit; } while (x) { foo(); } while (x) { exit; exit; }" => 1);
+pattern_test!(metavariable_in_string_ignored: "show_debug_message(\"$a\")", "show_debug_message(\"$a\"); show_debug_message(x);" => 1);
+pattern_test!(hex_literal: "$a | 0xFF", "a = x | 0xFF; a = x | 0xFE;" => 1);
+pattern_test!(local_variables: "var $a = $b", "var x = 1; var y; var x = 1, y = 2;" => 1);
+
+#[test]
+fn instance_create_layer_rewrite() {
+    assert_eq!(
+        matches(
+            "instance_create_layer($x, $y, \"Instances\", $obj)",
+            "spawn($obj, $x, $y)",
+            "var a = instance_create_layer(x + 16, y, \"Instances\", obj_bullet);
+            instance_create_layer(0, 0, \"Effects\", obj_smoke);",
+        ),
+        vec!["spawn(obj_bullet, x + 16, y)"]
+    );
+}
+
+#[test]
+fn statement_rewrite() {
+    assert_eq!(
+        matches("if ($a == true) $body", "if ($a) $body", "if foo.bar == true exit;"),
+        vec!["if (foo.bar) exit;"]
+    );
+}
+
+#[test]
+fn unbound_metavariables_kept_in_rewrite() {
+    assert_eq!(matches("foo($a)", "bar($a, $b, $)", "foo(1);"), vec!["bar(1, $b, $)"]);
+}
+
+#[test]
+fn rewrite_parenthesizes_bindings() {
+    assert_eq!(
+        matches("double($v)", "$v * 2", "x = double(a + 1);"),
+        vec!["(a + 1) * 2"]
+    );
+    assert_eq!(matches("foo($v)", "-$v", "x = foo(a ? b : c);"), vec!["-(a ? b : c)"]);
+    assert_eq!(matches("foo($v)", "$v - 1", "x = foo(a - b);"), vec!["a - b - 1"]);
+    assert_eq!(matches("foo($v)", "1 - $v", "x = foo(a - b);"), vec!["1 - (a - b)"]);
+    assert_eq!(matches("foo($v)", "1 + $v", "x = foo(a + b);"), vec!["1 + (a + b)"]);
+    assert_eq!(
+        matches("foo($v)", "bar($v)", "x = foo(a ? b : c);"),
+        vec!["bar(a ? b : c)"]
+    );
+}
+
+#[test]
+fn rewrite_parenthesized_for_surroundings() {
+    assert_eq!(matches("inc($v)", "$v + 1", "x = 2 * inc(a);"), vec!["(a + 1)"]);
+    assert_eq!(matches("inc($v)", "$v + 1", "x = 2 + inc(a);"), vec!["(a + 1)"]);
+    assert_eq!(matches("inc($v)", "$v + 1", "x = inc(a) + 2;"), vec!["a + 1"]);
+    assert_eq!(matches("inc($v)", "$v", "x = 2 * inc(a + b);"), vec!["(a + b)"]);
+    assert_eq!(matches("inc($v)", "$v + 1", "x = inc(a);"), vec!["a + 1"]);
+}
+
+#[test]
+fn bindings() {
+    let pattern = Pattern::new("foo($a, $_)").unwrap();
+    let ast = Parser::new_with_default_ids("foo(x, y);", 0).into_ast().unwrap();
+    let pattern_match = pattern.find(&ast).remove(0);
+    assert_eq!(
+        pattern_match.binding("a"),
+        Some(&Binding::Expr(Identifier::lazy("x").into_expr_lazy()))
+    );
+    assert_eq!(pattern_match.bindings().count(), 1);
+}
+
+#[test]
+fn invalid_patterns() {
+    assert!(Pattern::new("foo(").is_err());
+    assert!(Pattern::new("foo(); bar();").is_err());
+    assert!(Pattern::new("").is_err());
+    assert!(Pattern::new("$a").is_err());
+}
@@ -0,0 +1,256 @@
+use crate::parse::*;
+use pretty_assertions::assert_eq;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+/// Parses the source, prints it, and then parses the output again, ensuring both trees match.
+macro_rules! round_trip_test {
+    ($name:ident: $src:expr) => {
+        #[cfg(test)]
+        #[test]
+        fn $name() {
+            let original = Parser::new_with_default_ids($src, 0).into_ast().unwrap();
+            let printed = Printer::default().ast(&original);
+            let reparsed = Parser::new_with_default_ids(Box::leak(Box::new(printed.clone())), 0)
+                .into_ast()
+                .unwrap_or_else(|_| panic!("failed to parse printed output:\n{printed}"));
+            assert_eq!(original.stmts(), reparsed.stmts(), "printed output:\n{}", printed);
+            assert_eq!(printed, Printer::default().ast(&reparsed));
+        }
+    };
+}
+
+macro_rules! printer_test {
+    ($name:ident: $src:expr => $expected:expr) => {
+        #[cfg(test)]
+        #[test]
+        fn $name() {
+            let ast = Parser::new_with_default_ids($src, 0).into_ast().unwrap();
+            assert_eq!(Printer::default().ast(&ast), $expected);
+        }
+    };
+}
+
+round_trip_test!(enum_declaration: "enum Foo { Bar, Baz = 1 << 2 }");
+round_trip_test!(macros: "#macro FOO 0\n#macro bar:BAZ \"hi\"\nx = FOO;");
+round_trip_test!(globalvar: "globalvar foo;");
+round_trip_test!(local_variables: "var a, b = 1, c = b + 2;");
+round_trip_test!(try_catch: "try { foo(); } catch (e) { show(e); } finally { bar(); }");
+round_trip_test!(for_loop: "for (var i = 0; i < 10; i++) { foo(i); }");
+round_trip_test!(with: "with (obj_player) instance_destroy();");
+round_trip_test!(repeat: "repeat 3 { x += 1; }");
+round_trip_test!(do_until: "do { x -= 1; } until x <= 0;");
+round_trip_test!(while_loop: "while (true) { break; continue; }");
+round_trip_test!(if_else_chain: "if a { b(); } else if c then d(); else { exit; }");
+round_trip_test!(switch: "switch foo { case 0: case 1: bar(); break; case Foo.Bar: break; default: baz(); }");
+round_trip_test!(begin_end_blocks: "if a begin b(); end");
+round_trip_test!(return_throw_delete: "function f() { return; return 1; throw \"err\"; delete foo; }");
+round_trip_test!(assignment_ops: "a = 1; a += 1; a -= 1; a *= 1; a /= 1; a |= 1; a &= 1; a ^= 1; a ??= 1; a %= 1;");
+round_trip_test!(functions: "function foo(a, b = 1) {}\nvar bar = function() { return 0; };");
+round_trip_test!(constructors: "function Foo(a) constructor {}\nfunction Bar(a) : Foo(a) constructor { self.a = a; }");
+round_trip_test!(calls: "foo(); foo(1, 2); new Foo(); a.b.c(); a()(); foo(function() {});");
+round_trip_test!(accessors: "x = a[0] + a[@ 1, 2] + a[? \"k\"] + a[| 3] + a[# 4, 5] + a[$ \"k\"];");
+round_trip_test!(dot_accesses: "x = global.a + self.b + other.c + a.b.c + foo().bar;");
+round_trip_test!(literals: "x = [true, false, undefined, noone, 1, 0.5, $ff, 0xFF, c_white, \"s\", {}, { a: 1, b }];");
+round_trip_test!(multiline_string: "x = @\"multiple\nlines\";");
+round_trip_test!(single_quote_string: "x = @'has \"quotes\"';");
+round_trip_test!(groupings: "x = (a + b) * c - (d);");
+round_trip_test!(chains: "x = a + b + c; y = a - (b - c); z = (a - b) - c;");
+round_trip_test!(left_associative: "x = a - b - c; y = a / b * c; z = a == b == c;");
+round_trip_test!(operators: "x = a && b and c; y = a || b or c; z = a xor b; w = a == b; w = a != b; w = a < b; w = a <= b; w = a > b; w = a >= b;");
+round_trip_test!(bitwise: "x = a & b & c; y = a | b; z = a ^ b; w = a << b; v = a >> b;");
+round_trip_test!(multiplicative: "x = a * b * c; y = a / b; z = a div b; w = a mod b; v = a % b;");
+round_trip_test!(unary: "x = -a + !b + ~c + not d + - -e + ++f + --g;");
+round_trip_test!(postfix: "a++; b--; x = c++ + d--;");
+round_trip_test!(ternary_and_null: "x = a ? b ? c : d : e ?? f;");
+round_trip_test!(tags: "// #[allow(draw_text)]\ndraw_text(0, 0, \"hi\");\n// #[enum_string]\nenum A { B }");
+
+printer_test!(normalizes_whitespace: "foo=1+2;bar( 1,2 )" => "foo = 1 + 2;\nbar(1, 2);\n");
+printer_test!(indents_blocks: "if a { if b { c(); } } else d();" => "if a {\n    if b {\n        c();\n    }\n} else d();\n");
+printer_test!(switch_layout: "switch a { case 1: b(); break; default: c(); }" => "switch a {\n    case 1:\n        b();\n        break;\n    default:\n        c();\n}\n");
+printer_test!(function_layout: "function foo(a, b = 2) : bar() constructor { }" => "function foo(a, b = 2) : bar() constructor {}\n");
+printer_test!(left_associative_subtraction: "x = a - b - c;" => "x = a - b - c;\n");
+printer_test!(left_associative_multiplication: "x = a / b * c;" => "x = a / b * c;\n");
+printer_test!(left_associative_equality: "x = a == b == c;" => "x = a == b == c;\n");
+printer_test!(enum_layout: "enum A { B, C = 2 }" => "enum A {\n    B,\n    C = 2,\n}\n");
+
+fn lazy_token(token_type: TokenKind) -> Token {
+    Token::lazy(token_type)
+}
+
+fn minimal(expr: Expr) -> String {
+    Printer::default().expr(&expr)
+}
+
+#[test]
+fn parenthesis_added_for_precedence() {
+    let sum = Evaluation::new(
+        Identifier::lazy("a").into_expr_lazy(),
+        EvaluationOp::Plus(lazy_token(TokenKind::Plus)),
+        Identifier::lazy("b").into_expr_lazy(),
+    )
+    .into_expr_lazy();
+    let product = Evaluation::new(
+        sum.clone(),
+        EvaluationOp::Star(lazy_token(TokenKind::Star)),
+        Identifier::lazy("c").into_expr_lazy(),
+    )
+    .into_expr_lazy();
+    assert_eq!(minimal(product), "(a + b) * c");
+    let product = Evaluation::new(
+        Identifier::lazy("c").into_expr_lazy(),
+        EvaluationOp::Star(lazy_token(TokenKind::Star)),
+        sum,
+    )
+    .into_expr_lazy();
+    assert_eq!(minimal(product), "c * (a + b)");
+}
+
+#[test]
+fn parenthesis_added_for_right_chains() {
+    let sum =
+        |left, right| Evaluation::new(left, EvaluationOp::Plus(lazy_token(TokenKind::Plus)), right).into_expr_lazy();
+    let difference =
+        |left, right| Evaluation::new(left, EvaluationOp::Minus(lazy_token(TokenKind::Minus)), right).into_expr_lazy();
+    let a = || Identifier::lazy("a").into_expr_lazy();
+    assert_eq!(minimal(difference(difference(a(), a()), a())), "a - a - a");
+    assert_eq!(minimal(difference(a(), difference(a(), a()))), "a - (a - a)");
+    // `+` also joins strings, so even its chains keep their grouping
+    assert_eq!(minimal(sum(sum(a(), a()), a())), "a + a + a");
+    assert_eq!(minimal(sum(a(), sum(a(), a()))), "a + (a + a)");
+    assert_eq!(minimal(difference(a(), sum(a(), a()))), "a - (a + a)");
+}
+
+#[test]
+fn parenthesis_added_for_open_ended_unary() {
+    let negative = Unary::new(
+        UnaryOp::Negative(lazy_token(TokenKind::Minus)),
+        Identifier::lazy("a").into_expr_lazy(),
+    )
+    .into_expr_lazy();
+    let sum = Evaluation::new(
+        negative.clone(),
+        EvaluationOp::Plus(lazy_token(TokenKind::Plus)),
+        Identifier::lazy("b").into_expr_lazy(),
+    )
+    .into_expr_lazy();
+    assert_eq!(minimal(sum), "(-a) + b");
+    let sum = Evaluation::new(
+        Identifier::lazy("b").into_expr_lazy(),
+        EvaluationOp::Plus(lazy_token(TokenKind::Plus)),
+        negative,
+    )
+    .into_expr_lazy();
+    assert_eq!(minimal(sum), "b + -a");
+}
+
+/// Removes every grouping from the expression, leaving only its structure.
+fn strip_groupings(expr: &mut Expr) {
+    while let ExprKind::Grouping(grouping) = expr.kind() {
+        *expr = grouping.inner.clone();
+    }
+    expr.visit_child_exprs_mut(strip_groupings);
+}
+
+/// Generates a random expression without any groupings in it.
+fn random_expr(rng: &mut StdRng, depth: usize) -> Expr {
+    let leaf = depth == 0 || rng.gen_bool(0.2);
+    if leaf {
+        return match rng.gen_range(0..4) {
+            0 => Identifier::lazy(["a", "b", "c"][rng.gen_range(0..3)]).into_expr_lazy(),
+            1 => Literal::Real(rng.gen_range(0..100) as f64).into_expr_lazy(),
+            2 => Literal::String("s".into()).into_expr_lazy(),
+            _ => Literal::True.into_expr_lazy(),
+        };
+    }
+    let depth = depth - 1;
+    match rng.gen_range(0..10) {
+        0 => {
+            let (kind, op): (TokenKind, fn(Token) -> EvaluationOp) = [
+                (TokenKind::Plus, EvaluationOp::Plus as fn(Token) -> EvaluationOp),
+                (TokenKind::Minus, EvaluationOp::Minus),
+                (TokenKind::Star, EvaluationOp::Star),
+                (TokenKind::Slash, EvaluationOp::Slash),
+                (TokenKind::Div, EvaluationOp::Div),
+                (TokenKind::Mod, EvaluationOp::Modulo),
+                (TokenKind::Ampersand, EvaluationOp::And),
+                (TokenKind::Pipe, EvaluationOp::Or),
+                (TokenKind::Caret, EvaluationOp::Xor),
+                (TokenKind::BitShiftLeft, EvaluationOp::BitShiftLeft),
+                (TokenKind::BitShiftRight, EvaluationOp::BitShiftRight),
+            ][rng.gen_range(0..11)];
+            Evaluation::new(random_expr(rng, depth), op(lazy_token(kind)), random_expr(rng, depth)).into_expr_lazy()
+        }
+        1 => {
+            let (kind, op): (TokenKind, fn(Token) -> EqualityOp) = [
+                (TokenKind::DoubleEqual, EqualityOp::Equal as fn(Token) -> EqualityOp),
+                (TokenKind::BangEqual, EqualityOp::NotEqual),
+                (TokenKind::LessThan, EqualityOp::LessThan),
+                (TokenKind::GreaterThanOrEqual, EqualityOp::GreaterThanOrEqual),
+            ][rng.gen_range(0..4)];
+            Equality::new(random_expr(rng, depth), op(lazy_token(kind)), random_expr(rng, depth)).into_expr_lazy()
+        }
+        2 => {
+            let (kind, op): (TokenKind, fn(Token) -> LogicalOp) = [
+                (TokenKind::DoubleAmpersand, LogicalOp::And as fn(Token) -> LogicalOp),
+                (TokenKind::Or, LogicalOp::Or),
+                (TokenKind::Xor, LogicalOp::Xor),
+            ][rng.gen_range(0..3)];
+            Logical::new(random_expr(rng, depth), op(lazy_token(kind)), random_expr(rng, depth)).into_expr_lazy()
+        }
+        3 => {
+            let (kind, op): (TokenKind, fn(Token) -> UnaryOp) = [
+                (TokenKind::Minus, UnaryOp::Negative as fn(Token) -> UnaryOp),
+                (TokenKind::Plus, UnaryOp::Positive),
+                (TokenKind::Bang, UnaryOp::Not),
+                (TokenKind::Not, UnaryOp::Not),
+                (TokenKind::Tilde, UnaryOp::BitwiseNot),
+                (TokenKind::DoublePlus, UnaryOp::Increment),
+                (TokenKind::DoubleMinus, UnaryOp::Decrement),
+            ][rng.gen_range(0..7)];
+            Unary::new(op(lazy_token(kind)), random_expr(rng, depth)).into_expr_lazy()
+        }
+        4 => Postfix::new(
+            random_expr(rng, depth),
+            PostfixOp::Increment(lazy_token(TokenKind::DoublePlus)),
+        )
+        .into_expr_lazy(),
+        5 => Ternary::new(
+            random_expr(rng, depth),
+            random_expr(rng, depth),
+            random_expr(rng, depth),
+        )
+        .into_expr_lazy(),
+        6 => NullCoalecence::new(random_expr(rng, depth), random_expr(rng, depth)).into_expr_lazy(),
+        7 => Call::new(
+            random_expr(rng, depth),
+            (0..rng.gen_range(0..3)).map(|_| random_expr(rng, depth)).collect(),
+        )
+        .into_expr_lazy(),
+        8 => Access::Dot {
+            left: random_expr(rng, depth),
+            right: Identifier::lazy("d"),
+        }
+        .into_expr_lazy(),
+        _ => Access::Array {
+            left: random_expr(rng, depth),
+            index_one: random_expr(rng, depth),
+            index_two: None,
+            using_accessor: false,
+        }
+        .into_expr_lazy(),
+    }
+}
+
+#[test]
+fn random_expressions_round_trip() {
+    let mut rng = StdRng::seed_from_u64(0xD0C);
+    for _ in 0..2000 {
+        let expr = random_expr(&mut rng, 5);
+        let printed = Printer::default().expr(&expr);
+        let mut reparsed = Parser::new_with_default_ids(Box::leak(Box::new(printed.clone())), 0)
+            .expr()
+            .unwrap_or_else(|_| panic!("failed to parse printed output: {printed}"));
+        strip_groupings(&mut reparsed);
+        assert_eq!(expr, reparsed, "printed output: {}", printed);
+    }
+}
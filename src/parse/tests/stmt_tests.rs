@@ -654,3 +654,17 @@ stmt_test!(
         Macro::new(Identifier::lazy("bar"), "0").into_stmt_lazy(),
     ])
 );
+
+#[test]
+fn switch_case_fallthrough_comment() {
+    let mut parser = Parser::new_with_default_ids(
+        "switch foo { case 0: bar(); // fallthrough\n case 1: bar(); // done\n case 2: bar(); }",
+        0,
+    );
+    let stmt = parser.stmt().unwrap();
+    let StmtKind::Switch(switch) = stmt.kind() else {
+        unreachable!()
+    };
+    let comments: Vec<bool> = switch.cases.iter().map(SwitchCase::has_fallthrough_comment).collect();
+    assert_eq!(comments, vec![true, false, false]);
+}
@@ -0,0 +1,82 @@
+use crate::parse::*;
+use pretty_assertions::assert_eq;
+
+macro_rules! round_trip_test {
+    ($name:ident: $src:expr) => {
+        #[cfg(test)]
+        #[test]
+        fn $name() {
+            let tree = Parser::new_with_default_ids($src, 0).into_syntax_tree().unwrap();
+            assert_eq!(tree.to_string(), $src)
+        }
+    };
+}
+
+round_trip_test!(empty: "");
+round_trip_test!(whitespace_only: " \n\t\r\n");
+round_trip_test!(simple_statement: "foo = 1;");
+round_trip_test!(irregular_whitespace: "  var   foo=1 ;\r\n\tbar (  foo )\n\n");
+round_trip_test!(line_comment: "// hello\nfoo = 1; // trailing\n");
+round_trip_test!(block_comment: "foo /* inline */ = 1;\n/*\n multi\n line\n*/");
+round_trip_test!(tag: "// #[allow(draw_text)]\ndraw_text(0, 0, \"hi\");");
+round_trip_test!(malformed_tag: "// #[allow(draw_text)\nfoo = 1;");
+round_trip_test!(regions: "#region Setup\nfoo = 1;\n#endregion\n");
+round_trip_test!(macros: "#macro FOO 1\n#macro bar:BAZ 2\nx = FOO;");
+round_trip_test!(strings: "foo = @\"multi\nline\" + @'single' + \"escaped \\\" quote\";");
+round_trip_test!(unicode: "foo = \"🦆\"; // quack 🦆\n");
+round_trip_test!(
+    function: "function foo(a, b = 1) constructor {\n    // body\n    return a + b;\n}\n"
+);
+
+#[test]
+fn trivia_kinds() {
+    let tree = Parser::new_with_default_ids("#region\n// #[allow(foo)]\n/* bar */ x=1; // baz", 0)
+        .into_syntax_tree()
+        .unwrap();
+    let kinds: Vec<TriviaKind> = tree.trivia().map(|trivia| trivia.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            TriviaKind::Region,
+            TriviaKind::Whitespace,
+            TriviaKind::Tag,
+            TriviaKind::Whitespace,
+            TriviaKind::Comment,
+            TriviaKind::Whitespace,
+            TriviaKind::Whitespace,
+            TriviaKind::Comment,
+        ]
+    );
+}
+
+#[test]
+fn comments_are_collected() {
+    let tree = Parser::new_with_default_ids("// one\nfoo = 1; /* two */\n// #[three]", 0)
+        .into_syntax_tree()
+        .unwrap();
+    let comments: Vec<&str> = tree.comments().map(|trivia| trivia.text).collect();
+    assert_eq!(comments, vec!["// one", "/* two */", "// #[three]"]);
+}
+
+#[test]
+fn leading_trivia_lookup() {
+    let tree = Parser::new_with_default_ids("foo = 1;\n// note\nbar = 2;", 0)
+        .into_syntax_tree()
+        .unwrap();
+    let texts: Vec<&str> = tree
+        .leading_trivia_at(17)
+        .unwrap()
+        .iter()
+        .map(|trivia| trivia.text)
+        .collect();
+    assert_eq!(texts, vec!["\n", "// note", "\n"]);
+    assert!(tree.leading_trivia_at(1).is_none());
+}
+
+#[test]
+fn ast_matches_into_ast() {
+    let source = "// comment\nvar foo = 1;\nif foo { bar(); }";
+    let tree = Parser::new_with_default_ids(source, 0).into_syntax_tree().unwrap();
+    let ast = Parser::new_with_default_ids(source, 0).into_ast().unwrap();
+    assert_eq!(tree.ast().stmts(), ast.stmts());
+}
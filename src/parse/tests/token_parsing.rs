@@ -1,6 +1,6 @@
 use crate::parse::{
     TokenKind,
-    lexer::{Lexer, MISC_GML_CONSTANTS, MISC_GML_VARIABLES},
+    lexer::{Lexer, MISC_GML_CONSTANTS, MISC_GML_GLOBAL_VARIABLES, MISC_GML_INSTANCE_VARIABLES},
 };
 use TokenKind::*;
 use pretty_assertions::assert_eq;
@@ -39,6 +39,9 @@ token_test!(tag: "// #[enum_string]" => Tag("enum_string", None));
 token_test!(tag_extra_slashes: "///// #[enum_string]" => Tag("enum_string", None));
 token_test!(tag_freeform_param: "///// #[tag(with random stuff inside!)]" => Tag("tag", Some("with random stuff inside!")));
 token_test!(parameter_tag: "// #[allow(and_keyword)]" => Tag("allow", Some("and_keyword")));
+token_test!(inner_tag: "// #![allow(global)]" => InnerTag("allow", Some("global")));
+token_test!(region: "#region Drawing" => Region("Drawing"));
+token_test!(end_region: "#endregion" => EndRegion);
 token_test!(
     keywords:
     "switch case break return enum default and or function constructor exit global
@@ -75,7 +78,10 @@ fn constants() {
 
 #[test]
 fn builtin_variables() {
-    for var in MISC_GML_VARIABLES.iter() {
+    for var in MISC_GML_INSTANCE_VARIABLES
+        .iter()
+        .chain(MISC_GML_GLOBAL_VARIABLES.iter())
+    {
         assert_eq!(Lexer::new(var).next().map(|t| t.token_type), Some(Identifier(var)));
     }
 }
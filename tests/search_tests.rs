@@ -0,0 +1,72 @@
+use pretty_assertions::assert_eq;
+use std::{path::PathBuf, process::Command};
+
+/// Creates a project containing a single script with the given source, runs `duck search` on it
+/// with the given arguments, and returns the script's source afterwards along with the output.
+fn search(name: &str, source: &str, args: &[&str]) -> (String, String) {
+    let project = std::env::temp_dir().join(format!("duck_search_tests_{name}_{}", std::process::id()));
+    let script: PathBuf = project.join("scripts/test/test.gml");
+    std::fs::create_dir_all(script.parent().unwrap()).unwrap();
+    std::fs::write(&script, source).unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_duck"))
+        .arg("search")
+        .args(args)
+        .arg("--path")
+        .arg(&project)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stdout));
+    let rewritten = std::fs::read_to_string(&script).unwrap();
+    std::fs::remove_dir_all(&project).unwrap();
+    (rewritten, String::from_utf8(output.stdout).unwrap())
+}
+
+#[test]
+fn rewrite_keeps_source_of_bindings() {
+    let (source, _) = search(
+        "keeps_source",
+        "if array_length(foo[ /* first */ 0 ]) == 0 {\n    bar();\n}\n",
+        &["array_length($a) == 0", "--rewrite", "array_empty($a)"],
+    );
+    assert_eq!(source, "if array_empty(foo[ /* first */ 0 ]) {\n    bar();\n}\n");
+}
+
+#[test]
+fn rewrite_parenthesizes_bindings() {
+    let (source, _) = search(
+        "parenthesizes_bindings",
+        "x = double(a + 1);\ny = negate(a ? b : c);\n",
+        &["double($v)", "--rewrite", "$v * 2"],
+    );
+    assert_eq!(source, "x = (a + 1) * 2;\ny = negate(a ? b : c);\n");
+    let (source, _) = search(
+        "parenthesizes_unary",
+        "y = negate(a ? b : c);\n",
+        &["negate($v)", "--rewrite=-$v"],
+    );
+    assert_eq!(source, "y = -(a ? b : c);\n");
+}
+
+#[test]
+fn rewrite_nested_in_binary_operator() {
+    let (source, _) = search(
+        "nested_in_binary",
+        "x = 2 * inc(a) - 1;\ny = 2 + inc(a);\nz = 10 - inc(a);\n",
+        &["inc($v)", "--rewrite", "$v + 1"],
+    );
+    assert_eq!(source, "x = 2 * (a + 1) - 1;\ny = 2 + (a + 1);\nz = 10 - (a + 1);\n");
+}
+
+#[test]
+fn rewrite_in_json() {
+    let (source, output) = search(
+        "json",
+        "x = double(a + 1);\n",
+        &["double($v)", "--rewrite", "$v * 2", "--json"],
+    );
+    assert_eq!(source, "x = (a + 1) * 2;\n");
+    let output: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(output[0]["text"], "double(a + 1)");
+    assert_eq!(output[0]["bindings"]["v"], "a + 1");
+    assert_eq!(output[0]["rewrite"], "(a + 1) * 2");
+}
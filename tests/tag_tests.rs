@@ -0,0 +1,77 @@
+use duck::{
+    Config, GmlLibrary,
+    analyze::Project,
+    driver,
+    lint::{LintLevel, LintRegistry},
+    parse::Parser,
+};
+use pretty_assertions::assert_eq;
+use std::path::Path;
+
+/// Runs every lint over a script with the given source and returns the codes of what was reported
+/// and of what was suppressed. Unlike the unit tests, this links the library as it is built for
+/// real, so every node keeps its span and tags can be resolved at the nodes lints point at.
+fn lint(source: &'static str) -> (Vec<String>, Vec<String>) {
+    lint_with_config(Config::full(), source)
+}
+
+fn lint_with_config(config: Config, source: &'static str) -> (Vec<String>, Vec<String>) {
+    let mut library = GmlLibrary::new();
+    let path = "scripts/test/test.gml";
+    let file_id = library.add(path.to_string(), source);
+    let ast = Parser::new(source, file_id).into_ast().unwrap();
+    let mut project = Project::new();
+    project.add(&ast, Path::new(path));
+    let reports = driver::process_file(&ast, &config, &LintRegistry::builtin(), &project);
+    let reported = reports.diagnostics.into_iter().filter_map(|diagnostic| diagnostic.code);
+    let suppressed = reports
+        .suppressions
+        .iter()
+        .filter_map(|suppression| suppression.diagnostic().code.clone());
+    (reported.collect(), suppressed.collect())
+}
+
+#[test]
+fn allow_on_unreachable_statement() {
+    let (reported, suppressed) = lint(
+        "
+            function foo() {
+                return 1;
+                // #[allow(unreachable_code)]
+                show_debug_message(\"x\");
+            }
+        ",
+    );
+    assert_eq!(reported, Vec::<String>::new());
+    assert_eq!(suppressed, vec!["unreachable_code".to_string()]);
+}
+
+#[test]
+fn allow_on_uninitialized_read() {
+    let (reported, suppressed) = lint(
+        "
+            var a;
+            // #[allow(uninitialized_local_variable)]
+            show_debug_message(a);
+        ",
+    );
+    assert_eq!(reported, Vec::<String>::new());
+    assert_eq!(suppressed, vec!["uninitialized_local_variable".to_string()]);
+}
+
+#[test]
+fn allow_on_magic_number() {
+    let mut config = Config::full();
+    config.lint_levels.insert("magic_number".into(), LintLevel::Warn);
+    let (reported, suppressed) = lint_with_config(
+        config,
+        "
+            enum Hp { Max = 37 }
+            // #[allow(magic_number)]
+            hp = 37;
+            speed = 12;
+        ",
+    );
+    assert_eq!(reported, vec!["magic_number".to_string()]);
+    assert_eq!(suppressed, vec!["magic_number".to_string()]);
+}